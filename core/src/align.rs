@@ -9,10 +9,16 @@ pub enum Align {
 
     /// Align at the end of the axis.
     End,
+
+    /// Align the text baselines of the elements.
+    ///
+    /// Only a `Row` is able to align its children by their baselines. In any
+    /// other case, it behaves like [`Align::Start`].
+    Baseline,
 }
 
 /// The horizontal alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HorizontalAlignment {
     /// Align left
     Left,
@@ -25,7 +31,7 @@ pub enum HorizontalAlignment {
 }
 
 /// The vertical alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VerticalAlignment {
     /// Align top
     Top,
@@ -0,0 +1,64 @@
+/// The radius of each corner of a box.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderRadius {
+    /// The radius of the top-left corner
+    pub top_left: f32,
+
+    /// The radius of the top-right corner
+    pub top_right: f32,
+
+    /// The radius of the bottom-right corner
+    pub bottom_right: f32,
+
+    /// The radius of the bottom-left corner
+    pub bottom_left: f32,
+}
+
+impl BorderRadius {
+    /// Creates a new [`BorderRadius`] with the same radius for every corner.
+    pub const fn all(radius: f32) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+
+    /// Returns true if every corner of the [`BorderRadius`] is square.
+    pub fn is_zero(self) -> bool {
+        self == Self::default()
+    }
+}
+
+impl From<f32> for BorderRadius {
+    fn from(radius: f32) -> Self {
+        Self::all(radius)
+    }
+}
+
+impl From<[f32; 4]> for BorderRadius {
+    /// Creates a [`BorderRadius`] from the radii of its corners, clockwise
+    /// from the top-left one.
+    fn from(
+        [top_left, top_right, bottom_right, bottom_left]: [f32; 4],
+    ) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+}
+
+impl From<BorderRadius> for [f32; 4] {
+    fn from(radius: BorderRadius) -> Self {
+        [
+            radius.top_left,
+            radius.top_right,
+            radius.bottom_right,
+            radius.bottom_left,
+        ]
+    }
+}
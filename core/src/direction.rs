@@ -0,0 +1,52 @@
+use crate::{Align, HorizontalAlignment};
+
+/// The direction in which content flows horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right, like in English.
+    #[default]
+    LeftToRight,
+
+    /// Content flows from right to left, like in Arabic or Hebrew.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_rtl(self) -> bool {
+        self == Direction::RightToLeft
+    }
+
+    /// Resolves the horizontal [`Align`] of some content flowing in this
+    /// [`Direction`].
+    ///
+    /// [`Align::Start`] and [`Align::End`] are swapped when the content flows
+    /// from right to left.
+    pub fn align(self, align: Align) -> Align {
+        match (self, align) {
+            (Direction::RightToLeft, Align::Start) => Align::End,
+            (Direction::RightToLeft, Align::End) => Align::Start,
+            _ => align,
+        }
+    }
+
+    /// Resolves the [`HorizontalAlignment`] of some text flowing in this
+    /// [`Direction`].
+    ///
+    /// [`HorizontalAlignment::Left`] and [`HorizontalAlignment::Right`] are
+    /// swapped when the text flows from right to left.
+    pub fn horizontal_alignment(
+        self,
+        alignment: HorizontalAlignment,
+    ) -> HorizontalAlignment {
+        match (self, alignment) {
+            (Direction::RightToLeft, HorizontalAlignment::Left) => {
+                HorizontalAlignment::Right
+            }
+            (Direction::RightToLeft, HorizontalAlignment::Right) => {
+                HorizontalAlignment::Left
+            }
+            _ => alignment,
+        }
+    }
+}
@@ -1,5 +1,18 @@
+//! Describe the fonts of your text.
+mod descriptor;
+mod family;
+mod stretch;
+mod style;
+mod weight;
+
+pub use descriptor::Descriptor;
+pub use family::Family;
+pub use stretch::Stretch;
+pub use style::Style;
+pub use weight::Weight;
+
 /// A font.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Font {
     /// The default font.
     ///
@@ -15,6 +28,12 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// A font described by its family and properties.
+    ///
+    /// Renderers resolve it against the fonts they already know about and the
+    /// fonts installed in the system, choosing the closest match.
+    Descriptor(Descriptor),
 }
 
 impl Default for Font {
@@ -22,3 +41,15 @@ impl Default for Font {
         Font::Default
     }
 }
+
+impl From<Descriptor> for Font {
+    fn from(descriptor: Descriptor) -> Font {
+        Font::Descriptor(descriptor)
+    }
+}
+
+impl From<Family> for Font {
+    fn from(family: Family) -> Font {
+        Font::Descriptor(Descriptor::new(family))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::font::{Family, Stretch, Style, Weight};
+
+/// The description of a font, by its family and properties.
+///
+/// # Example
+/// ```
+/// use iced_core::font::{Descriptor, Family, Style, Weight};
+///
+/// let bold_italic = Descriptor::new(Family::Name("Fira Sans"))
+///     .weight(Weight::Bold)
+///     .style(Style::Italic);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Descriptor {
+    /// The [`Family`] of the font.
+    pub family: Family,
+
+    /// The [`Weight`] of the font.
+    pub weight: Weight,
+
+    /// The [`Style`] of the font.
+    pub style: Style,
+
+    /// The [`Stretch`] of the font.
+    pub stretch: Stretch,
+}
+
+impl Descriptor {
+    /// Creates a new [`Descriptor`] for the regular face of the given
+    /// [`Family`].
+    pub const fn new(family: Family) -> Self {
+        Descriptor {
+            family,
+            weight: Weight::Normal,
+            style: Style::Normal,
+            stretch: Stretch::Normal,
+        }
+    }
+
+    /// Sets the [`Weight`] of the [`Descriptor`].
+    pub const fn weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets the [`Style`] of the [`Descriptor`].
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the [`Stretch`] of the [`Descriptor`].
+    pub const fn stretch(mut self, stretch: Stretch) -> Self {
+        self.stretch = stretch;
+        self
+    }
+}
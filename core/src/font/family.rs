@@ -0,0 +1,23 @@
+/// The family of a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Family {
+    /// A specific font family, by its name.
+    ///
+    /// For instance, `"Fira Sans"` or `"DejaVu Serif"`.
+    Name(&'static str),
+
+    /// Fonts with serifs, or small strokes attached to the ends of letters.
+    Serif,
+
+    /// Fonts without serifs.
+    SansSerif,
+
+    /// Fonts that look like handwriting.
+    Cursive,
+
+    /// Decorative fonts.
+    Fantasy,
+
+    /// Fonts whose characters all have the same width.
+    Monospace,
+}
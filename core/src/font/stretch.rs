@@ -0,0 +1,47 @@
+/// The width of the letterforms of a font, relative to its normal width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stretch {
+    /// 50% of the normal width.
+    UltraCondensed,
+
+    /// 62.5% of the normal width.
+    ExtraCondensed,
+
+    /// 75% of the normal width.
+    Condensed,
+
+    /// 87.5% of the normal width.
+    SemiCondensed,
+
+    /// The normal width.
+    Normal,
+
+    /// 112.5% of the normal width.
+    SemiExpanded,
+
+    /// 125% of the normal width.
+    Expanded,
+
+    /// 150% of the normal width.
+    ExtraExpanded,
+
+    /// 200% of the normal width.
+    UltraExpanded,
+}
+
+impl Stretch {
+    /// Returns the width of the [`Stretch`], relative to the normal width.
+    pub fn ratio(self) -> f32 {
+        match self {
+            Stretch::UltraCondensed => 0.5,
+            Stretch::ExtraCondensed => 0.625,
+            Stretch::Condensed => 0.75,
+            Stretch::SemiCondensed => 0.875,
+            Stretch::Normal => 1.0,
+            Stretch::SemiExpanded => 1.125,
+            Stretch::Expanded => 1.25,
+            Stretch::ExtraExpanded => 1.5,
+            Stretch::UltraExpanded => 2.0,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// The slant of a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// An upright font.
+    Normal,
+
+    /// A font with cursive letterforms, designed to be slanted.
+    Italic,
+
+    /// A slanted version of an upright font.
+    Oblique,
+}
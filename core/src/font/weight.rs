@@ -0,0 +1,47 @@
+/// The weight, or thickness, of the strokes of a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weight {
+    /// Thin, with a value of 100.
+    Thin,
+
+    /// Extra light, with a value of 200.
+    ExtraLight,
+
+    /// Light, with a value of 300.
+    Light,
+
+    /// Normal, with a value of 400.
+    Normal,
+
+    /// Medium, with a value of 500.
+    Medium,
+
+    /// Semibold, with a value of 600.
+    Semibold,
+
+    /// Bold, with a value of 700.
+    Bold,
+
+    /// Extra bold, with a value of 800.
+    ExtraBold,
+
+    /// Black, with a value of 900.
+    Black,
+}
+
+impl Weight {
+    /// Returns the numeric value of the [`Weight`], between 100 and 900.
+    pub fn value(self) -> u16 {
+        match self {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        }
+    }
+}
@@ -6,7 +6,7 @@ use super::{KeyCode, Modifiers};
 /// additional events, feel free to [open an issue] and share your use case!_
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
@@ -29,6 +29,22 @@ pub enum Event {
     /// A unicode character was received.
     CharacterReceived(char),
 
+    /// The text being composed by an input method editor (IME) changed.
+    ///
+    /// The text is not part of the input until it is committed. An empty
+    /// text means the composition was cancelled.
+    ImePreedit {
+        /// The text being composed
+        text: String,
+
+        /// The byte range of the cursor or selection inside the text, if
+        /// it is visible
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// An input method editor (IME) committed some text to the input.
+    ImeCommit(String),
+
     /// The keyboard modifiers have changed.
     ModifiersChanged(Modifiers),
 }
@@ -14,25 +14,33 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
 
 mod align;
 mod background;
+mod border_radius;
 mod color;
-mod font;
+mod direction;
 mod length;
+mod line_height;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
 
 pub use align::{Align, HorizontalAlignment, VerticalAlignment};
 pub use background::Background;
+pub use border_radius::BorderRadius;
 pub use color::Color;
+pub use direction::Direction;
 pub use font::Font;
 pub use length::Length;
+pub use line_height::LineHeight;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
 pub use vector::Vector;
@@ -0,0 +1,26 @@
+/// The height of a line of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// A factor of the size of the text.
+    Relative(f32),
+
+    /// An absolute height in logical pixels.
+    Absolute(f32),
+}
+
+impl LineHeight {
+    /// Returns the [`LineHeight`] in logical pixels for text of the given
+    /// size.
+    pub fn to_absolute(self, size: f32) -> f32 {
+        match self {
+            LineHeight::Relative(factor) => factor * size,
+            LineHeight::Absolute(height) => height,
+        }
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(factor: f32) -> Self {
+        LineHeight::Relative(factor)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{Color, Vector};
+
+/// The shadow cast by a box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The color of the shadow
+    pub color: Color,
+
+    /// The offset of the shadow from the box, in logical pixels
+    pub offset: Vector,
+
+    /// The blur radius of the shadow, in logical pixels
+    pub blur_radius: f32,
+
+    /// The distance the shadow is expanded (or contracted, if negative)
+    /// before blurring, in logical pixels
+    pub spread: f32,
+}
+
+impl Shadow {
+    /// Creates a new [`Shadow`] of the given [`Color`], offset and blur
+    /// radius.
+    pub fn new(color: Color, offset: Vector, blur_radius: f32) -> Self {
+        Self {
+            color,
+            offset,
+            blur_radius,
+            spread: 0.0,
+        }
+    }
+
+    /// Sets the spread of the [`Shadow`].
+    pub fn spread(self, spread: f32) -> Self {
+        Self { spread, ..self }
+    }
+}
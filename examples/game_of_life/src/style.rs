@@ -44,7 +44,7 @@ impl button::StyleSheet for Button {
     fn active(&self) -> button::Style {
         button::Style {
             background: Some(Background::Color(ACTIVE)),
-            border_radius: 3.0,
+            border_radius: 3.0.into(),
             text_color: Color::WHITE,
             ..button::Style::default()
         }
@@ -73,7 +73,7 @@ impl button::StyleSheet for Clear {
     fn active(&self) -> button::Style {
         button::Style {
             background: Some(Background::Color(DESTRUCTIVE)),
-            border_radius: 3.0,
+            border_radius: 3.0.into(),
             text_color: Color::WHITE,
             ..button::Style::default()
         }
@@ -157,6 +157,7 @@ impl pick_list::StyleSheet for PickList {
             }
             .into(),
             selected_text_color: Color::WHITE,
+            shadow: None,
         }
     }
 
@@ -169,7 +170,7 @@ impl pick_list::StyleSheet for PickList {
                 a: 0.6,
                 ..Color::BLACK
             },
-            border_radius: 2.0,
+            border_radius: 2.0.into(),
             icon_size: 0.5,
         }
     }
@@ -191,6 +191,7 @@ pub fn main() {
                 // And then iced on top
                 let mouse_interaction = renderer.backend_mut().draw(
                     &mut device,
+                    &queue,
                     &mut staging_belt,
                     &mut encoder,
                     &frame.output.view,
@@ -137,12 +137,12 @@ mod dark {
                     ..SCROLLBAR
                 }
                 .into(),
-                border_radius: 2.0,
+                border_radius: 2.0.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
                 scroller: scrollable::Scroller {
                     color: Color { a: 0.7, ..SCROLLER },
-                    border_radius: 2.0,
+                    border_radius: 2.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 },
@@ -249,7 +249,7 @@ mod style {
             fn active(&self) -> button::Style {
                 button::Style {
                     background: Color::from_rgb(0.11, 0.42, 0.87).into(),
-                    border_radius: 12.0,
+                    border_radius: 12.0.into(),
                     shadow_offset: Vector::new(1.0, 1.0),
                     text_color: Color::from_rgb8(0xEE, 0xEE, 0xEE),
                     ..button::Style::default()
@@ -334,7 +334,7 @@ mod style {
             fn active(&self) -> text_input::Style {
                 text_input::Style {
                     background: SURFACE.into(),
-                    border_radius: 2.0,
+                    border_radius: 2.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                 }
@@ -375,7 +375,7 @@ mod style {
             fn active(&self) -> button::Style {
                 button::Style {
                     background: ACTIVE.into(),
-                    border_radius: 3.0,
+                    border_radius: 3.0.into(),
                     text_color: Color::WHITE,
                     ..button::Style::default()
                 }
@@ -404,12 +404,12 @@ mod style {
             fn active(&self) -> scrollable::Scrollbar {
                 scrollable::Scrollbar {
                     background: SURFACE.into(),
-                    border_radius: 2.0,
+                    border_radius: 2.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
                     scroller: scrollable::Scroller {
                         color: ACTIVE,
-                        border_radius: 2.0,
+                        border_radius: 2.0.into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
                     },
@@ -489,7 +489,7 @@ mod style {
                 progress_bar::Style {
                     background: SURFACE.into(),
                     bar: ACTIVE.into(),
-                    border_radius: 10.0,
+                    border_radius: 10.0.into(),
                 }
             }
         }
@@ -502,7 +502,7 @@ mod style {
                     background: if is_checked { ACTIVE } else { SURFACE }
                         .into(),
                     checkmark_color: Color::WHITE,
-                    border_radius: 2.0,
+                    border_radius: 2.0.into(),
                     border_width: 1.0,
                     border_color: ACTIVE,
                 }
@@ -610,7 +610,7 @@ mod style {
                             background: Some(Background::Color(
                                 Color::from_rgb(0.2, 0.2, 0.7),
                             )),
-                            border_radius: 10.0,
+                            border_radius: 10.0.into(),
                             text_color: Color::WHITE,
                             ..button::Style::default()
                         }
@@ -626,7 +626,7 @@ mod style {
                     background: Some(Background::Color(Color::from_rgb(
                         0.8, 0.2, 0.2,
                     ))),
-                    border_radius: 5.0,
+                    border_radius: 5.0.into(),
                     text_color: Color::WHITE,
                     shadow_offset: Vector::new(1.0, 1.0),
                     ..button::Style::default()
@@ -769,7 +769,7 @@ mod style {
                     Button::Primary => Color::from_rgb(0.11, 0.42, 0.87),
                     Button::Secondary => Color::from_rgb(0.5, 0.5, 0.5),
                 })),
-                border_radius: 12.0,
+                border_radius: 12.0.into(),
                 shadow_offset: Vector::new(1.0, 1.0),
                 text_color: Color::from_rgb8(0xEE, 0xEE, 0xEE),
                 ..button::Style::default()
@@ -1,13 +1,22 @@
+#[cfg(any(feature = "image", feature = "svg"))]
+use crate::image;
+
+use crate::glyph;
 use crate::quad;
-use crate::text;
 use crate::triangle;
 use crate::{Settings, Transformation, Viewport};
+use glow::HasContext;
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::Layer;
+use iced_graphics::layer::{self, Layer};
 use iced_graphics::Primitive;
 use iced_native::mouse;
-use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
+use iced_native::{
+    Color, Direction, Font, HorizontalAlignment, Rectangle, Size,
+    VerticalAlignment,
+};
+
+use std::borrow::Cow;
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -16,22 +25,33 @@ use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
 #[derive(Debug)]
 pub struct Backend {
     quad_pipeline: quad::Pipeline,
-    text_pipeline: text::Pipeline,
+    text_pipeline: glyph::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image_pipeline: image::Pipeline,
+
     default_text_size: u16,
 }
 
 impl Backend {
     /// Creates a new [`Backend`].
     pub fn new(gl: &glow::Context, settings: Settings) -> Self {
-        let text_pipeline = text::Pipeline::new(gl, settings.default_font);
+        let text_pipeline = glyph::Pipeline::new(gl, settings.default_font);
         let quad_pipeline = quad::Pipeline::new(gl);
         let triangle_pipeline = triangle::Pipeline::new(gl);
 
+        #[cfg(any(feature = "image", feature = "svg"))]
+        let image_pipeline = image::Pipeline::new(gl);
+
         Self {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_pipeline,
+
             default_text_size: settings.default_text_size,
         }
     }
@@ -47,6 +67,90 @@ impl Backend {
         (primitive, mouse_interaction): &(Primitive, mouse::Interaction),
         overlay_text: &[T],
     ) -> mouse::Interaction {
+        self.render(gl, viewport, primitive, overlay_text);
+
+        *mouse_interaction
+    }
+
+    /// Renders the provided primitive at the given [`Viewport`] into an
+    /// offscreen framebuffer and reads its pixels back.
+    ///
+    /// The pixels are returned as an RGBA buffer, row by row from the top-left
+    /// corner. Its dimensions are the physical size of the [`Viewport`].
+    ///
+    /// The viewport of the context is resized to fit the framebuffer, and it
+    /// is not restored afterwards.
+    pub fn render_offscreen(
+        &mut self,
+        gl: &glow::Context,
+        viewport: &Viewport,
+        background_color: Color,
+        primitive: &Primitive,
+    ) -> Vec<u8> {
+        let Size { width, height } = viewport.physical_size();
+
+        if width == 0 || height == 0 {
+            return Vec::new();
+        }
+
+        let (framebuffer, renderbuffer) = unsafe {
+            let framebuffer =
+                gl.create_framebuffer().expect("Create framebuffer");
+            let renderbuffer =
+                gl.create_renderbuffer().expect("Create renderbuffer");
+
+            gl.bind_renderbuffer(glow::RENDERBUFFER, Some(renderbuffer));
+            gl.renderbuffer_storage(
+                glow::RENDERBUFFER,
+                glow::SRGB8_ALPHA8,
+                width as i32,
+                height as i32,
+            );
+            gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_renderbuffer(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::RENDERBUFFER,
+                Some(renderbuffer),
+            );
+
+            gl.viewport(0, 0, width as i32, height as i32);
+
+            // The same state the window compositor sets up
+            gl.enable(glow::FRAMEBUFFER_SRGB);
+            gl.enable(glow::BLEND);
+            gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+
+            let [r, g, b, a] = background_color.into_linear();
+
+            gl.clear_color(r, g, b, a);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+
+            (framebuffer, renderbuffer)
+        };
+
+        self.render(gl, viewport, primitive, &[] as &[&str]);
+
+        let pixels = read_pixels(gl, Size::new(width, height));
+
+        unsafe {
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            gl.delete_framebuffer(framebuffer);
+            gl.delete_renderbuffer(renderbuffer);
+        }
+
+        pixels
+    }
+
+    fn render<T: AsRef<str>>(
+        &mut self,
+        gl: &glow::Context,
+        viewport: &Viewport,
+        primitive: &Primitive,
+        overlay_text: &[T],
+    ) {
         let viewport_size = viewport.physical_size();
         let scale_factor = viewport.scale_factor() as f32;
         let projection = viewport.projection();
@@ -64,7 +168,8 @@ impl Backend {
             );
         }
 
-        *mouse_interaction
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache();
     }
 
     fn flush(
@@ -78,6 +183,49 @@ impl Backend {
         let mut bounds = (layer.bounds * scale_factor).snap();
         bounds.height = bounds.height.min(target_height);
 
+        // The transformation of the layer is given in logical coordinates,
+        // but quads and text are positioned in physical ones
+        let transformation = if layer.transformation
+            == Transformation::identity()
+        {
+            transformation
+        } else {
+            transformation
+                * Transformation::scale(scale_factor, scale_factor)
+                * layer.transformation
+                * Transformation::scale(1.0 / scale_factor, 1.0 / scale_factor)
+        };
+
+        // Without offscreen targets, the opacity of a group is approximated
+        // by fading every primitive on its own
+        let opacity = layer
+            .opacity
+            .map_or(1.0, |opacity| opacity.cumulative_alpha);
+
+        // Blurring needs to sample what has been drawn so far, which is not
+        // supported yet. Therefore, the blur of a layer is ignored and only
+        // its contents are drawn.
+        //
+        // Custom primitives are only supported by `iced_wgpu`, so they are
+        // ignored as well.
+
+        // Fragments are masked in window coordinates, whose origin is the
+        // bottom-left corner of the target
+        let mask = layer.mask.map(|mask| {
+            let layer::Mask {
+                bounds,
+                border_radius: [top_left, top_right, bottom_right, bottom_left],
+            } = mask * scale_factor;
+
+            layer::Mask {
+                bounds: Rectangle {
+                    y: target_height as f32 - bounds.y - bounds.height,
+                    ..bounds
+                },
+                border_radius: [bottom_left, bottom_right, top_right, top_left],
+            }
+        });
+
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
                 gl,
@@ -85,7 +233,9 @@ impl Backend {
                 &layer.quads,
                 transformation,
                 scale_factor,
+                opacity,
                 bounds,
+                mask,
             );
         }
 
@@ -98,12 +248,40 @@ impl Backend {
                 target_height,
                 scaled,
                 scale_factor,
+                opacity,
+                mask,
                 &layer.meshes,
             );
         }
 
-        if !layer.text.is_empty() {
+        #[cfg(any(feature = "image", feature = "svg"))]
+        {
+            if !layer.images.is_empty() {
+                let scaled = transformation
+                    * Transformation::scale(scale_factor, scale_factor);
+
+                self.image_pipeline.draw(
+                    gl,
+                    target_height,
+                    &layer.images,
+                    scaled,
+                    scale_factor,
+                    opacity,
+                    bounds,
+                    mask,
+                );
+            }
+        }
+
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
+                let line_height = text
+                    .line_height
+                    .map(|height| height.to_absolute(text.size) * scale_factor);
+
+                let letter_spacing = text.letter_spacing * scale_factor;
+                let direction = text.direction;
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = glow_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
@@ -133,39 +311,78 @@ impl Backend {
                             x: text.size * scale_factor,
                             y: text.size * scale_factor,
                         },
-                        font_id: self.text_pipeline.find_font(text.font),
+                        font_id: self
+                            .text_pipeline
+                            .cache()
+                            .find_font(text.font),
                         extra: glow_glyph::Extra {
-                            color: text.color,
+                            color: fade(text.color, opacity),
                             z: 0.0,
                         },
                     }],
                     layout: glow_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            HorizontalAlignment::Left => {
-                                glow_glyph::HorizontalAlign::Left
-                            }
-                            HorizontalAlignment::Center => {
-                                glow_glyph::HorizontalAlign::Center
-                            }
-                            HorizontalAlignment::Right => {
-                                glow_glyph::HorizontalAlign::Right
-                            }
-                        })
-                        .v_align(match text.vertical_alignment {
-                            VerticalAlignment::Top => {
-                                glow_glyph::VerticalAlign::Top
-                            }
-                            VerticalAlignment::Center => {
-                                glow_glyph::VerticalAlign::Center
-                            }
-                            VerticalAlignment::Bottom => {
-                                glow_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .h_align(horizontal_align(text.horizontal_alignment))
+                        .v_align(vertical_align(text.vertical_alignment)),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                self.text_pipeline.queue(
+                    text,
+                    line_height,
+                    letter_spacing,
+                    direction,
+                );
+            }
+
+            for text in layer.rich_text.iter() {
+                let line_height = text.line_height.and_then(|height| {
+                    text.spans.first().map(|span| {
+                        height.to_absolute(span.size) * scale_factor
+                    })
+                });
+
+                let letter_spacing = text.letter_spacing * scale_factor;
+                let direction = text.direction;
+
+                let text = glow_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| glow_glyph::Text {
+                            text: span.content,
+                            scale: glow_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self
+                                .text_pipeline
+                                .cache()
+                                .find_font(span.font),
+                            extra: glow_glyph::Extra {
+                                color: fade(span.color, opacity),
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
+                    layout: glow_glyph::Layout::default()
+                        .h_align(horizontal_align(text.horizontal_alignment))
+                        .v_align(vertical_align(text.vertical_alignment)),
+                };
+
+                self.text_pipeline.queue(
+                    text,
+                    line_height,
+                    letter_spacing,
+                    direction,
+                );
             }
 
             self.text_pipeline.draw_queued(
@@ -184,7 +401,14 @@ impl Backend {
 
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
-        self.text_pipeline.trim_measurement_cache()
+        self.text_pipeline.cache_mut().trim_measurement_cache()
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), iced_native::font::Error> {
+        self.text_pipeline.cache_mut().load_font(bytes)
     }
 }
 
@@ -203,15 +427,112 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline
+            .cache()
+            .measure(contents, size, font, bounds, spacing, direction)
+    }
+
+    fn baseline(
+        &self,
+        size: f32,
+        font: Font,
+        line_height: Option<iced_native::LineHeight>,
+    ) -> f32 {
+        self.text_pipeline.cache().baseline(size, font, line_height)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[backend::Span<'_>],
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline
+            .cache()
+            .measure_spans(spans, spacing, direction, bounds)
+    }
+
+    fn span_fragments(
+        &self,
+        spans: &[backend::Span<'_>],
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<backend::Fragment> {
+        self.text_pipeline.cache().span_fragments(
+            spans,
+            spacing,
+            direction,
+            bounds,
+            horizontal_align(horizontal_alignment),
+            vertical_align(vertical_alignment),
+        )
+    }
+
+    fn character_bounds(
+        &self,
+        span: backend::Span<'_>,
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<(usize, Rectangle)> {
+        self.text_pipeline.cache().character_bounds(
+            span,
+            spacing,
+            direction,
+            bounds,
+            horizontal_align(horizontal_alignment),
+            vertical_align(vertical_alignment),
+        )
+    }
+
+    fn line_breaks(
+        &self,
+        span: backend::Span<'_>,
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        width: f32,
+    ) -> Vec<std::ops::Range<usize>> {
+        self.text_pipeline
+            .cache()
+            .line_breaks(span, spacing, direction, width)
+    }
+}
+
+fn fade([r, g, b, a]: [f32; 4], opacity: f32) -> [f32; 4] {
+    [r, g, b, a * opacity]
+}
+
+fn horizontal_align(
+    alignment: HorizontalAlignment,
+) -> glow_glyph::HorizontalAlign {
+    match alignment {
+        HorizontalAlignment::Left => glow_glyph::HorizontalAlign::Left,
+        HorizontalAlignment::Center => glow_glyph::HorizontalAlign::Center,
+        HorizontalAlignment::Right => glow_glyph::HorizontalAlign::Right,
+    }
+}
+
+fn vertical_align(alignment: VerticalAlignment) -> glow_glyph::VerticalAlign {
+    match alignment {
+        VerticalAlignment::Top => glow_glyph::VerticalAlign::Top,
+        VerticalAlignment::Center => glow_glyph::VerticalAlign::Center,
+        VerticalAlignment::Bottom => glow_glyph::VerticalAlign::Bottom,
     }
 }
 
 #[cfg(feature = "image")]
 impl backend::Image for Backend {
-    fn dimensions(&self, _handle: &iced_native::image::Handle) -> (u32, u32) {
-        (50, 50)
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
+        self.image_pipeline.dimensions(handle)
     }
 }
 
@@ -219,8 +540,30 @@ impl backend::Image for Backend {
 impl backend::Svg for Backend {
     fn viewport_dimensions(
         &self,
-        _handle: &iced_native::svg::Handle,
+        handle: &iced_native::svg::Handle,
     ) -> (u32, u32) {
-        (50, 50)
+        self.image_pipeline.viewport_dimensions(handle)
+    }
+}
+
+/// Reads the pixels of the bound framebuffer back as an RGBA buffer, row by
+/// row from the top-left corner.
+pub(crate) fn read_pixels(gl: &glow::Context, size: Size<u32>) -> Vec<u8> {
+    let row_size = size.width as usize * 4;
+    let mut pixels = vec![0; row_size * size.height as usize];
+
+    unsafe {
+        gl.read_pixels(
+            0,
+            0,
+            size.width as i32,
+            size.height as i32,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            glow::PixelPackData::Slice(&mut pixels),
+        );
     }
+
+    // The rows are read starting from the bottom-left corner
+    pixels.chunks(row_size).rev().flatten().copied().collect()
 }
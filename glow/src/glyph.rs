@@ -0,0 +1,67 @@
+use crate::Transformation;
+use iced_graphics::glyph;
+use iced_native::Direction;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    draw_brush: glow_glyph::GlyphBrush,
+    cache: glyph::Cache,
+}
+
+impl Pipeline {
+    pub fn new(gl: &glow::Context, default_font: Option<&[u8]>) -> Self {
+        let cache = glyph::Cache::new(default_font);
+
+        let draw_brush =
+            glow_glyph::GlyphBrushBuilder::using_font(cache.default_font())
+                .initial_cache_size((2048, 2048))
+                .draw_cache_multithread(false) // TODO: Expose as a configuration flag
+                .build(gl);
+
+        Pipeline { draw_brush, cache }
+    }
+
+    pub fn cache(&self) -> &glyph::Cache {
+        &self.cache
+    }
+
+    pub fn cache_mut(&mut self) -> &mut glyph::Cache {
+        &mut self.cache
+    }
+
+    pub fn queue(
+        &mut self,
+        section: glow_glyph::Section<'_>,
+        line_height: Option<f32>,
+        letter_spacing: f32,
+        direction: Direction,
+    ) {
+        let layout = self.cache.prepare(
+            &section,
+            line_height,
+            letter_spacing,
+            direction,
+        );
+
+        for font in self.cache.take_new_fonts() {
+            let _ = self.draw_brush.add_font(font);
+        }
+
+        self.draw_brush.queue_custom_layout(section, &layout);
+    }
+
+    pub fn draw_queued(
+        &mut self,
+        gl: &glow::Context,
+        transformation: Transformation,
+        region: glow_glyph::Region,
+    ) {
+        self.draw_brush
+            .draw_queued_with_transform_and_scissoring(
+                gl,
+                transformation.into(),
+                region,
+            )
+            .expect("Draw text");
+    }
+}
@@ -0,0 +1,441 @@
+mod texture;
+
+use crate::program;
+use crate::Transformation;
+use texture::Texture;
+
+use glow::HasContext;
+use iced_graphics::atlas::{self, Atlas};
+use iced_graphics::layer;
+use iced_native::Rectangle;
+use std::cell::RefCell;
+
+use bytemuck::{Pod, Zeroable};
+
+#[cfg(feature = "image")]
+use iced_native::image;
+
+#[cfg(feature = "svg")]
+use iced_native::svg;
+
+const MAX_INSTANCES: usize = 1_000;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    #[cfg(feature = "image")]
+    raster_cache: RefCell<iced_graphics::raster::Cache>,
+    #[cfg(feature = "svg")]
+    vector_cache: RefCell<iced_graphics::vector::Cache>,
+
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    instances: <glow::Context as HasContext>::Buffer,
+    transform_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
+    mask_bounds_location: <glow::Context as HasContext>::UniformLocation,
+    mask_radius_location: <glow::Context as HasContext>::UniformLocation,
+    current_transform: Transformation,
+    current_opacity: f32,
+    current_mask: Option<layer::Mask>,
+    texture_atlas: Texture,
+    atlas: Atlas,
+}
+
+impl Pipeline {
+    pub fn new(gl: &glow::Context) -> Pipeline {
+        let program = unsafe {
+            program::create(
+                gl,
+                &[
+                    (glow::VERTEX_SHADER, include_str!("shader/image.vert")),
+                    (glow::FRAGMENT_SHADER, include_str!("shader/image.frag")),
+                ],
+            )
+        };
+
+        let transform_location =
+            unsafe { gl.get_uniform_location(program, "u_Transform") }
+                .expect("Get transform location");
+
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
+        let mask_bounds_location =
+            unsafe { gl.get_uniform_location(program, "u_MaskBounds") }
+                .expect("Get mask bounds location");
+
+        let mask_radius_location =
+            unsafe { gl.get_uniform_location(program, "u_MaskRadius") }
+                .expect("Get mask radius location");
+
+        let texture_location =
+            unsafe { gl.get_uniform_location(program, "u_Texture") }
+                .expect("Get texture location");
+
+        unsafe {
+            gl.use_program(Some(program));
+
+            let matrix: [f32; 16] = Transformation::identity().into();
+            gl.uniform_matrix_4_f32_slice(
+                Some(&transform_location),
+                false,
+                &matrix,
+            );
+
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
+
+            // The atlas layers are always bound to the first texture unit
+            gl.uniform_1_i32(Some(&texture_location), 0);
+
+            gl.use_program(None);
+        }
+
+        let (vertex_array, instances) =
+            unsafe { create_instance_buffer(gl, MAX_INSTANCES) };
+
+        let texture_atlas = Texture::new(gl);
+
+        Pipeline {
+            #[cfg(feature = "image")]
+            raster_cache: RefCell::new(iced_graphics::raster::Cache::new()),
+            #[cfg(feature = "svg")]
+            vector_cache: RefCell::new(iced_graphics::vector::Cache::new()),
+
+            program,
+            vertex_array,
+            instances,
+            transform_location,
+            opacity_location,
+            mask_bounds_location,
+            mask_radius_location,
+            current_transform: Transformation::identity(),
+            current_opacity: 1.0,
+            current_mask: None,
+            texture_atlas,
+            atlas: Atlas::new(),
+        }
+    }
+
+    #[cfg(feature = "image")]
+    pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(handle);
+
+        memory.dimensions()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
+        let mut cache = self.vector_cache.borrow_mut();
+        let svg = cache.load(handle);
+
+        svg.viewport_dimensions()
+    }
+
+    pub fn draw(
+        &mut self,
+        gl: &glow::Context,
+        target_height: u32,
+        images: &[layer::Image],
+        transformation: Transformation,
+        _scale: f32,
+        opacity: f32,
+        bounds: Rectangle<u32>,
+        mask: Option<layer::Mask>,
+    ) {
+        let instances: &mut Vec<Instance> = &mut Vec::new();
+
+        #[cfg(feature = "image")]
+        let mut raster_cache = self.raster_cache.borrow_mut();
+
+        #[cfg(feature = "svg")]
+        let mut vector_cache = self.vector_cache.borrow_mut();
+
+        for image in images {
+            match &image {
+                #[cfg(feature = "image")]
+                layer::Image::Raster { handle, bounds } => {
+                    if let Some(atlas_entry) = raster_cache.upload(
+                        handle,
+                        &mut self.atlas,
+                        &mut self.texture_atlas.storage(gl),
+                    ) {
+                        add_instances(
+                            [bounds.x, bounds.y],
+                            [bounds.width, bounds.height],
+                            atlas_entry,
+                            instances,
+                        );
+                    }
+                }
+                #[cfg(not(feature = "image"))]
+                layer::Image::Raster { .. } => {}
+
+                #[cfg(feature = "svg")]
+                layer::Image::Vector { handle, bounds } => {
+                    let size = [bounds.width, bounds.height];
+
+                    if let Some(atlas_entry) = vector_cache.upload(
+                        handle,
+                        size,
+                        _scale,
+                        &mut self.atlas,
+                        &mut self.texture_atlas.storage(gl),
+                    ) {
+                        add_instances(
+                            [bounds.x, bounds.y],
+                            size,
+                            atlas_entry,
+                            instances,
+                        );
+                    }
+                }
+                #[cfg(not(feature = "svg"))]
+                layer::Image::Vector { .. } => {}
+            }
+        }
+
+        if instances.is_empty() {
+            return;
+        }
+
+        unsafe {
+            gl.enable(glow::SCISSOR_TEST);
+            gl.scissor(
+                bounds.x as i32,
+                (target_height - (bounds.y + bounds.height)) as i32,
+                bounds.width as i32,
+                bounds.height as i32,
+            );
+
+            gl.use_program(Some(self.program));
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.instances));
+            gl.active_texture(glow::TEXTURE0);
+        }
+
+        if transformation != self.current_transform {
+            unsafe {
+                let matrix: [f32; 16] = transformation.into();
+                gl.uniform_matrix_4_f32_slice(
+                    Some(&self.transform_location),
+                    false,
+                    &matrix,
+                );
+
+                self.current_transform = transformation;
+            }
+        }
+
+        if opacity != self.current_opacity {
+            unsafe {
+                gl.uniform_1_f32(Some(&self.opacity_location), opacity);
+            }
+
+            self.current_opacity = opacity;
+        }
+
+        if mask != self.current_mask {
+            let (bounds, radius) = match mask {
+                Some(layer::Mask {
+                    bounds,
+                    border_radius,
+                }) => (
+                    [bounds.x, bounds.y, bounds.width, bounds.height],
+                    border_radius,
+                ),
+                None => ([0.0; 4], [0.0; 4]),
+            };
+
+            unsafe {
+                gl.uniform_4_f32_slice(
+                    Some(&self.mask_bounds_location),
+                    &bounds,
+                );
+                gl.uniform_4_f32_slice(
+                    Some(&self.mask_radius_location),
+                    &radius,
+                );
+            }
+
+            self.current_mask = mask;
+        }
+
+        // Every layer of the atlas is a different texture, so consecutive
+        // instances in the same layer are drawn together to keep their order
+        let mut i = 0;
+        let total = instances.len();
+
+        while i < total {
+            let layer = instances[i].layer;
+
+            let end = instances[i..]
+                .iter()
+                .position(|instance| instance.layer != layer)
+                .map_or(total, |amount| i + amount)
+                .min(i + MAX_INSTANCES);
+
+            unsafe {
+                gl.bind_texture(
+                    glow::TEXTURE_2D,
+                    Some(self.texture_atlas.layer(layer as usize)),
+                );
+
+                gl.buffer_sub_data_u8_slice(
+                    glow::ARRAY_BUFFER,
+                    0,
+                    bytemuck::cast_slice(&instances[i..end]),
+                );
+
+                gl.draw_arrays_instanced(
+                    glow::TRIANGLE_STRIP,
+                    0,
+                    4,
+                    (end - i) as i32,
+                );
+            }
+
+            i = end;
+        }
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            gl.bind_vertex_array(None);
+            gl.use_program(None);
+            gl.disable(glow::SCISSOR_TEST);
+        }
+    }
+
+    pub fn trim_cache(&mut self) {
+        #[cfg(feature = "image")]
+        self.raster_cache.borrow_mut().trim(&mut self.atlas);
+
+        #[cfg(feature = "svg")]
+        self.vector_cache.borrow_mut().trim(&mut self.atlas);
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Instance {
+    _position: [f32; 2],
+    _size: [f32; 2],
+    _position_in_atlas: [f32; 2],
+    _size_in_atlas: [f32; 2],
+    layer: u32,
+}
+
+unsafe fn create_instance_buffer(
+    gl: &glow::Context,
+    size: usize,
+) -> (
+    <glow::Context as HasContext>::VertexArray,
+    <glow::Context as HasContext>::Buffer,
+) {
+    let vertex_array = gl.create_vertex_array().expect("Create vertex array");
+    let buffer = gl.create_buffer().expect("Create instance buffer");
+
+    gl.bind_vertex_array(Some(vertex_array));
+    gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer));
+    gl.buffer_data_size(
+        glow::ARRAY_BUFFER,
+        (size * std::mem::size_of::<Instance>()) as i32,
+        glow::DYNAMIC_DRAW,
+    );
+
+    let stride = std::mem::size_of::<Instance>() as i32;
+
+    gl.enable_vertex_attrib_array(0);
+    gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
+    gl.vertex_attrib_divisor(0, 1);
+
+    gl.enable_vertex_attrib_array(1);
+    gl.vertex_attrib_pointer_f32(1, 2, glow::FLOAT, false, stride, 4 * 2);
+    gl.vertex_attrib_divisor(1, 1);
+
+    gl.enable_vertex_attrib_array(2);
+    gl.vertex_attrib_pointer_f32(2, 2, glow::FLOAT, false, stride, 4 * (2 + 2));
+    gl.vertex_attrib_divisor(2, 1);
+
+    gl.enable_vertex_attrib_array(3);
+    gl.vertex_attrib_pointer_f32(
+        3,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 2),
+    );
+    gl.vertex_attrib_divisor(3, 1);
+
+    gl.bind_vertex_array(None);
+    gl.bind_buffer(glow::ARRAY_BUFFER, None);
+
+    (vertex_array, buffer)
+}
+
+fn add_instances(
+    image_position: [f32; 2],
+    image_size: [f32; 2],
+    entry: &atlas::Entry,
+    instances: &mut Vec<Instance>,
+) {
+    match entry {
+        atlas::Entry::Contiguous(allocation) => {
+            add_instance(image_position, image_size, allocation, instances);
+        }
+        atlas::Entry::Fragmented { fragments, size } => {
+            let scaling_x = image_size[0] / size.0 as f32;
+            let scaling_y = image_size[1] / size.1 as f32;
+
+            for fragment in fragments {
+                let allocation = &fragment.allocation;
+
+                let [x, y] = image_position;
+                let (fragment_x, fragment_y) = fragment.position;
+                let (fragment_width, fragment_height) = allocation.size();
+
+                let position = [
+                    x + fragment_x as f32 * scaling_x,
+                    y + fragment_y as f32 * scaling_y,
+                ];
+
+                let size = [
+                    fragment_width as f32 * scaling_x,
+                    fragment_height as f32 * scaling_y,
+                ];
+
+                add_instance(position, size, allocation, instances);
+            }
+        }
+    }
+}
+
+#[inline]
+fn add_instance(
+    position: [f32; 2],
+    size: [f32; 2],
+    allocation: &atlas::Allocation,
+    instances: &mut Vec<Instance>,
+) {
+    let (x, y) = allocation.position();
+    let (width, height) = allocation.size();
+    let layer = allocation.layer();
+
+    let instance = Instance {
+        _position: position,
+        _size: size,
+        _position_in_atlas: [
+            (x as f32 + 0.5) / atlas::SIZE as f32,
+            (y as f32 + 0.5) / atlas::SIZE as f32,
+        ],
+        _size_in_atlas: [
+            (width as f32 - 1.0) / atlas::SIZE as f32,
+            (height as f32 - 1.0) / atlas::SIZE as f32,
+        ],
+        layer: layer as u32,
+    };
+
+    instances.push(instance);
+}
@@ -0,0 +1,150 @@
+use glow::HasContext;
+use iced_graphics::atlas::{self, Entry, Layer};
+
+/// The textures holding the layers of an [`Atlas`].
+///
+/// Every layer is a texture of its own, so growing the atlas does not need to
+/// copy the existing layers.
+///
+/// [`Atlas`]: atlas::Atlas
+#[derive(Debug)]
+pub struct Texture {
+    layers: Vec<<glow::Context as HasContext>::Texture>,
+}
+
+impl Texture {
+    pub fn new(gl: &glow::Context) -> Self {
+        Texture {
+            layers: vec![unsafe { create_layer(gl) }],
+        }
+    }
+
+    pub fn layer(
+        &self,
+        layer: usize,
+    ) -> <glow::Context as HasContext>::Texture {
+        self.layers[layer]
+    }
+
+    pub fn storage<'a>(&'a mut self, gl: &'a glow::Context) -> Storage<'a> {
+        Storage { texture: self, gl }
+    }
+}
+
+/// A [`Texture`] ready to be grown and uploaded to.
+#[derive(Debug)]
+pub struct Storage<'a> {
+    texture: &'a mut Texture,
+    gl: &'a glow::Context,
+}
+
+impl Storage<'_> {
+    fn upload_allocation(&self, data: &[u8], allocation: &atlas::Allocation) {
+        let (x, y) = allocation.position();
+        let (width, height) = allocation.size();
+        let layer = allocation.layer();
+
+        unsafe {
+            self.gl.bind_texture(
+                glow::TEXTURE_2D,
+                Some(self.texture.layers[layer]),
+            );
+            self.gl.tex_sub_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                x as i32,
+                y as i32,
+                width as i32,
+                height as i32,
+                glow::BGRA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelUnpackData::Slice(data),
+            );
+        }
+    }
+}
+
+impl atlas::Storage for Storage<'_> {
+    fn grow(&mut self, layers: &[Layer]) {
+        while self.texture.layers.len() < layers.len() {
+            let layer = unsafe { create_layer(self.gl) };
+
+            self.texture.layers.push(layer);
+        }
+    }
+
+    fn upload(&mut self, width: u32, _height: u32, data: &[u8], entry: &Entry) {
+        // Fragments are uploaded from the middle of the image, so the length
+        // of its rows needs to be known
+        unsafe {
+            self.gl
+                .pixel_store_i32(glow::UNPACK_ROW_LENGTH, width as i32);
+        }
+
+        match entry {
+            Entry::Contiguous(allocation) => {
+                self.upload_allocation(data, allocation);
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    let (x, y) = fragment.position;
+                    let offset = (4 * (y * width + x)) as usize;
+
+                    self.upload_allocation(
+                        &data[offset..],
+                        &fragment.allocation,
+                    );
+                }
+            }
+        }
+
+        unsafe {
+            self.gl.pixel_store_i32(glow::UNPACK_ROW_LENGTH, 0);
+            self.gl.bind_texture(glow::TEXTURE_2D, None);
+        }
+    }
+}
+
+unsafe fn create_layer(
+    gl: &glow::Context,
+) -> <glow::Context as HasContext>::Texture {
+    let texture = gl.create_texture().expect("Create texture");
+
+    gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+    gl.tex_image_2d(
+        glow::TEXTURE_2D,
+        0,
+        glow::SRGB8_ALPHA8 as i32,
+        atlas::SIZE as i32,
+        atlas::SIZE as i32,
+        0,
+        glow::BGRA,
+        glow::UNSIGNED_BYTE,
+        None,
+    );
+
+    gl.tex_parameter_i32(
+        glow::TEXTURE_2D,
+        glow::TEXTURE_WRAP_S,
+        glow::CLAMP_TO_EDGE as i32,
+    );
+    gl.tex_parameter_i32(
+        glow::TEXTURE_2D,
+        glow::TEXTURE_WRAP_T,
+        glow::CLAMP_TO_EDGE as i32,
+    );
+    gl.tex_parameter_i32(
+        glow::TEXTURE_2D,
+        glow::TEXTURE_MIN_FILTER,
+        glow::LINEAR as i32,
+    );
+    gl.tex_parameter_i32(
+        glow::TEXTURE_2D,
+        glow::TEXTURE_MAG_FILTER,
+        glow::LINEAR as i32,
+    );
+
+    gl.bind_texture(glow::TEXTURE_2D, None);
+
+    texture
+}
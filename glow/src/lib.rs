@@ -11,11 +11,14 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod backend;
+mod glyph;
 mod program;
 mod quad;
-mod text;
 mod triangle;
 
+#[cfg(any(feature = "image", feature = "svg"))]
+mod image;
+
 pub mod settings;
 pub mod widget;
 pub mod window;
@@ -13,10 +13,13 @@ pub struct Pipeline {
     instances: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
     scale_location: <glow::Context as HasContext>::UniformLocation,
-    screen_height_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
+    mask_bounds_location: <glow::Context as HasContext>::UniformLocation,
+    mask_radius_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
     current_scale: f32,
-    current_target_height: u32,
+    current_opacity: f32,
+    current_mask: Option<layer::Mask>,
 }
 
 impl Pipeline {
@@ -39,9 +42,17 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Scale") }
                 .expect("Get scale location");
 
-        let screen_height_location =
-            unsafe { gl.get_uniform_location(program, "u_ScreenHeight") }
-                .expect("Get target height location");
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
+        let mask_bounds_location =
+            unsafe { gl.get_uniform_location(program, "u_MaskBounds") }
+                .expect("Get mask bounds location");
+
+        let mask_radius_location =
+            unsafe { gl.get_uniform_location(program, "u_MaskRadius") }
+                .expect("Get mask radius location");
 
         unsafe {
             gl.use_program(Some(program));
@@ -54,7 +65,7 @@ impl Pipeline {
             );
 
             gl.uniform_1_f32(Some(&scale_location), 1.0);
-            gl.uniform_1_f32(Some(&screen_height_location), 0.0);
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
 
             gl.use_program(None);
         }
@@ -68,10 +79,13 @@ impl Pipeline {
             instances,
             transform_location,
             scale_location,
-            screen_height_location,
+            opacity_location,
+            mask_bounds_location,
+            mask_radius_location,
             current_transform: Transformation::identity(),
             current_scale: 1.0,
-            current_target_height: 0,
+            current_opacity: 1.0,
+            current_mask: None,
         }
     }
 
@@ -82,7 +96,9 @@ impl Pipeline {
         instances: &[layer::Quad],
         transformation: Transformation,
         scale: f32,
+        opacity: f32,
         bounds: Rectangle<u32>,
+        mask: Option<layer::Mask>,
     ) {
         unsafe {
             gl.enable(glow::SCISSOR_TEST);
@@ -119,15 +135,38 @@ impl Pipeline {
             self.current_scale = scale;
         }
 
-        if target_height != self.current_target_height {
+        if opacity != self.current_opacity {
+            unsafe {
+                gl.uniform_1_f32(Some(&self.opacity_location), opacity);
+            }
+
+            self.current_opacity = opacity;
+        }
+
+        if mask != self.current_mask {
+            let (bounds, radius) = match mask {
+                Some(layer::Mask {
+                    bounds,
+                    border_radius,
+                }) => (
+                    [bounds.x, bounds.y, bounds.width, bounds.height],
+                    border_radius,
+                ),
+                None => ([0.0; 4], [0.0; 4]),
+            };
+
             unsafe {
-                gl.uniform_1_f32(
-                    Some(&self.screen_height_location),
-                    target_height as f32,
+                gl.uniform_4_f32_slice(
+                    Some(&self.mask_bounds_location),
+                    &bounds,
+                );
+                gl.uniform_4_f32_slice(
+                    Some(&self.mask_radius_location),
+                    &radius,
                 );
             }
 
-            self.current_target_height = target_height;
+            self.current_mask = mask;
         }
 
         let mut i = 0;
@@ -209,7 +248,7 @@ unsafe fn create_instance_buffer(
     gl.enable_vertex_attrib_array(4);
     gl.vertex_attrib_pointer_f32(
         4,
-        1,
+        4,
         glow::FLOAT,
         false,
         stride,
@@ -224,10 +263,54 @@ unsafe fn create_instance_buffer(
         glow::FLOAT,
         false,
         stride,
-        4 * (2 + 2 + 4 + 4 + 1),
+        4 * (2 + 2 + 4 + 4 + 4),
     );
     gl.vertex_attrib_divisor(5, 1);
 
+    gl.enable_vertex_attrib_array(6);
+    gl.vertex_attrib_pointer_f32(
+        6,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1),
+    );
+    gl.vertex_attrib_divisor(6, 1);
+
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        2,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4),
+    );
+    gl.vertex_attrib_divisor(7, 1);
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2),
+    );
+    gl.vertex_attrib_divisor(8, 1);
+
+    gl.enable_vertex_attrib_array(9);
+    gl.vertex_attrib_pointer_f32(
+        9,
+        1,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2 + 1),
+    );
+    gl.vertex_attrib_divisor(9, 1);
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
 
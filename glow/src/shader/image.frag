@@ -0,0 +1,53 @@
+#version 330
+
+uniform float u_Opacity;
+uniform vec4 u_MaskBounds;
+uniform vec4 u_MaskRadius;
+uniform sampler2D u_Texture;
+
+in vec2 v_Uv;
+
+out vec4 o_Color;
+
+// Picks the radius of the corner closest to the fragment, given the radii
+// clockwise from the top-left one
+float select_border_radius(in vec4 radii, in vec2 frag_coord, in vec2 center)
+{
+    float top = frag_coord.x > center.x ? radii.y : radii.x;
+    float bottom = frag_coord.x > center.x ? radii.z : radii.w;
+
+    return frag_coord.y > center.y ? bottom : top;
+}
+
+// The coverage of the rounded clipping mask of the layer
+//
+// The mask is given in window coordinates, so its corners are expected
+// clockwise from the bottom-left one
+float mask_alpha(in vec2 frag_coord)
+{
+    if(u_MaskRadius == vec4(0.0)) {
+        return 1.0;
+    }
+
+    vec2 half_size = u_MaskBounds.zw / 2.0;
+    vec2 center = u_MaskBounds.xy + half_size;
+
+    float radius = min(
+        select_border_radius(u_MaskRadius, frag_coord, center),
+        min(half_size.x, half_size.y)
+    );
+
+    vec2 q = abs(frag_coord - center) - half_size + vec2(radius);
+    float d = min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - radius;
+
+    return 1.0 - smoothstep(-0.5, 0.5, d);
+}
+
+void main() {
+    vec4 color = texture(u_Texture, v_Uv);
+
+    o_Color = vec4(
+        color.xyz,
+        color.w * mask_alpha(gl_FragCoord.xy) * u_Opacity
+    );
+}
@@ -7,15 +7,24 @@ layout(location = 0) in vec2 i_Pos;
 layout(location = 1) in vec2 i_Scale;
 layout(location = 2) in vec4 i_Color;
 layout(location = 3) in vec4 i_BorderColor;
-layout(location = 4) in float i_BorderRadius;
+layout(location = 4) in vec4 i_BorderRadius;
 layout(location = 5) in float i_BorderWidth;
+layout(location = 6) in vec4 i_ShadowColor;
+layout(location = 7) in vec2 i_ShadowOffset;
+layout(location = 8) in float i_ShadowBlurRadius;
+layout(location = 9) in float i_ShadowSpread;
 
 out vec4 v_Color;
 out vec4 v_BorderColor;
 out vec2 v_Pos;
 out vec2 v_Scale;
-out float v_BorderRadius;
+out vec4 v_BorderRadius;
 out float v_BorderWidth;
+out vec2 v_Position;
+out vec4 v_ShadowColor;
+out vec2 v_ShadowOffset;
+out float v_ShadowBlurRadius;
+out float v_ShadowSpread;
 
 const vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -29,16 +38,31 @@ void main() {
     vec2 p_Pos = i_Pos * u_Scale;
     vec2 p_Scale = i_Scale  * u_Scale;
 
-    float i_BorderRadius = min(
+    vec4 i_BorderRadius = min(
         i_BorderRadius,
-        min(i_Scale.x, i_Scale.y) / 2.0
+        vec4(min(i_Scale.x, i_Scale.y) / 2.0)
     );
 
+    vec2 p_ShadowOffset = i_ShadowOffset * u_Scale;
+    float p_ShadowBlurRadius = max(i_ShadowBlurRadius, 0.0) * u_Scale;
+    float p_ShadowSpread = i_ShadowSpread * u_Scale;
+
+    // The geometry is grown to cover the shadow, if there is any
+    vec2 p_Min = p_Pos;
+    vec2 p_Max = p_Pos + p_Scale;
+
+    if(i_ShadowColor.a > 0.0) {
+        vec2 extent = vec2(max(p_ShadowBlurRadius + p_ShadowSpread, 0.0));
+
+        p_Min = min(p_Min, p_Pos + p_ShadowOffset - extent);
+        p_Max = max(p_Max, p_Pos + p_Scale + p_ShadowOffset + extent);
+    }
+
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(p_Max.x - p_Min.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, p_Max.y - p_Min.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(p_Min - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
@@ -47,6 +71,16 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = p_ShadowOffset;
+    v_ShadowBlurRadius = p_ShadowBlurRadius;
+    v_ShadowSpread = p_ShadowSpread;
+
+    // The position before the transformation is used to compute the
+    // rounded corners, so they are still right when rotated or scaled
+    vec4 position = i_Transform * vec4(q_Pos, 0.0, 1.0);
+
+    v_Position = position.xy;
 
-    gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
+    gl_Position = u_Transform * position;
 }
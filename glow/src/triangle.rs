@@ -17,7 +17,12 @@ pub(crate) struct Pipeline {
     vertices: Buffer<Vertex2D>,
     indices: Buffer<u32>,
     transform_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
+    mask_bounds_location: <glow::Context as HasContext>::UniformLocation,
+    mask_radius_location: <glow::Context as HasContext>::UniformLocation,
     current_transform: Transformation,
+    current_opacity: f32,
+    current_mask: Option<layer::Mask>,
 }
 
 impl Pipeline {
@@ -39,6 +44,18 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Transform") }
                 .expect("Get transform location");
 
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
+        let mask_bounds_location =
+            unsafe { gl.get_uniform_location(program, "u_MaskBounds") }
+                .expect("Get mask bounds location");
+
+        let mask_radius_location =
+            unsafe { gl.get_uniform_location(program, "u_MaskRadius") }
+                .expect("Get mask radius location");
+
         unsafe {
             gl.use_program(Some(program));
 
@@ -49,6 +66,8 @@ impl Pipeline {
                 &transform,
             );
 
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
+
             gl.use_program(None);
         }
 
@@ -102,7 +121,12 @@ impl Pipeline {
             vertices,
             indices,
             transform_location,
+            opacity_location,
+            mask_bounds_location,
+            mask_radius_location,
             current_transform: Transformation::identity(),
+            current_opacity: 1.0,
+            current_mask: None,
         }
     }
 
@@ -112,6 +136,8 @@ impl Pipeline {
         target_height: u32,
         transformation: Transformation,
         scale_factor: f32,
+        opacity: f32,
+        mask: Option<layer::Mask>,
         meshes: &[layer::Mesh<'_>],
     ) {
         unsafe {
@@ -121,6 +147,40 @@ impl Pipeline {
             gl.bind_vertex_array(Some(self.vertex_array));
         }
 
+        if opacity != self.current_opacity {
+            unsafe {
+                gl.uniform_1_f32(Some(&self.opacity_location), opacity);
+            }
+
+            self.current_opacity = opacity;
+        }
+
+        if mask != self.current_mask {
+            let (bounds, radius) = match mask {
+                Some(layer::Mask {
+                    bounds,
+                    border_radius,
+                }) => (
+                    [bounds.x, bounds.y, bounds.width, bounds.height],
+                    border_radius,
+                ),
+                None => ([0.0; 4], [0.0; 4]),
+            };
+
+            unsafe {
+                gl.uniform_4_f32_slice(
+                    Some(&self.mask_bounds_location),
+                    &bounds,
+                );
+                gl.uniform_4_f32_slice(
+                    Some(&self.mask_radius_location),
+                    &radius,
+                );
+            }
+
+            self.current_mask = mask;
+        }
+
         // This looks a bit crazy, but we are just counting how many vertices
         // and indices we will need to handle.
         // TODO: Improve readability
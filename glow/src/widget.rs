@@ -9,25 +9,36 @@
 //! ```
 use crate::Renderer;
 
+pub mod blur;
 pub mod button;
 pub mod checkbox;
+pub mod code_view;
 pub mod container;
+pub mod opacity;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod text_input;
 
+#[doc(no_inline)]
+pub use blur::Blur;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use code_view::CodeView;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -36,10 +47,14 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use text_input::TextInput;
@@ -52,6 +67,14 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub mod qr_code;
@@ -60,11 +83,19 @@ pub mod qr_code;
 #[doc(no_inline)]
 pub use qr_code::QRCode;
 
-pub use iced_native::{Image, Space};
+pub use iced_native::{constrained, responsive, text, Image, Space};
 
 /// A container that distributes its contents vertically.
 pub type Column<'a, Message> = iced_native::Column<'a, Message, Renderer>;
 
+/// An element constraining the size of its content.
+pub type Constrained<'a, Message> =
+    iced_native::Constrained<'a, Message, Renderer>;
+
+/// A widget that produces its content from the space available.
+pub type Responsive<'a, Message> =
+    iced_native::Responsive<'a, Message, Renderer>;
+
 /// A container that distributes its contents horizontally.
 pub type Row<'a, Message> = iced_native::Row<'a, Message, Renderer>;
 
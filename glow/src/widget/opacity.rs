@@ -0,0 +1,8 @@
+//! Composite some content with a given opacity.
+use crate::Renderer;
+
+/// An element drawing its content with some opacity.
+///
+/// This is an alias of an `iced_native` opacity with a default
+/// `Renderer`.
+pub type Opacity<'a, Message> = iced_native::Opacity<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Display read-only text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::selectable_text::StyleSheet;
+pub use iced_native::selectable_text::State;
+
+/// A paragraph of read-only text that can be selected and copied.
+///
+/// This is an alias of an `iced_native` selectable text with an
+/// `iced_glow::Renderer`.
+pub type SelectableText<'a> = iced_native::SelectableText<'a, Renderer>;
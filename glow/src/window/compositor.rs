@@ -1,8 +1,9 @@
-use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+use crate::backend::{self, Backend};
+use crate::{Color, Error, Renderer, Settings, Viewport};
 
 use core::ffi::c_void;
 use glow::HasContext;
-use iced_graphics::{Antialiasing, Size};
+use iced_graphics::{Antialiasing, Primitive, Size};
 use iced_native::mouse;
 
 /// A window graphics backend for iced powered by `glow`.
@@ -73,4 +74,33 @@ impl iced_graphics::window::GLCompositor for Compositor {
 
         renderer.backend_mut().draw(gl, viewport, output, overlay)
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        output: &(Primitive, mouse::Interaction),
+        overlay: &[T],
+    ) -> Option<(Vec<u8>, mouse::Interaction)> {
+        let mouse_interaction = self.draw(
+            renderer,
+            viewport,
+            background_color,
+            output,
+            &[] as &[&str],
+        );
+
+        let pixels = backend::read_pixels(&self.gl, viewport.physical_size());
+
+        // The overlay is drawn on top of the captured frame
+        let _ = renderer.backend_mut().draw(
+            &self.gl,
+            viewport,
+            &(Primitive::None, mouse_interaction),
+            overlay,
+        );
+
+        Some((pixels, mouse_interaction))
+    }
 }
@@ -8,6 +8,8 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
+use iced_winit::ime;
+use iced_winit::window::Screenshot;
 use iced_winit::{Cache, Clipboard, Debug, Proxy, Settings};
 
 use glutin::window::Window;
@@ -48,7 +50,6 @@ where
 
     let subscription = application.subscription();
 
-    runtime.spawn(init_command);
     runtime.track(subscription);
 
     let context = {
@@ -80,12 +81,21 @@ where
     };
 
     #[allow(unsafe_code)]
-    let (compositor, renderer) = unsafe {
+    let (compositor, mut renderer) = unsafe {
         C::new(compositor_settings, |address| {
             context.get_proc_address(address)
         })?
     };
 
+    let mut screenshots = Vec::new();
+
+    let _ = application::run_command(
+        init_command,
+        &mut renderer,
+        &mut runtime,
+        &mut screenshots,
+    );
+
     let (mut sender, receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
@@ -96,6 +106,7 @@ where
         runtime,
         debug,
         receiver,
+        screenshots,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -128,6 +139,7 @@ async fn run_instance<A, E, C>(
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    mut screenshots: Vec<Box<dyn Fn(Screenshot) -> A::Message + Send>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -152,7 +164,9 @@ async fn run_instance<A, E, C>(
     let mut primitive =
         user_interface.draw(&mut renderer, state.cursor_position());
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut caret_bounds = None;
 
+    let mut ime = ime::Ime::new();
     let mut events = Vec::new();
     let mut messages = Vec::new();
 
@@ -161,6 +175,8 @@ async fn run_instance<A, E, C>(
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                ime.flush(&mut events);
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -182,15 +198,18 @@ async fn run_instance<A, E, C>(
                 }
 
                 if !messages.is_empty() {
-                    let cache =
+                    let mut cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
-                    application::update(
+                    application::update_with(
                         &mut application,
+                        &mut cache,
+                        &mut renderer,
                         &mut runtime,
                         &mut debug,
                         &mut messages,
+                        &mut screenshots,
                     );
 
                     // Update window
@@ -211,6 +230,21 @@ async fn run_instance<A, E, C>(
                     user_interface.draw(&mut renderer, state.cursor_position());
                 debug.draw_finished();
 
+                let new_caret_bounds = user_interface.caret_bounds(&renderer);
+
+                if new_caret_bounds != caret_bounds {
+                    if let Some(bounds) = new_caret_bounds {
+                        context.window().set_ime_position(
+                            conversion::ime_position(
+                                bounds,
+                                state.scale_factor(),
+                            ),
+                        );
+                    }
+
+                    caret_bounds = new_caret_bounds;
+                }
+
                 context.window().request_redraw();
             }
             event::Event::UserEvent(message) => {
@@ -246,13 +280,49 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                let new_mouse_interaction = compositor.draw(
-                    &mut renderer,
-                    state.viewport(),
-                    state.background_color(),
-                    &primitive,
-                    &debug.overlay(),
-                );
+                let screenshot = if screenshots.is_empty() {
+                    None
+                } else {
+                    compositor.screenshot(
+                        &mut renderer,
+                        state.viewport(),
+                        state.background_color(),
+                        &primitive,
+                        &debug.overlay(),
+                    )
+                };
+
+                let new_mouse_interaction = match screenshot {
+                    Some((bytes, mouse_interaction)) => {
+                        let screenshot = Screenshot {
+                            bytes,
+                            size: state.physical_size(),
+                        };
+
+                        application::deliver_screenshots(
+                            screenshot,
+                            &mut screenshots,
+                            &mut runtime,
+                        );
+
+                        mouse_interaction
+                    }
+                    None => {
+                        if !screenshots.is_empty() {
+                            log::error!("The frame could not be captured");
+
+                            screenshots.clear();
+                        }
+
+                        compositor.draw(
+                            &mut renderer,
+                            state.viewport(),
+                            state.background_color(),
+                            &primitive,
+                            &debug.overlay(),
+                        )
+                    }
+                };
 
                 context.swap_buffers().expect("Swap buffers");
 
@@ -280,13 +350,12 @@ async fn run_instance<A, E, C>(
 
                 state.update(context.window(), &window_event, &mut debug);
 
-                if let Some(event) = conversion::window_event(
+                ime.window_event(
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
-                ) {
-                    events.push(event);
-                }
+                    &mut events,
+                );
             }
             _ => {}
         }
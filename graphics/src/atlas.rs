@@ -0,0 +1,223 @@
+//! Allocate images in the layers of a texture atlas.
+mod allocation;
+mod allocator;
+mod entry;
+mod layer;
+
+pub use allocation::Allocation;
+pub use allocator::{Allocator, Region};
+pub use entry::{Entry, Fragment};
+pub use layer::Layer;
+
+/// The width and height of every layer of an [`Atlas`], in pixels.
+pub const SIZE: u32 = 2048;
+
+/// A texture atlas made of square layers.
+///
+/// It only keeps track of the allocations of every layer. The textures
+/// themselves are held by the [`Storage`] of a backend.
+#[derive(Debug)]
+pub struct Atlas {
+    layers: Vec<Layer>,
+}
+
+impl Atlas {
+    /// Creates a new [`Atlas`] with a single empty layer.
+    pub fn new() -> Self {
+        Atlas {
+            layers: vec![Layer::Empty],
+        }
+    }
+
+    /// Returns the layers of the [`Atlas`].
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    /// Returns the amount of layers of the [`Atlas`].
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Allocates an image of the given size and uploads its BGRA pixels to
+    /// the [`Storage`], growing it first if new layers are needed.
+    pub fn upload(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        storage: &mut impl Storage,
+    ) -> Option<Entry> {
+        let entry = {
+            let current_size = self.layers.len();
+            let entry = self.allocate(width, height)?;
+
+            // We grow the storage after allocating if necessary
+            if self.layers.len() > current_size {
+                storage.grow(&self.layers);
+            }
+
+            entry
+        };
+
+        log::info!("Allocated atlas entry: {:?}", entry);
+
+        storage.upload(width, height, data, &entry);
+
+        log::info!("Current atlas: {:?}", self);
+
+        Some(entry)
+    }
+
+    /// Frees the allocations of the given [`Entry`].
+    pub fn remove(&mut self, entry: &Entry) {
+        log::info!("Removing atlas entry: {:?}", entry);
+
+        match entry {
+            Entry::Contiguous(allocation) => {
+                self.deallocate(allocation);
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    self.deallocate(&fragment.allocation);
+                }
+            }
+        }
+    }
+
+    fn allocate(&mut self, width: u32, height: u32) -> Option<Entry> {
+        // Allocate one layer if texture fits perfectly
+        if width == SIZE && height == SIZE {
+            let mut empty_layers = self
+                .layers
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, layer)| layer.is_empty());
+
+            if let Some((i, layer)) = empty_layers.next() {
+                *layer = Layer::Full;
+
+                return Some(Entry::Contiguous(Allocation::Full { layer: i }));
+            }
+
+            self.layers.push(Layer::Full);
+
+            return Some(Entry::Contiguous(Allocation::Full {
+                layer: self.layers.len() - 1,
+            }));
+        }
+
+        // Split big textures across multiple layers
+        if width > SIZE || height > SIZE {
+            let mut fragments = Vec::new();
+            let mut y = 0;
+
+            while y < height {
+                let height = std::cmp::min(height - y, SIZE);
+                let mut x = 0;
+
+                while x < width {
+                    let width = std::cmp::min(width - x, SIZE);
+
+                    let allocation = self.allocate(width, height)?;
+
+                    if let Entry::Contiguous(allocation) = allocation {
+                        fragments.push(Fragment {
+                            position: (x, y),
+                            allocation,
+                        });
+                    }
+
+                    x += width;
+                }
+
+                y += height;
+            }
+
+            return Some(Entry::Fragmented {
+                size: (width, height),
+                fragments,
+            });
+        }
+
+        // Try allocating on an existing layer
+        for (i, layer) in self.layers.iter_mut().enumerate() {
+            match layer {
+                Layer::Empty => {
+                    let mut allocator = Allocator::new(SIZE);
+
+                    if let Some(region) = allocator.allocate(width, height) {
+                        *layer = Layer::Busy(allocator);
+
+                        return Some(Entry::Contiguous(Allocation::Partial {
+                            region,
+                            layer: i,
+                        }));
+                    }
+                }
+                Layer::Busy(allocator) => {
+                    if let Some(region) = allocator.allocate(width, height) {
+                        return Some(Entry::Contiguous(Allocation::Partial {
+                            region,
+                            layer: i,
+                        }));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Create new layer with atlas allocator
+        let mut allocator = Allocator::new(SIZE);
+
+        if let Some(region) = allocator.allocate(width, height) {
+            self.layers.push(Layer::Busy(allocator));
+
+            return Some(Entry::Contiguous(Allocation::Partial {
+                region,
+                layer: self.layers.len() - 1,
+            }));
+        }
+
+        // We ran out of memory (?)
+        None
+    }
+
+    fn deallocate(&mut self, allocation: &Allocation) {
+        log::info!("Deallocating atlas: {:?}", allocation);
+
+        match allocation {
+            Allocation::Full { layer } => {
+                self.layers[*layer] = Layer::Empty;
+            }
+            Allocation::Partial { layer, region } => {
+                let layer = &mut self.layers[*layer];
+
+                if let Layer::Busy(allocator) = layer {
+                    allocator.deallocate(region);
+
+                    if allocator.is_empty() {
+                        *layer = Layer::Empty;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for Atlas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The textures holding the layers of an [`Atlas`].
+pub trait Storage {
+    /// Grows the [`Storage`] to hold all the given layers, keeping the
+    /// contents of the ones it already holds.
+    fn grow(&mut self, layers: &[Layer]);
+
+    /// Uploads the BGRA pixels of an image of the given size to the
+    /// allocations of its [`Entry`].
+    fn upload(&mut self, width: u32, height: u32, data: &[u8], entry: &Entry);
+}
@@ -1,17 +1,26 @@
-use crate::image::atlas::{self, allocator};
+use crate::atlas::{self, allocator};
 
+/// An allocation in a single layer of an [`Atlas`](super::Atlas).
 #[derive(Debug)]
 pub enum Allocation {
+    /// A region of a layer.
     Partial {
+        /// The index of the layer.
         layer: usize,
+
+        /// The allocated region of the layer.
         region: allocator::Region,
     },
+
+    /// A whole layer.
     Full {
+        /// The index of the layer.
         layer: usize,
     },
 }
 
 impl Allocation {
+    /// Returns the position of the [`Allocation`] in its layer.
     pub fn position(&self) -> (u32, u32) {
         match self {
             Allocation::Partial { region, .. } => region.position(),
@@ -19,6 +28,7 @@ impl Allocation {
         }
     }
 
+    /// Returns the size of the [`Allocation`].
     pub fn size(&self) -> (u32, u32) {
         match self {
             Allocation::Partial { region, .. } => region.size(),
@@ -26,6 +36,7 @@ impl Allocation {
         }
     }
 
+    /// Returns the index of the layer of the [`Allocation`].
     pub fn layer(&self) -> usize {
         match self {
             Allocation::Partial { layer, .. } => *layer,
@@ -1,11 +1,13 @@
 use guillotiere::{AtlasAllocator, Size};
 
+/// The allocator of a busy [`Layer`](super::Layer).
 pub struct Allocator {
     raw: AtlasAllocator,
     allocations: usize,
 }
 
 impl Allocator {
+    /// Creates a new [`Allocator`] for a square layer of the given size.
     pub fn new(size: u32) -> Allocator {
         let raw = AtlasAllocator::new(Size::new(size as i32, size as i32));
 
@@ -15,6 +17,7 @@ impl Allocator {
         }
     }
 
+    /// Allocates a [`Region`] of the given size, if there is room for it.
     pub fn allocate(&mut self, width: u32, height: u32) -> Option<Region> {
         let allocation =
             self.raw.allocate(Size::new(width as i32, height as i32))?;
@@ -24,28 +27,33 @@ impl Allocator {
         Some(Region { allocation })
     }
 
+    /// Frees the given [`Region`].
     pub fn deallocate(&mut self, region: &Region) {
         self.raw.deallocate(region.allocation.id);
 
         self.allocations = self.allocations.saturating_sub(1);
     }
 
+    /// Returns true if the [`Allocator`] has no regions allocated.
     pub fn is_empty(&self) -> bool {
         self.allocations == 0
     }
 }
 
+/// A region allocated by an [`Allocator`].
 pub struct Region {
     allocation: guillotiere::Allocation,
 }
 
 impl Region {
+    /// Returns the position of the [`Region`] in its layer.
     pub fn position(&self) -> (u32, u32) {
         let rectangle = &self.allocation.rectangle;
 
         (rectangle.min.x as u32, rectangle.min.y as u32)
     }
 
+    /// Returns the size of the [`Region`].
     pub fn size(&self) -> (u32, u32) {
         let size = self.allocation.rectangle.size();
 
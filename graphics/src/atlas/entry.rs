@@ -1,16 +1,23 @@
-use crate::image::atlas;
+use crate::atlas;
 
+/// An image allocated in an [`Atlas`](super::Atlas).
 #[derive(Debug)]
 pub enum Entry {
+    /// An image that fits in a single layer.
     Contiguous(atlas::Allocation),
+
+    /// An image bigger than a layer, split in fragments across many of them.
     Fragmented {
+        /// The size of the whole image.
         size: (u32, u32),
+
+        /// The fragments of the image.
         fragments: Vec<Fragment>,
     },
 }
 
 impl Entry {
-    #[cfg(feature = "image")]
+    /// Returns the size of the image of the [`Entry`].
     pub fn size(&self) -> (u32, u32) {
         match self {
             Entry::Contiguous(allocation) => allocation.size(),
@@ -19,8 +26,12 @@ impl Entry {
     }
 }
 
+/// A fragment of a [`Entry::Fragmented`] image.
 #[derive(Debug)]
 pub struct Fragment {
+    /// The position of the fragment in the image.
     pub position: (u32, u32),
+
+    /// The allocation of the fragment.
     pub allocation: atlas::Allocation,
 }
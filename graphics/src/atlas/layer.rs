@@ -0,0 +1,21 @@
+use crate::atlas::Allocator;
+
+/// A layer of an [`Atlas`](super::Atlas).
+#[derive(Debug)]
+pub enum Layer {
+    /// The layer has no allocations.
+    Empty,
+
+    /// The layer has some regions allocated.
+    Busy(Allocator),
+
+    /// The layer is taken by a single allocation of its whole size.
+    Full,
+}
+
+impl Layer {
+    /// Returns true if the [`Layer`] has no allocations.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Layer::Empty)
+    }
+}
@@ -1,7 +1,15 @@
 //! Write a graphics backend.
+use iced_native::font;
 use iced_native::image;
 use iced_native::svg;
-use iced_native::{Font, Size};
+use iced_native::text::Spacing;
+use iced_native::{
+    Direction, Font, HorizontalAlignment, LineHeight, Rectangle, Size,
+    VerticalAlignment,
+};
+
+use std::borrow::Cow;
+use std::ops::Range;
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -13,6 +21,16 @@ pub trait Backend {
     /// `iced_wgpu` and `iced_glow` because of limitations in the text rendering
     /// pipeline. It will be removed in the future.
     fn trim_measurements(&mut self) {}
+
+    /// Loads a font from its bytes.
+    ///
+    /// By default, it returns [`font::Error::Unsupported`].
+    fn load_font(
+        &mut self,
+        _bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        Err(font::Error::Unsupported)
+    }
 }
 
 /// A graphics backend that supports text rendering.
@@ -33,16 +51,111 @@ pub trait Text {
     /// Returns the default size of text.
     fn default_size(&self) -> u16;
 
-    /// Measures the text contents with the given size and font,
-    /// returning the size of a laid out paragraph that fits in the provided
-    /// bounds.
+    /// Measures the text contents with the given size, font, [`Spacing`] and
+    /// [`Direction`], returning the size of a laid out paragraph that fits in
+    /// the provided bounds.
     fn measure(
         &self,
         contents: &str,
         size: f32,
         font: Font,
         bounds: Size,
+        spacing: Spacing,
+        direction: Direction,
+    ) -> (f32, f32);
+
+    /// Returns the distance from the top of a line of text to its baseline,
+    /// given the size, font and [`LineHeight`] of the text.
+    ///
+    /// By default, it is 80% of the size of the text.
+    fn baseline(
+        &self,
+        size: f32,
+        _font: Font,
+        _line_height: Option<LineHeight>,
+    ) -> f32 {
+        size * 0.8
+    }
+
+    /// Measures a paragraph made of the given spans with the provided
+    /// [`Spacing`] and [`Direction`], returning the size of the laid out
+    /// paragraph that fits in the provided bounds.
+    fn measure_spans(
+        &self,
+        spans: &[Span<'_>],
+        spacing: Spacing,
+        direction: Direction,
+        bounds: Size,
     ) -> (f32, f32);
+
+    /// Lays out a paragraph made of the given spans with the provided
+    /// [`Spacing`] and [`Direction`] in the given bounds and returns the
+    /// [`Fragment`]s of every span, relative to the top-left corner of the
+    /// bounds.
+    fn span_fragments(
+        &self,
+        spans: &[Span<'_>],
+        spacing: Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<Fragment>;
+
+    /// Lays out the contents of the given [`Span`] with the provided
+    /// [`Spacing`] and [`Direction`] in the given bounds and returns the byte
+    /// offset and the bounds of every visible character, relative to the
+    /// top-left corner of the bounds.
+    ///
+    /// The bounds of a character span the whole height of its line.
+    fn character_bounds(
+        &self,
+        span: Span<'_>,
+        spacing: Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<(usize, Rectangle)>;
+
+    /// Wraps the contents of the given [`Span`] with the provided [`Spacing`]
+    /// and [`Direction`] to the given width and returns the byte range of
+    /// every line.
+    fn line_breaks(
+        &self,
+        span: Span<'_>,
+        spacing: Spacing,
+        direction: Direction,
+        width: f32,
+    ) -> Vec<Range<usize>>;
+}
+
+/// A piece of text of a paragraph, with its own size and font.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The font of the [`Span`].
+    pub font: Font,
+}
+
+/// A part of a [`Span`] laid out in a single line.
+///
+/// A [`Span`] is split into many fragments when it wraps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fragment {
+    /// The index of the [`Span`] of the [`Fragment`].
+    pub span: usize,
+
+    /// The bounds of the [`Fragment`].
+    pub bounds: Rectangle,
+
+    /// The vertical position of the baseline of the [`Fragment`].
+    pub baseline: f32,
 }
 
 /// A graphics backend that supports image rendering.
@@ -0,0 +1,811 @@
+//! Export primitives to other formats.
+use crate::layer::{self, Layer};
+use crate::{
+    Color, HorizontalAlignment, Primitive, Rectangle, Transformation,
+    VerticalAlignment, Viewport,
+};
+
+use iced_native::font::{self, Family};
+use iced_native::{image, svg, Font};
+
+use std::fmt::{self, Write};
+use std::fs;
+
+/// The line height used for text without one, as a factor of its size.
+const DEFAULT_LINE_HEIGHT: f32 = 1.2;
+
+/// Exports the given [`Primitive`] to a standalone SVG document of the
+/// logical size of the [`Viewport`].
+///
+/// The primitive is distributed in layers with [`Layer::generate`], like
+/// backends do, so the document stacks its contents in the same order as a
+/// frame would. Images are embedded, including the ones loaded from a path;
+/// the ones that cannot be read are skipped.
+///
+/// Some features cannot be represented in SVG and are approximated:
+///
+/// - Backdrop blurs and custom primitives are ignored.
+/// - Each triangle of a mesh is filled with the average color of its
+///   vertices, so gradients become flat.
+/// - Text is laid out by the viewer of the document, with the fonts it
+///   has available.
+pub fn to_svg(
+    primitive: &Primitive,
+    viewport: &Viewport,
+    background_color: Color,
+) -> String {
+    let mut document = String::new();
+
+    write_document(&mut document, primitive, viewport, background_color)
+        .expect("Write SVG document");
+
+    document
+}
+
+fn write_document(
+    out: &mut String,
+    primitive: &Primitive,
+    viewport: &Viewport,
+    background_color: Color,
+) -> fmt::Result {
+    let size = viewport.logical_size();
+    let layers = Layer::generate(primitive, viewport);
+
+    writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         xmlns:xlink=\"http://www.w3.org/1999/xlink\" \
+         width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">",
+        width = size.width,
+        height = size.height,
+    )?;
+
+    if background_color.a > 0.0 {
+        writeln!(
+            out,
+            "<rect width=\"100%\" height=\"100%\" {}/>",
+            paint("fill", background_color.into_linear()),
+        )?;
+    }
+
+    let mut ids = 0;
+    let mut groups: Vec<usize> = Vec::new();
+
+    for layer in &layers {
+        let innermost = match layer.opacity {
+            Some(opacity) if groups.contains(&opacity.group) => {
+                Some(opacity.group)
+            }
+            Some(opacity) => opacity.parent,
+            None => None,
+        };
+
+        // The layers of an opacity group, including its nested groups, are
+        // composited together
+        while let Some(group) = groups.last().copied() {
+            if Some(group) == innermost {
+                break;
+            }
+
+            let _ = groups.pop();
+            writeln!(out, "</g>")?;
+        }
+
+        if let Some(opacity) = layer.opacity {
+            if innermost != Some(opacity.group) {
+                writeln!(out, "<g opacity=\"{}\">", opacity.alpha)?;
+                groups.push(opacity.group);
+            }
+        }
+
+        write_layer(out, layer, &mut ids)?;
+    }
+
+    for _ in groups {
+        writeln!(out, "</g>")?;
+    }
+
+    writeln!(out, "</svg>")
+}
+
+fn write_layer(
+    out: &mut String,
+    layer: &Layer<'_>,
+    ids: &mut usize,
+) -> fmt::Result {
+    if layer.quads.is_empty()
+        && layer.meshes.is_empty()
+        && layer.text.is_empty()
+        && layer.rich_text.is_empty()
+        && layer.images.is_empty()
+    {
+        return Ok(());
+    }
+
+    let bounds = layer.bounds;
+    let clip = next_id(ids);
+
+    writeln!(
+        out,
+        "<clipPath id=\"clip{}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" \
+         height=\"{}\"/></clipPath>",
+        clip, bounds.x, bounds.y, bounds.width, bounds.height,
+    )?;
+    writeln!(out, "<g clip-path=\"url(#clip{})\">", clip)?;
+
+    if let Some(mask) = layer.mask {
+        let id = next_id(ids);
+
+        writeln!(
+            out,
+            "<clipPath id=\"clip{}\"><path d=\"{}\"/></clipPath>",
+            id,
+            rounded_rectangle(mask.bounds, mask.border_radius),
+        )?;
+        writeln!(out, "<g clip-path=\"url(#clip{})\">", id)?;
+    }
+
+    // The clipping bounds are not affected by the transformation
+    let transformed = layer.transformation != Transformation::identity();
+
+    if transformed {
+        let matrix: [f32; 16] = layer.transformation.into();
+
+        writeln!(
+            out,
+            "<g transform=\"matrix({} {} {} {} {} {})\">",
+            matrix[0], matrix[1], matrix[4], matrix[5], matrix[12], matrix[13],
+        )?;
+    }
+
+    for quad in &layer.quads {
+        write_quad(out, quad, ids)?;
+    }
+
+    for mesh in &layer.meshes {
+        write_mesh(out, mesh)?;
+    }
+
+    for image in &layer.images {
+        write_image(out, image)?;
+    }
+
+    for text in &layer.text {
+        write_text(out, text)?;
+    }
+
+    for text in &layer.rich_text {
+        write_rich_text(out, text)?;
+    }
+
+    if transformed {
+        writeln!(out, "</g>")?;
+    }
+
+    if layer.mask.is_some() {
+        writeln!(out, "</g>")?;
+    }
+
+    writeln!(out, "</g>")
+}
+
+fn write_quad(
+    out: &mut String,
+    quad: &layer::Quad,
+    ids: &mut usize,
+) -> fmt::Result {
+    let bounds = Rectangle {
+        x: quad.position[0],
+        y: quad.position[1],
+        width: quad.size[0],
+        height: quad.size[1],
+    };
+
+    if quad.shadow_color[3] > 0.0 {
+        let spread = quad.shadow_spread;
+        let shadow = Rectangle {
+            x: bounds.x + quad.shadow_offset[0] - spread,
+            y: bounds.y + quad.shadow_offset[1] - spread,
+            width: (bounds.width + spread * 2.0).max(0.0),
+            height: (bounds.height + spread * 2.0).max(0.0),
+        };
+
+        let [top_left, top_right, bottom_right, bottom_left] =
+            quad.border_radius;
+        let radii = [
+            (top_left + spread).max(0.0),
+            (top_right + spread).max(0.0),
+            (bottom_right + spread).max(0.0),
+            (bottom_left + spread).max(0.0),
+        ];
+
+        // Like in CSS, the blur radius is twice the standard deviation
+        let deviation = quad.shadow_blur_radius / 2.0;
+
+        if deviation > 0.0 {
+            let id = next_id(ids);
+            let margin = deviation * 3.0;
+
+            writeln!(
+                out,
+                "<filter id=\"shadow{}\" filterUnits=\"userSpaceOnUse\" \
+                 x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\">\
+                 <feGaussianBlur stdDeviation=\"{}\"/></filter>",
+                id,
+                shadow.x - margin,
+                shadow.y - margin,
+                shadow.width + margin * 2.0,
+                shadow.height + margin * 2.0,
+                deviation,
+            )?;
+            writeln!(
+                out,
+                "<path d=\"{}\" {} filter=\"url(#shadow{})\"/>",
+                rounded_rectangle(shadow, radii),
+                paint("fill", quad.shadow_color),
+                id,
+            )?;
+        } else {
+            writeln!(
+                out,
+                "<path d=\"{}\" {}/>",
+                rounded_rectangle(shadow, radii),
+                paint("fill", quad.shadow_color),
+            )?;
+        }
+    }
+
+    writeln!(
+        out,
+        "<path d=\"{}\" {}/>",
+        rounded_rectangle(bounds, quad.border_radius),
+        paint("fill", quad.color),
+    )?;
+
+    let width = quad.border_width;
+
+    if width > 0.0 && quad.border_color[3] > 0.0 {
+        // Strokes are centered on their path, so the border is drawn on a
+        // path shrunk by half its width to keep it inside the quad
+        let half = width / 2.0;
+        let inner = Rectangle {
+            x: bounds.x + half,
+            y: bounds.y + half,
+            width: (bounds.width - width).max(0.0),
+            height: (bounds.height - width).max(0.0),
+        };
+
+        let [top_left, top_right, bottom_right, bottom_left] =
+            quad.border_radius;
+        let radii = [
+            (top_left - half).max(0.0),
+            (top_right - half).max(0.0),
+            (bottom_right - half).max(0.0),
+            (bottom_left - half).max(0.0),
+        ];
+
+        writeln!(
+            out,
+            "<path d=\"{}\" fill=\"none\" stroke-width=\"{}\" {}/>",
+            rounded_rectangle(inner, radii),
+            width,
+            paint("stroke", quad.border_color),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_mesh(out: &mut String, mesh: &layer::Mesh<'_>) -> fmt::Result {
+    let vertices = &mesh.buffers.vertices;
+
+    writeln!(
+        out,
+        "<g transform=\"translate({} {})\">",
+        mesh.origin.x, mesh.origin.y
+    )?;
+
+    // Consecutive triangles of the same color are merged in a single path,
+    // which avoids seams between them
+    let mut current: Option<([f32; 4], String)> = None;
+
+    for triangle in mesh.buffers.indices.chunks_exact(3) {
+        let [a, b, c] = [
+            &vertices[triangle[0] as usize],
+            &vertices[triangle[1] as usize],
+            &vertices[triangle[2] as usize],
+        ];
+
+        let mut color = [0.0; 4];
+
+        for (i, component) in color.iter_mut().enumerate() {
+            *component = (a.color[i] + b.color[i] + c.color[i]) / 3.0;
+        }
+
+        let segment = format!(
+            "M{} {}L{} {}L{} {}Z",
+            a.position[0],
+            a.position[1],
+            b.position[0],
+            b.position[1],
+            c.position[0],
+            c.position[1],
+        );
+
+        match &mut current {
+            Some((current_color, path)) if *current_color == color => {
+                path.push_str(&segment);
+            }
+            _ => {
+                if let Some((color, path)) = current.take() {
+                    writeln!(
+                        out,
+                        "<path d=\"{}\" {}/>",
+                        path,
+                        paint("fill", color)
+                    )?;
+                }
+
+                current = Some((color, segment));
+            }
+        }
+    }
+
+    if let Some((color, path)) = current {
+        writeln!(out, "<path d=\"{}\" {}/>", path, paint("fill", color))?;
+    }
+
+    writeln!(out, "</g>")
+}
+
+fn write_text(out: &mut String, text: &layer::Text<'_>) -> fmt::Result {
+    let line_height = text
+        .line_height
+        .map_or(text.size * DEFAULT_LINE_HEIGHT, |line_height| {
+            line_height.to_absolute(text.size)
+        });
+
+    let lines: Vec<&str> = text.content.lines().collect();
+
+    write!(
+        out,
+        "<text font-size=\"{}\" {} {} {}",
+        text.size,
+        font_attributes(text.font),
+        text_anchor(text.horizontal_alignment),
+        paint("fill", text.color),
+    )?;
+
+    if text.letter_spacing != 0.0 {
+        write!(out, " letter-spacing=\"{}\"", text.letter_spacing)?;
+    }
+
+    write!(out, ">")?;
+
+    let top = text_top(
+        text.bounds,
+        text.vertical_alignment,
+        line_height * lines.len() as f32,
+    );
+
+    for (i, line) in lines.iter().enumerate() {
+        write!(
+            out,
+            "<tspan x=\"{}\" y=\"{}\" dominant-baseline=\"central\">{}</tspan>",
+            text.bounds.x,
+            top + line_height * (i as f32 + 0.5),
+            escape(line),
+        )?;
+    }
+
+    writeln!(out, "</text>")
+}
+
+fn write_rich_text(
+    out: &mut String,
+    text: &layer::RichText<'_>,
+) -> fmt::Result {
+    let line_height = match (text.line_height, text.spans.first()) {
+        (Some(line_height), Some(span)) => line_height.to_absolute(span.size),
+        _ => text
+            .spans
+            .iter()
+            .map(|span| span.size * DEFAULT_LINE_HEIGHT)
+            .fold(0.0, f32::max),
+    };
+
+    write!(
+        out,
+        "<text x=\"{}\" y=\"{}\" dominant-baseline=\"central\" {}",
+        text.bounds.x,
+        text_top(text.bounds, text.vertical_alignment, line_height)
+            + line_height / 2.0,
+        text_anchor(text.horizontal_alignment),
+    )?;
+
+    if text.letter_spacing != 0.0 {
+        write!(out, " letter-spacing=\"{}\"", text.letter_spacing)?;
+    }
+
+    write!(out, ">")?;
+
+    for span in &text.spans {
+        write!(
+            out,
+            "<tspan font-size=\"{}\" {} {}>{}</tspan>",
+            span.size,
+            font_attributes(span.font),
+            paint("fill", span.color),
+            escape(span.content),
+        )?;
+    }
+
+    writeln!(out, "</text>")
+}
+
+fn write_image(out: &mut String, image: &layer::Image) -> fmt::Result {
+    let (bounds, href) = match image {
+        layer::Image::Raster { handle, bounds } => {
+            let href = match handle.data() {
+                image::Data::Path(path) => fs::read(path)
+                    .map(|bytes| data_url(raster_mime_type(&bytes), &bytes))
+                    .map_err(|error| error.to_string()),
+                image::Data::Bytes(bytes) => {
+                    Ok(data_url(raster_mime_type(bytes), bytes))
+                }
+                image::Data::Pixels {
+                    width,
+                    height,
+                    pixels,
+                } => png(*width, *height, pixels)
+                    .map(|png| data_url("image/png", &png))
+                    .map_err(|error| error.to_string()),
+            };
+
+            (bounds, href)
+        }
+        layer::Image::Vector { handle, bounds } => {
+            let href = match handle.data() {
+                svg::Data::Path(path) => fs::read(path)
+                    .map(|bytes| data_url("image/svg+xml", &bytes))
+                    .map_err(|error| error.to_string()),
+                svg::Data::Bytes(bytes) => Ok(data_url("image/svg+xml", bytes)),
+            };
+
+            (bounds, href)
+        }
+    };
+
+    let href = match href {
+        Ok(href) => href,
+        Err(error) => {
+            log::warn!("Skipping image that cannot be embedded: {}", error);
+
+            return Ok(());
+        }
+    };
+
+    writeln!(
+        out,
+        "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+         preserveAspectRatio=\"none\" xlink:href=\"{}\"/>",
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+        escape(&href),
+    )
+}
+
+fn next_id(ids: &mut usize) -> usize {
+    let id = *ids;
+    *ids += 1;
+
+    id
+}
+
+/// Returns the path data of a rectangle with the given corner radii,
+/// clockwise from the top-left one.
+fn rounded_rectangle(bounds: Rectangle, border_radius: [f32; 4]) -> String {
+    let max = bounds.width.min(bounds.height) / 2.0;
+    let [top_left, top_right, bottom_right, bottom_left] = border_radius;
+    let [top_left, top_right, bottom_right, bottom_left] = [
+        top_left.min(max).max(0.0),
+        top_right.min(max).max(0.0),
+        bottom_right.min(max).max(0.0),
+        bottom_left.min(max).max(0.0),
+    ];
+
+    let Rectangle {
+        x,
+        y,
+        width,
+        height,
+    } = bounds;
+
+    format!(
+        "M{} {}H{}A{r1} {r1} 0 0 1 {} {}V{}A{r2} {r2} 0 0 1 {} {}H{}\
+         A{r3} {r3} 0 0 1 {} {}V{}A{r0} {r0} 0 0 1 {} {}Z",
+        x + top_left,
+        y,
+        x + width - top_right,
+        x + width,
+        y + top_right,
+        y + height - bottom_right,
+        x + width - bottom_right,
+        y + height,
+        x + bottom_left,
+        x,
+        y + height - bottom_left,
+        y + top_left,
+        x + top_left,
+        y,
+        r0 = top_left,
+        r1 = top_right,
+        r2 = bottom_right,
+        r3 = bottom_left,
+    )
+}
+
+/// Returns the attributes painting the given property with a __linear RGB__
+/// color.
+fn paint(property: &str, color: [f32; 4]) -> String {
+    // As described in:
+    // https://en.wikipedia.org/wiki/SRGB#The_forward_transformation_(CIE_XYZ_to_sRGB)
+    fn srgb_component(u: f32) -> u8 {
+        let u = u.clamp(0.0, 1.0);
+
+        let srgb = if u < 0.003_130_8 {
+            u * 12.92
+        } else {
+            1.055 * u.powf(1.0 / 2.4) - 0.055
+        };
+
+        (srgb * 255.0).round() as u8
+    }
+
+    let [r, g, b, a] = color;
+
+    let mut attributes = format!(
+        "{}=\"#{:02x}{:02x}{:02x}\"",
+        property,
+        srgb_component(r),
+        srgb_component(g),
+        srgb_component(b),
+    );
+
+    if a < 1.0 {
+        attributes.push_str(&format!(" {}-opacity=\"{}\"", property, a));
+    }
+
+    attributes
+}
+
+fn font_attributes(font: Font) -> String {
+    match font {
+        Font::Default => String::from("font-family=\"sans-serif\""),
+        Font::External { name, .. } => {
+            format!("font-family=\"{}\"", escape(name))
+        }
+        Font::Descriptor(descriptor) => {
+            let family = match descriptor.family {
+                Family::Name(name) => escape(name),
+                Family::Serif => String::from("serif"),
+                Family::SansSerif => String::from("sans-serif"),
+                Family::Cursive => String::from("cursive"),
+                Family::Fantasy => String::from("fantasy"),
+                Family::Monospace => String::from("monospace"),
+            };
+
+            let style = match descriptor.style {
+                font::Style::Normal => "normal",
+                font::Style::Italic => "italic",
+                font::Style::Oblique => "oblique",
+            };
+
+            format!(
+                "font-family=\"{}\" font-weight=\"{}\" font-style=\"{}\"",
+                family,
+                descriptor.weight.value(),
+                style,
+            )
+        }
+    }
+}
+
+fn text_anchor(alignment: HorizontalAlignment) -> &'static str {
+    match alignment {
+        HorizontalAlignment::Left => "text-anchor=\"start\"",
+        HorizontalAlignment::Center => "text-anchor=\"middle\"",
+        HorizontalAlignment::Right => "text-anchor=\"end\"",
+    }
+}
+
+/// Returns the top of a block of text of the given height, whose vertical
+/// position is anchored like its alignment.
+fn text_top(
+    bounds: Rectangle,
+    alignment: VerticalAlignment,
+    height: f32,
+) -> f32 {
+    match alignment {
+        VerticalAlignment::Top => bounds.y,
+        VerticalAlignment::Center => bounds.y - height / 2.0,
+        VerticalAlignment::Bottom => bounds.y - height,
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Guesses the MIME type of encoded image data from its signature.
+fn raster_mime_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        "image/webp"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn data_url(mime_type: &str, bytes: &[u8]) -> String {
+    format!("data:{};base64,{}", mime_type, base64::encode(bytes))
+}
+
+/// Encodes BGRA pixels as a PNG.
+fn png(
+    width: u32,
+    height: u32,
+    pixels: &[u8],
+) -> Result<Vec<u8>, png::EncodingError> {
+    let mut rgba = Vec::with_capacity(pixels.len());
+
+    for pixel in pixels.chunks_exact(4) {
+        rgba.extend_from_slice(&[pixel[2], pixel[1], pixel[0], pixel[3]]);
+    }
+
+    let mut png = Vec::new();
+
+    {
+        let mut encoder = png::Encoder::new(&mut png, width, height);
+        encoder.set_color(png::ColorType::RGBA);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&rgba)?;
+    }
+
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Background, Font, Point, Size};
+    use iced_native::Direction;
+
+    #[test]
+    fn exports_quads_and_text() {
+        let primitive = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds: Rectangle::new(
+                        Point::new(10.0, 10.0),
+                        Size::new(100.0, 50.0),
+                    ),
+                    background: Background::Color(Color::WHITE),
+                    border_radius: 5.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: None,
+                },
+                Primitive::Text {
+                    content: String::from("Fish & Chips"),
+                    bounds: Rectangle::new(
+                        Point::new(60.0, 35.0),
+                        Size::new(100.0, 50.0),
+                    ),
+                    color: Color::BLACK,
+                    size: 20.0,
+                    font: Font::Default,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                    line_height: None,
+                    letter_spacing: 0.0,
+                    direction: Direction::LeftToRight,
+                },
+            ],
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 100), 1.0);
+        let document = to_svg(&primitive, &viewport, Color::BLACK);
+
+        assert!(document.starts_with("<svg "));
+        assert!(document.contains("viewBox=\"0 0 200 100\""));
+        assert!(document.contains("fill=\"#ffffff\""));
+        assert!(document.contains("text-anchor=\"middle\""));
+        assert!(document.contains(">Fish &amp; Chips</tspan>"));
+        assert!(document.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn nests_opacity_groups() {
+        let quad = |x: f32| Primitive::Quad {
+            bounds: Rectangle::new(Point::new(x, 0.0), Size::new(50.0, 50.0)),
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: None,
+        };
+
+        let primitive = Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    quad(0.0),
+                    Primitive::Opacity {
+                        opacity: 0.25,
+                        content: Box::new(quad(25.0)),
+                    },
+                    quad(50.0),
+                ],
+            }),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(100, 50), 1.0);
+        let document = to_svg(&primitive, &viewport, Color::TRANSPARENT);
+
+        let outer = document.find("<g opacity=\"0.5\">").unwrap();
+        let inner = document.find("<g opacity=\"0.25\">").unwrap();
+        let inner_end = document[inner..].find("</g>").unwrap() + inner;
+        let last_quad = document.find("d=\"M50 0").unwrap();
+
+        // The outer group is not split by the inner one
+        assert_eq!(document.matches("<g opacity=\"0.5\">").count(), 1);
+        assert!(outer < inner);
+        assert!(inner_end < last_quad);
+        assert!(document[last_quad..].contains("</g>"));
+    }
+
+    #[test]
+    fn embeds_images_loaded_from_a_path() {
+        let path = std::env::temp_dir().join("iced_graphics_export.png");
+        fs::write(&path, png(1, 1, &[0, 0, 255, 255]).unwrap()).unwrap();
+
+        let primitive = Primitive::Image {
+            handle: image::Handle::from_path(&path),
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0)),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(10, 10), 1.0);
+        let document = to_svg(&primitive, &viewport, Color::BLACK);
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(document.contains("xlink:href=\"data:image/png;base64,"));
+        assert!(!document.contains("iced_graphics_export.png"));
+    }
+}
@@ -4,7 +4,7 @@ mod source;
 
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
-pub use source::Source;
+pub use source::{Coverage, Source};
 
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
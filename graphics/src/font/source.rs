@@ -1,5 +1,10 @@
 use crate::font::{Family, LoadError};
 
+use iced_native::font::{self, Descriptor};
+
+use std::collections::HashMap;
+use std::fmt;
+
 /// A font source that can find and load system fonts.
 #[allow(missing_debug_implementations)]
 pub struct Source {
@@ -21,19 +26,144 @@ impl Source {
             &font_kit::properties::Properties::default(),
         )?;
 
-        match font {
-            font_kit::handle::Handle::Path { path, .. } => {
-                use std::io::Read;
+        let (data, _) = read(font);
 
-                let mut buf = Vec::new();
-                let mut reader = std::fs::File::open(path).expect("Read font");
-                let _ = reader.read_to_end(&mut buf);
+        Ok(data)
+    }
 
-                Ok(buf)
-            }
-            font_kit::handle::Handle::Memory { bytes, .. } => {
-                Ok(bytes.as_ref().clone())
+    /// Finds and loads the system font that best matches the given
+    /// [`Descriptor`].
+    ///
+    /// Returns the data of the font and the index of the face inside of it.
+    pub fn load_descriptor(
+        &self,
+        descriptor: &Descriptor,
+    ) -> Result<(Vec<u8>, u32), LoadError> {
+        use font_kit::properties;
+
+        let family = match descriptor.family {
+            font::Family::Name(name) => Family::Title(String::from(name)),
+            font::Family::Serif => Family::Serif,
+            font::Family::SansSerif => Family::SansSerif,
+            font::Family::Cursive => Family::Cursive,
+            font::Family::Fantasy => Family::Fantasy,
+            font::Family::Monospace => Family::Monospace,
+        };
+
+        let properties = properties::Properties {
+            weight: properties::Weight(f32::from(descriptor.weight.value())),
+            style: match descriptor.style {
+                font::Style::Normal => properties::Style::Normal,
+                font::Style::Italic => properties::Style::Italic,
+                font::Style::Oblique => properties::Style::Oblique,
+            },
+            stretch: properties::Stretch(descriptor.stretch.ratio()),
+        };
+
+        let font = self.raw.select_best_match(&[family], &properties)?;
+
+        Ok(read(font))
+    }
+
+    /// Builds the [`Coverage`] of all the system fonts.
+    ///
+    /// Every system font is read once, so this is expensive! Build it only
+    /// when a fallback font is needed, and keep it around.
+    pub fn coverage(&self) -> Coverage {
+        let handles = self.raw.all_fonts().unwrap_or_default();
+        let mut fonts = HashMap::new();
+
+        for (i, handle) in handles.iter().enumerate() {
+            let (data, index) = match try_read(handle) {
+                Some(font) => font,
+                None => continue,
+            };
+
+            let face = match ttf_parser::Face::from_slice(&data, index) {
+                Ok(face) => face,
+                Err(_) => continue,
+            };
+
+            for subtable in face.character_mapping_subtables() {
+                if !subtable.is_unicode() {
+                    continue;
+                }
+
+                subtable.codepoints(|codepoint| {
+                    let c = match std::char::from_u32(codepoint) {
+                        Some(c) if !fonts.contains_key(&c) => c,
+                        _ => return,
+                    };
+
+                    // Codepoints may still be mapped to the missing glyph
+                    let is_mapped = subtable
+                        .glyph_index(codepoint)
+                        .filter(|glyph| glyph.0 != 0)
+                        .is_some();
+
+                    if is_mapped {
+                        let _ = fonts.insert(c, i);
+                    }
+                });
             }
         }
+
+        Coverage { handles, fonts }
+    }
+}
+
+/// An index of the system fonts by the characters they contain.
+pub struct Coverage {
+    handles: Vec<font_kit::handle::Handle>,
+    fonts: HashMap<char, usize>,
+}
+
+impl Coverage {
+    /// Loads the first system font containing a glyph for the given
+    /// character, if any.
+    ///
+    /// Returns the data of the font and the index of the face inside of it.
+    pub fn load(&self, c: char) -> Option<(Vec<u8>, u32)> {
+        let handle = self.handles.get(*self.fonts.get(&c)?)?;
+
+        try_read(handle)
+    }
+}
+
+impl fmt::Debug for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Coverage")
+            .field("fonts", &self.handles.len())
+            .field("characters", &self.fonts.len())
+            .finish()
+    }
+}
+
+fn read(handle: font_kit::handle::Handle) -> (Vec<u8>, u32) {
+    match handle {
+        font_kit::handle::Handle::Path { path, font_index } => {
+            use std::io::Read;
+
+            let mut buf = Vec::new();
+            let mut reader = std::fs::File::open(path).expect("Read font");
+            let _ = reader.read_to_end(&mut buf);
+
+            (buf, font_index)
+        }
+        font_kit::handle::Handle::Memory { bytes, font_index } => {
+            (bytes.as_ref().clone(), font_index)
+        }
+    }
+}
+
+/// Reads the data of a font, without panicking if its file cannot be read.
+fn try_read(handle: &font_kit::handle::Handle) -> Option<(Vec<u8>, u32)> {
+    match handle {
+        font_kit::handle::Handle::Path { path, font_index } => {
+            Some((std::fs::read(path).ok()?, *font_index))
+        }
+        font_kit::handle::Handle::Memory { bytes, font_index } => {
+            Some((bytes.as_ref().clone(), *font_index))
+        }
     }
 }
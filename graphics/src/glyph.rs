@@ -0,0 +1,629 @@
+//! Load fonts and measure text for a `glyph_brush` text pipeline.
+use crate::backend;
+use crate::font;
+use crate::shaping;
+use glyph_brush::ab_glyph;
+use glyph_brush::{
+    BuiltInLineBreaker, FontId, GlyphCruncher, HorizontalAlign, Layout,
+    Section, SectionGlyph, Text, VerticalAlign,
+};
+use iced_native::font::Descriptor;
+use iced_native::{text, Direction, LineHeight, Rectangle, Size};
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+
+#[cfg(feature = "font-source")]
+use std::collections::HashSet;
+
+/// The fonts and the measurements shared by the text pipelines of the
+/// different backends.
+///
+/// A [`Cache`] keeps a `glyph_brush` to measure text. The fonts it loads
+/// need to be added to the brush used to draw, in the same order, so their
+/// [`FontId`] match. See [`Cache::take_new_fonts`].
+#[derive(Debug)]
+pub struct Cache {
+    measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    font_map: RefCell<HashMap<String, FontId>>,
+    descriptor_font_map: RefCell<HashMap<Descriptor, FontId>>,
+    fonts: RefCell<shaping::Fonts>,
+    new_fonts: RefCell<Vec<ab_glyph::FontArc>>,
+    #[cfg(feature = "font-source")]
+    system_fonts: RefCell<HashMap<Descriptor, Option<FontId>>>,
+    #[cfg(feature = "font-source")]
+    known_characters: RefCell<HashSet<char>>,
+    #[cfg(feature = "font-source")]
+    coverage: RefCell<Option<font::Coverage>>,
+}
+
+impl Cache {
+    /// Creates a new [`Cache`] with the given default font.
+    ///
+    /// If no default font is provided, a system font is used if the
+    /// `font-source` feature is enabled. Otherwise, the [`font::FALLBACK`]
+    /// font is used.
+    pub fn new(default_font: Option<&[u8]>) -> Self {
+        let default_font = default_font.map(|slice| slice.to_vec());
+
+        // TODO: Font customization
+        #[cfg(feature = "font-source")]
+        let default_font = {
+            default_font.or_else(|| {
+                font::Source::new()
+                    .load(&[font::Family::SansSerif, font::Family::Serif])
+                    .ok()
+            })
+        };
+
+        let default_font =
+            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+        let mut fonts = shaping::Fonts::new();
+
+        let font = match ab_glyph::FontArc::try_from_vec(default_font.clone()) {
+            Ok(font) => {
+                fonts.push(default_font, 0);
+                font
+            }
+            Err(_) => {
+                log::warn!(
+                    "System font failed to load. Falling back to \
+                    embedded font..."
+                );
+
+                fonts.push(font::FALLBACK, 0);
+
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            }
+        };
+
+        let measure_brush =
+            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        Cache {
+            measure_brush: RefCell::new(measure_brush),
+            font_map: RefCell::new(HashMap::new()),
+            descriptor_font_map: RefCell::new(HashMap::new()),
+            fonts: RefCell::new(fonts),
+            new_fonts: RefCell::new(Vec::new()),
+            #[cfg(feature = "font-source")]
+            system_fonts: RefCell::new(HashMap::new()),
+            #[cfg(feature = "font-source")]
+            known_characters: RefCell::new(HashSet::new()),
+            #[cfg(feature = "font-source")]
+            coverage: RefCell::new(None),
+        }
+    }
+
+    /// Returns the default font of the [`Cache`].
+    ///
+    /// It should be the first font of the brush used to draw.
+    pub fn default_font(&self) -> ab_glyph::FontArc {
+        self.measure_brush.borrow().fonts()[0].clone()
+    }
+
+    /// Returns the fonts loaded since the last call, in the order they need
+    /// to be added to the brush used to draw.
+    pub fn take_new_fonts(&self) -> Vec<ab_glyph::FontArc> {
+        std::mem::take(&mut *self.new_fonts.borrow_mut())
+    }
+
+    /// Prepares a [`Section`] to be drawn, loading any fallback font it needs,
+    /// and returns the [`shaping::Layout`] to position its glyphs with.
+    ///
+    /// The fonts loaded will be returned by the next call to
+    /// [`Cache::take_new_fonts`].
+    pub fn prepare(
+        &self,
+        section: &Section<'_>,
+        line_height: Option<f32>,
+        letter_spacing: f32,
+        direction: Direction,
+    ) -> shaping::Layout {
+        for text in &section.text {
+            self.load_fallback_fonts(text.text);
+        }
+
+        shaping::Layout::new(&self.fonts.borrow(), section.layout)
+            .line_height(line_height)
+            .letter_spacing(letter_spacing)
+            .direction(direction)
+    }
+
+    /// Measures the given content with the given size, font, spacing, and
+    /// direction in the given bounds.
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: Size,
+        spacing: text::Spacing,
+        direction: Direction,
+    ) -> (f32, f32) {
+        self.measure_paragraph(
+            &[backend::Span {
+                content,
+                size,
+                font,
+            }],
+            bounds,
+            spacing.line_height.map(|height| height.to_absolute(size)),
+            spacing.letter_spacing,
+            direction,
+        )
+    }
+
+    /// Measures the given spans laid out as a single paragraph with the given
+    /// spacing and direction in the given bounds.
+    ///
+    /// A relative line height is resolved using the size of the first span.
+    pub fn measure_spans(
+        &self,
+        spans: &[backend::Span<'_>],
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Size,
+    ) -> (f32, f32) {
+        let line_height = spacing.line_height.and_then(|height| {
+            spans.first().map(|span| height.to_absolute(span.size))
+        });
+
+        self.measure_paragraph(
+            spans,
+            bounds,
+            line_height,
+            spacing.letter_spacing,
+            direction,
+        )
+    }
+
+    fn measure_paragraph(
+        &self,
+        spans: &[backend::Span<'_>],
+        bounds: Size,
+        line_height: Option<f32>,
+        letter_spacing: f32,
+        direction: Direction,
+    ) -> (f32, f32) {
+        let section = self.measure_section(spans, bounds, Layout::default());
+
+        let layout = shaping::Layout::new(&self.fonts.borrow(), section.layout)
+            .line_height(line_height)
+            .letter_spacing(letter_spacing)
+            .direction(direction);
+
+        // The bounds of a glyph computed by the brush only cover the line
+        // of its font
+        if let Some(line_height) = line_height {
+            let mut measure_brush = self.measure_brush.borrow_mut();
+
+            let glyphs: Vec<_> = measure_brush
+                .glyphs_custom_layout(section, &layout)
+                .cloned()
+                .collect();
+
+            let (width, height) =
+                shaping::measure(measure_brush.fonts(), &glyphs, line_height);
+
+            return (width.ceil(), height.ceil());
+        }
+
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    /// Returns the [`backend::Fragment`]s of the given spans laid out as a
+    /// single paragraph with the given spacing and direction in the given
+    /// bounds.
+    pub fn span_fragments(
+        &self,
+        spans: &[backend::Span<'_>],
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlign,
+        vertical_alignment: VerticalAlign,
+    ) -> Vec<backend::Fragment> {
+        let glyphs = self.layout_glyphs(
+            spans,
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+            spacing,
+            direction,
+        );
+
+        shaping::fragments(self.measure_brush.borrow().fonts(), &glyphs)
+    }
+
+    /// Returns the byte offset and the bounds of every character of the given
+    /// span laid out in the given bounds.
+    pub fn character_bounds(
+        &self,
+        span: backend::Span<'_>,
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlign,
+        vertical_alignment: VerticalAlign,
+    ) -> Vec<(usize, Rectangle)> {
+        let glyphs = self.layout_glyphs(
+            &[span],
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+            spacing,
+            direction,
+        );
+
+        shaping::characters(
+            self.measure_brush.borrow().fonts(),
+            &glyphs,
+            spacing
+                .line_height
+                .map(|height| height.to_absolute(span.size)),
+        )
+    }
+
+    /// Returns the byte range of every line of the given span wrapped to the
+    /// given width.
+    pub fn line_breaks(
+        &self,
+        span: backend::Span<'_>,
+        spacing: text::Spacing,
+        direction: Direction,
+        width: f32,
+    ) -> Vec<Range<usize>> {
+        let section = self.measure_section(
+            &[span],
+            Size::new(width, f32::INFINITY),
+            Layout::default(),
+        );
+
+        let layout = shaping::Layout::new(&self.fonts.borrow(), section.layout)
+            .line_height(
+                spacing
+                    .line_height
+                    .map(|height| height.to_absolute(span.size)),
+            )
+            .letter_spacing(spacing.letter_spacing)
+            .direction(direction);
+
+        layout.line_breaks(
+            self.measure_brush.borrow().fonts(),
+            width,
+            &section.text,
+        )
+    }
+
+    /// Lays out the given spans with the provided spacing in the given
+    /// bounds, positioning the glyphs relative to the top-left corner of the
+    /// bounds.
+    ///
+    /// A relative line height is resolved using the size of the first span.
+    fn layout_glyphs(
+        &self,
+        spans: &[backend::Span<'_>],
+        bounds: Size,
+        horizontal_alignment: HorizontalAlign,
+        vertical_alignment: VerticalAlign,
+        spacing: text::Spacing,
+        direction: Direction,
+    ) -> Vec<SectionGlyph> {
+        let mut section = self.measure_section(
+            spans,
+            bounds,
+            Layout::default()
+                .h_align(horizontal_alignment)
+                .v_align(vertical_alignment),
+        );
+
+        section.screen_position = (
+            match horizontal_alignment {
+                HorizontalAlign::Left => 0.0,
+                HorizontalAlign::Center => bounds.width / 2.0,
+                HorizontalAlign::Right => bounds.width,
+            },
+            match vertical_alignment {
+                VerticalAlign::Top => 0.0,
+                VerticalAlign::Center => bounds.height / 2.0,
+                VerticalAlign::Bottom => bounds.height,
+            },
+        );
+
+        let line_height = spacing.line_height.and_then(|height| {
+            spans.first().map(|span| height.to_absolute(span.size))
+        });
+
+        let layout = shaping::Layout::new(&self.fonts.borrow(), section.layout)
+            .line_height(line_height)
+            .letter_spacing(spacing.letter_spacing)
+            .direction(direction);
+
+        self.measure_brush
+            .borrow_mut()
+            .glyphs_custom_layout(section, &layout)
+            .cloned()
+            .collect()
+    }
+
+    fn measure_section<'a>(
+        &self,
+        spans: &[backend::Span<'a>],
+        bounds: Size,
+        layout: Layout<BuiltInLineBreaker>,
+    ) -> Section<'a> {
+        let text = spans
+            .iter()
+            .map(|span| {
+                self.load_fallback_fonts(span.content);
+
+                Text {
+                    text: span.content,
+                    scale: span.size.into(),
+                    font_id: self.find_font(span.font),
+                    extra: glyph_brush::Extra::default(),
+                }
+            })
+            .collect();
+
+        Section {
+            bounds: (bounds.width, bounds.height),
+            text,
+            layout,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the distance from the top of a line of text with the given
+    /// size, font, and line height to its baseline.
+    pub fn baseline(
+        &self,
+        size: f32,
+        font: iced_native::Font,
+        line_height: Option<LineHeight>,
+    ) -> f32 {
+        use ab_glyph::{Font, ScaleFont};
+
+        let FontId(font_id) = self.find_font(font);
+
+        let measure_brush = self.measure_brush.borrow();
+        let font = measure_brush.fonts()[font_id].as_scaled(size);
+
+        match line_height {
+            Some(line_height) => {
+                let leading = line_height.to_absolute(size)
+                    - (font.ascent() - font.descent());
+
+                font.ascent() + leading / 2.0
+            }
+            None => font.ascent(),
+        }
+    }
+
+    /// Discards the measurements that have not been used since the last
+    /// trim.
+    pub fn trim_measurement_cache(&mut self) {
+        // TODO: We should probably use a `GlyphCalculator` for this. However,
+        // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
+        // This makes stuff quite inconvenient. A manual method for trimming the
+        // cache would make our lives easier.
+        loop {
+            let action = self
+                .measure_brush
+                .borrow_mut()
+                .process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.measure_brush
+                        .borrow_mut()
+                        .resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    /// Loads the font contained in the given bytes.
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), iced_native::font::Error> {
+        let font = match bytes.clone() {
+            Cow::Borrowed(bytes) => ab_glyph::FontArc::try_from_slice(bytes),
+            Cow::Owned(bytes) => ab_glyph::FontArc::try_from_vec(bytes),
+        }
+        .map_err(|_| iced_native::font::Error::Invalid)?;
+
+        let font_id = self.add_font(font, bytes, 0);
+
+        // Only the descriptors the new font matches better need to be
+        // resolved again
+        let fonts = self.fonts.borrow();
+
+        self.descriptor_font_map
+            .borrow_mut()
+            .retain(|descriptor, _| {
+                fonts.find(descriptor).map(|(closest, _)| closest)
+                    != Some(font_id)
+            });
+
+        Ok(())
+    }
+
+    /// Returns the [`FontId`] of the given font, loading it if necessary.
+    pub fn find_font(&self, font: iced_native::Font) -> FontId {
+        match font {
+            iced_native::Font::Default => FontId(0),
+            iced_native::Font::External { name, bytes } => {
+                if let Some(font_id) = self.font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                    .expect("Load font");
+
+                let font_id = self.add_font(font, bytes, 0);
+
+                let _ = self
+                    .font_map
+                    .borrow_mut()
+                    .insert(String::from(name), font_id);
+
+                font_id
+            }
+            iced_native::Font::Descriptor(descriptor) => {
+                if let Some(font_id) =
+                    self.descriptor_font_map.borrow().get(&descriptor)
+                {
+                    return *font_id;
+                }
+
+                let font_id = self.resolve(&descriptor);
+
+                let _ = self
+                    .descriptor_font_map
+                    .borrow_mut()
+                    .insert(descriptor, font_id);
+
+                font_id
+            }
+        }
+    }
+
+    /// Resolves a [`Descriptor`] to an exact match among the loaded fonts, a
+    /// system font, or the closest loaded font, in that order.
+    ///
+    /// The default font is used if nothing matches.
+    fn resolve(&self, descriptor: &Descriptor) -> FontId {
+        let closest = self.fonts.borrow().find(descriptor);
+
+        match closest {
+            Some((font_id, true)) => font_id,
+            _ => self
+                .load_system_font(descriptor)
+                .or_else(|| closest.map(|(font_id, _)| font_id))
+                .unwrap_or(FontId(0)),
+        }
+    }
+
+    /// Loads the system font that best matches the given [`Descriptor`].
+    ///
+    /// The result is remembered, so the system is only queried once per
+    /// [`Descriptor`].
+    #[cfg(feature = "font-source")]
+    fn load_system_font(&self, descriptor: &Descriptor) -> Option<FontId> {
+        if let Some(font_id) = self.system_fonts.borrow().get(descriptor) {
+            return *font_id;
+        }
+
+        let font_id = font::Source::new()
+            .load_descriptor(descriptor)
+            .ok()
+            .and_then(|(data, index)| self.add_system_font(data, index));
+
+        let _ = self.system_fonts.borrow_mut().insert(*descriptor, font_id);
+
+        font_id
+    }
+
+    #[cfg(not(feature = "font-source"))]
+    fn load_system_font(&self, _descriptor: &Descriptor) -> Option<FontId> {
+        None
+    }
+
+    /// Adds a system font, unless the same face has been loaded already.
+    #[cfg(feature = "font-source")]
+    fn add_system_font(&self, data: Vec<u8>, index: u32) -> Option<FontId> {
+        // Different descriptors and characters may resolve to the same face
+        if let Some(font_id) = self.fonts.borrow().position(&data, index) {
+            return Some(font_id);
+        }
+
+        let font =
+            ab_glyph::FontVec::try_from_vec_and_index(data.clone(), index)
+                .ok()?;
+
+        Some(self.add_font(ab_glyph::FontArc::new(font), data, index))
+    }
+
+    fn add_font(
+        &self,
+        font: ab_glyph::FontArc,
+        data: impl Into<Cow<'static, [u8]>>,
+        index: u32,
+    ) -> FontId {
+        self.fonts.borrow_mut().push(data, index);
+        self.new_fonts.borrow_mut().push(font.clone());
+
+        self.measure_brush.borrow_mut().add_font(font)
+    }
+
+    /// Loads the system fonts needed to display the characters of the given
+    /// content that none of the current fonts contain.
+    ///
+    /// Characters are only looked up in the fonts the first time they are
+    /// seen, since text is measured and drawn every frame. The system fonts
+    /// are only scanned once, the first time a character is not covered.
+    #[cfg(feature = "font-source")]
+    fn load_fallback_fonts(&self, content: &str) {
+        let unknown: Vec<char> = {
+            let known_characters = self.known_characters.borrow();
+
+            content
+                .chars()
+                .filter(|c| !known_characters.contains(c))
+                .collect()
+        };
+
+        if unknown.is_empty() {
+            return;
+        }
+
+        for c in unknown {
+            if !self.known_characters.borrow_mut().insert(c) {
+                continue;
+            }
+
+            // A font loaded for a previous character may contain it already
+            let is_covered = shaping::uncovered(
+                self.measure_brush.borrow().fonts(),
+                c.encode_utf8(&mut [0; 4]),
+            )
+            .next()
+            .is_none();
+
+            if is_covered {
+                continue;
+            }
+
+            let fallback = self
+                .coverage
+                .borrow_mut()
+                .get_or_insert_with(|| font::Source::new().coverage())
+                .load(c);
+
+            if let Some((data, index)) = fallback {
+                let _ = self.add_system_font(data, index);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "font-source"))]
+    fn load_fallback_fonts(&self, _content: &str) {}
+}
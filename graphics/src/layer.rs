@@ -3,9 +3,12 @@ use crate::image;
 use crate::svg;
 use crate::triangle;
 use crate::{
-    Background, Font, HorizontalAlignment, Point, Primitive, Rectangle, Size,
-    Vector, VerticalAlignment, Viewport,
+    Background, Font, HorizontalAlignment, LineHeight, Point, Primitive,
+    Rectangle, Size, Transformation, Vector, VerticalAlignment, Viewport,
 };
+use iced_native::Direction;
+
+use std::any::Any;
 
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
@@ -13,6 +16,33 @@ pub struct Layer<'a> {
     /// The clipping bounds of the [`Layer`].
     pub bounds: Rectangle,
 
+    /// The [`Transformation`] applied to the contents of the [`Layer`].
+    ///
+    /// The clipping bounds are not affected by it.
+    pub transformation: Transformation,
+
+    /// The innermost [`Opacity`] group of the [`Layer`], if any.
+    ///
+    /// The layers of a group, including the ones of its nested groups, should
+    /// be composited together.
+    pub opacity: Option<Opacity>,
+
+    /// The rounded clipping [`Mask`] of the [`Layer`], if any.
+    ///
+    /// Its corners should be cut on top of the clipping bounds.
+    pub mask: Option<Mask>,
+
+    /// The backdrop [`Blur`] of the [`Layer`], if any.
+    ///
+    /// The region beneath it should be blurred before drawing the contents of
+    /// the [`Layer`].
+    pub blur: Option<Blur>,
+
+    /// The [`Custom`] primitive of the [`Layer`], if any.
+    ///
+    /// It should be drawn on top of the other contents of the [`Layer`].
+    pub custom: Option<Custom<'a>>,
+
     /// The quads of the [`Layer`].
     pub quads: Vec<Quad>,
 
@@ -22,6 +52,9 @@ pub struct Layer<'a> {
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 }
@@ -31,13 +64,30 @@ impl<'a> Layer<'a> {
     pub fn new(bounds: Rectangle) -> Self {
         Self {
             bounds,
+            transformation: Transformation::identity(),
+            opacity: None,
+            mask: None,
+            blur: None,
+            custom: None,
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
 
+    /// Creates a new [`Layer`] with the given clipping bounds, keeping the
+    /// transformation, the opacity and the mask of the current one.
+    fn nested(&self, bounds: Rectangle) -> Self {
+        Self {
+            transformation: self.transformation,
+            opacity: self.opacity,
+            mask: self.mask,
+            ..Self::new(bounds)
+        }
+    }
+
     /// Creates a new [`Layer`] for the provided overlay text.
     ///
     /// This can be useful for displaying debug information.
@@ -57,6 +107,9 @@ impl<'a> Layer<'a> {
                 font: Font::Default,
                 horizontal_alignment: HorizontalAlignment::Left,
                 vertical_alignment: VerticalAlignment::Top,
+                line_height: None,
+                letter_spacing: 0.0,
+                direction: Direction::LeftToRight,
             };
 
             overlay.text.push(text);
@@ -108,6 +161,9 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                line_height,
+                letter_spacing,
+                direction,
             } => {
                 let layer = layers.last_mut().unwrap();
 
@@ -119,6 +175,38 @@ impl<'a> Layer<'a> {
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    line_height: *line_height,
+                    letter_spacing: *letter_spacing,
+                    direction: *direction,
+                });
+            }
+            Primitive::RichText {
+                spans,
+                bounds,
+                horizontal_alignment,
+                vertical_alignment,
+                line_height,
+                letter_spacing,
+                direction,
+            } => {
+                let layer = layers.last_mut().unwrap();
+
+                layer.rich_text.push(RichText {
+                    spans: spans
+                        .iter()
+                        .map(|span| Span {
+                            content: &span.content,
+                            color: span.color.into_linear(),
+                            size: span.size,
+                            font: span.font,
+                        })
+                        .collect(),
+                    bounds: *bounds + translation,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
+                    line_height: *line_height,
+                    letter_spacing: *letter_spacing,
+                    direction: *direction,
                 });
             }
             Primitive::Quad {
@@ -127,6 +215,7 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = layers.last_mut().unwrap();
 
@@ -140,18 +229,27 @@ impl<'a> Layer<'a> {
                     color: match background {
                         Background::Color(color) => color.into_linear(),
                     },
-                    border_radius: *border_radius,
+                    border_radius: (*border_radius).into(),
                     border_width: *border_width,
                     border_color: border_color.into_linear(),
+                    shadow_color: shadow
+                        .map_or([0.0; 4], |shadow| shadow.color.into_linear()),
+                    shadow_offset: shadow.map_or([0.0; 2], |shadow| {
+                        [shadow.offset.x, shadow.offset.y]
+                    }),
+                    shadow_blur_radius: shadow
+                        .map_or(0.0, |shadow| shadow.blur_radius),
+                    shadow_spread: shadow.map_or(0.0, |shadow| shadow.spread),
                 });
             }
             Primitive::Mesh2D { buffers, size } => {
                 let layer = layers.last_mut().unwrap();
 
-                let bounds = Rectangle::new(
-                    Point::new(translation.x, translation.y),
-                    *size,
-                );
+                let bounds =
+                    layer.transformation.transform_rectangle(Rectangle::new(
+                        Point::new(translation.x, translation.y),
+                        *size,
+                    ));
 
                 // Only draw visible content
                 if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
@@ -165,17 +263,29 @@ impl<'a> Layer<'a> {
             Primitive::Clip {
                 bounds,
                 offset,
+                border_radius,
                 content,
             } => {
                 let layer = layers.last_mut().unwrap();
-                let translated_bounds = *bounds + translation;
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
 
                 // Only draw visible content
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
-                    let new_layer = Layer::new(layer.bounds);
+                    let mut clip_layer = layer.nested(clip_bounds);
+                    let new_layer = layer.nested(layer.bounds);
+
+                    // A rectangular clip keeps the rounded corners of its
+                    // parent, while a rounded one replaces them
+                    if !border_radius.is_zero() {
+                        clip_layer.mask = Some(Mask {
+                            bounds: translated_bounds,
+                            border_radius: (*border_radius).into(),
+                        });
+                    }
 
                     layers.push(clip_layer);
                     Self::process_primitive(
@@ -197,6 +307,119 @@ impl<'a> Layer<'a> {
                     &content,
                 );
             }
+            Primitive::Transform {
+                transformation,
+                content,
+            } => {
+                let layer = layers.last_mut().unwrap();
+
+                // The current translation is folded into the transformation
+                // of the new layer, so its contents start at the origin
+                let transformed_layer = Layer {
+                    transformation: layer.transformation
+                        * Transformation::translate(
+                            translation.x,
+                            translation.y,
+                        )
+                        * *transformation,
+                    ..layer.nested(layer.bounds)
+                };
+                let new_layer = layer.nested(layer.bounds);
+
+                layers.push(transformed_layer);
+                Self::process_primitive(layers, Vector::new(0.0, 0.0), content);
+                layers.push(new_layer);
+            }
+            Primitive::Opacity { opacity, content } => {
+                let layer = layers.last().unwrap();
+
+                let alpha = opacity.clamp(0.0, 1.0);
+                let cumulative_alpha = alpha
+                    * layer
+                        .opacity
+                        .map_or(1.0, |opacity| opacity.cumulative_alpha);
+
+                if cumulative_alpha <= 0.0 {
+                    return;
+                }
+
+                if alpha >= 1.0 {
+                    Self::process_primitive(layers, translation, content);
+                    return;
+                }
+
+                // Every group starts with a new layer, so its index can be
+                // used to identify it
+                let group = Opacity {
+                    group: layers.len(),
+                    parent: layer.opacity.map(|opacity| opacity.group),
+                    alpha,
+                    cumulative_alpha,
+                };
+
+                let group_layer = Layer {
+                    opacity: Some(group),
+                    ..layer.nested(layer.bounds)
+                };
+                let new_layer = layer.nested(layer.bounds);
+
+                layers.push(group_layer);
+                Self::process_primitive(layers, translation, content);
+                layers.push(new_layer);
+            }
+            Primitive::Blur { bounds, radius } => {
+                if *radius <= 0.0 {
+                    return;
+                }
+
+                let layer = layers.last().unwrap();
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
+
+                // Only blur visible content
+                if let Some(blur_bounds) =
+                    layer.bounds.intersection(&translated_bounds)
+                {
+                    // The blur has no content of its own, so it can be
+                    // applied right before the primitives drawn after it
+                    let blur_layer = Layer {
+                        blur: Some(Blur {
+                            bounds: blur_bounds,
+                            radius: *radius,
+                        }),
+                        ..layer.nested(layer.bounds)
+                    };
+
+                    layers.push(blur_layer);
+                }
+            }
+            Primitive::Custom { bounds, content } => {
+                let layer = layers.last().unwrap();
+                let translated_bounds = layer
+                    .transformation
+                    .transform_rectangle(*bounds + translation);
+
+                // Only draw visible content
+                if let Some(clip_bounds) =
+                    layer.bounds.intersection(&translated_bounds)
+                {
+                    // The custom primitive gets a layer of its own, so it is
+                    // stacked between the primitives before and after it
+                    let custom_layer = Layer {
+                        custom: Some(Custom {
+                            bounds: translated_bounds,
+                            clip_bounds,
+                            content: content.as_ref(),
+                        }),
+                        ..layer.nested(layer.bounds)
+                    };
+                    let new_layer = layer.nested(layer.bounds);
+
+                    layers.push(custom_layer);
+                    layers.push(new_layer);
+                }
+            }
             Primitive::Cached { cache } => {
                 Self::process_primitive(layers, translation, &cache);
             }
@@ -220,6 +443,95 @@ impl<'a> Layer<'a> {
     }
 }
 
+/// A group of layers that is composited with some opacity.
+///
+/// Groups can be nested. A nested group is composited into its parent group,
+/// which is composited once all of its layers have been drawn.
+///
+/// Every group starts with its own layer and its layers are contiguous.
+/// Therefore, the identifier of a group is the index of its first layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Opacity {
+    /// The identifier of the group.
+    pub group: usize,
+
+    /// The identifier of the group containing this one, if any.
+    pub parent: Option<usize>,
+
+    /// The opacity of the group relative to its parent, from 0 to 1.
+    pub alpha: f32,
+
+    /// The product of the opacities of the group and all of its ancestors.
+    pub cumulative_alpha: f32,
+}
+
+/// A clipping region with rounded corners.
+///
+/// Only the innermost rounded clip of some content is honored. Any clip
+/// around it only applies its bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mask {
+    /// The bounds of the [`Mask`], in logical pixels.
+    pub bounds: Rectangle,
+
+    /// The radius of each corner of the [`Mask`], clockwise from the
+    /// top-left one.
+    pub border_radius: [f32; 4],
+}
+
+impl std::ops::Mul<f32> for Mask {
+    type Output = Self;
+
+    fn mul(self, scale: f32) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] =
+            self.border_radius;
+
+        Self {
+            bounds: self.bounds * scale,
+            border_radius: [
+                top_left * scale,
+                top_right * scale,
+                bottom_right * scale,
+                bottom_left * scale,
+            ],
+        }
+    }
+}
+
+/// A region of a frame that is blurred before drawing a [`Layer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Blur {
+    /// The bounds of the blurred region, in logical pixels.
+    pub bounds: Rectangle,
+
+    /// The standard deviation of the Gaussian, in logical pixels.
+    pub radius: f32,
+}
+
+impl std::ops::Mul<f32> for Blur {
+    type Output = Self;
+
+    fn mul(self, scale: f32) -> Self {
+        Self {
+            bounds: self.bounds * scale,
+            radius: self.radius * scale,
+        }
+    }
+}
+
+/// A primitive rendered by the backend with its own means.
+#[derive(Debug, Clone, Copy)]
+pub struct Custom<'a> {
+    /// The bounds of the [`Custom`] primitive, in logical pixels.
+    pub bounds: Rectangle,
+
+    /// The clipping bounds of the [`Custom`] primitive, in logical pixels.
+    pub clip_bounds: Rectangle,
+
+    /// The backend-specific content of the [`Custom`] primitive.
+    pub content: &'a (dyn Any + Send + Sync),
+}
+
 /// A colored rectangle with a border.
 ///
 /// This type can be directly uploaded to GPU memory.
@@ -238,11 +550,26 @@ pub struct Quad {
     /// The border color of the [`Quad`], in __linear RGB__.
     pub border_color: [f32; 4],
 
-    /// The border radius of the [`Quad`].
-    pub border_radius: f32,
+    /// The radius of each corner of the [`Quad`], clockwise from the
+    /// top-left one.
+    pub border_radius: [f32; 4],
 
     /// The border width of the [`Quad`].
     pub border_width: f32,
+
+    /// The color of the shadow of the [`Quad`], in __linear RGB__.
+    ///
+    /// A transparent color means the [`Quad`] casts no shadow.
+    pub shadow_color: [f32; 4],
+
+    /// The offset of the shadow of the [`Quad`].
+    pub shadow_offset: [f32; 2],
+
+    /// The blur radius of the shadow of the [`Quad`].
+    pub shadow_blur_radius: f32,
+
+    /// The spread of the shadow of the [`Quad`].
+    pub shadow_spread: f32,
 }
 
 /// A mesh of triangles.
@@ -281,6 +608,61 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: VerticalAlignment,
+
+    /// The [`LineHeight`] of the [`Text`], if different from the one of its
+    /// font.
+    pub line_height: Option<LineHeight>,
+
+    /// The extra space between the characters of the [`Text`].
+    pub letter_spacing: f32,
+
+    /// The base [`Direction`] of the paragraphs of the [`Text`].
+    pub direction: Direction,
+}
+
+/// A paragraph of text made of spans with their own style.
+#[derive(Debug, Clone)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    pub spans: Vec<Span<'a>>,
+
+    /// The layout bounds of the [`RichText`].
+    pub bounds: Rectangle,
+
+    /// The horizontal alignment of the [`RichText`].
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// The vertical alignment of the [`RichText`].
+    pub vertical_alignment: VerticalAlignment,
+
+    /// The [`LineHeight`] of the [`RichText`], if different from the one of
+    /// its fonts.
+    ///
+    /// A relative line height is resolved using the size of the first
+    /// [`Span`].
+    pub line_height: Option<LineHeight>,
+
+    /// The extra space between the characters of the [`RichText`].
+    pub letter_spacing: f32,
+
+    /// The base [`Direction`] of the paragraphs of the [`RichText`].
+    pub direction: Direction,
+}
+
+/// A piece of text with its own style, part of a [`RichText`].
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The color of the [`Span`], in __linear RGB_.
+    pub color: [f32; 4],
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The font of the [`Span`].
+    pub font: Font,
 }
 
 /// A raster or vector image.
@@ -17,12 +17,28 @@ mod renderer;
 mod transformation;
 mod viewport;
 
+#[cfg(any(feature = "image", feature = "svg"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "image", feature = "svg"))))]
+pub mod atlas;
 pub mod backend;
 pub mod defaults;
+#[cfg(feature = "export")]
+#[cfg_attr(docsrs, doc(cfg(feature = "export")))]
+pub mod export;
 pub mod font;
+#[cfg(feature = "font-fallback")]
+#[cfg_attr(docsrs, doc(cfg(feature = "font-fallback")))]
+pub mod glyph;
 pub mod layer;
 pub mod overlay;
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod raster;
+pub mod shaping;
 pub mod triangle;
+#[cfg(feature = "svg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
+pub mod vector;
 pub mod widget;
 pub mod window;
 
@@ -34,12 +50,12 @@ pub use backend::Backend;
 pub use defaults::Defaults;
 pub use error::Error;
 pub use layer::Layer;
-pub use primitive::Primitive;
+pub use primitive::{Primitive, Span};
 pub use renderer::Renderer;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 
 pub use iced_native::{
-    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
-    Vector, VerticalAlignment,
+    Background, Color, Font, HorizontalAlignment, LineHeight, Point, Rectangle,
+    Size, Vector, VerticalAlignment,
 };
@@ -2,8 +2,8 @@
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::{
-    mouse, overlay, Color, Font, HorizontalAlignment, Point, Rectangle,
-    VerticalAlignment,
+    mouse, overlay, Color, Direction, Font, HorizontalAlignment, Point,
+    Rectangle, VerticalAlignment,
 };
 
 pub use iced_style::menu::Style;
@@ -29,7 +29,8 @@ where
                         background: style.background,
                         border_color: style.border_color,
                         border_width: style.border_width,
-                        border_radius: 0.0,
+                        border_radius: 0.0.into(),
+                        shadow: style.shadow,
                     },
                     primitives,
                 ],
@@ -81,7 +82,8 @@ where
                     background: style.selected_background,
                     border_color: Color::TRANSPARENT,
                     border_width: 0.0,
-                    border_radius: 0.0,
+                    border_radius: 0.0.into(),
+                    shadow: None,
                 });
             }
 
@@ -102,6 +104,9 @@ where
                 },
                 horizontal_alignment: HorizontalAlignment::Left,
                 vertical_alignment: VerticalAlignment::Center,
+                line_height: None,
+                letter_spacing: 0.0,
+                direction: Direction::LeftToRight,
             });
         }
 
@@ -1,9 +1,12 @@
 use iced_native::{
-    image, svg, Background, Color, Font, HorizontalAlignment, Rectangle, Size,
-    Vector, VerticalAlignment,
+    image, svg, Background, BorderRadius, Color, Direction, Font,
+    HorizontalAlignment, LineHeight, Rectangle, Shadow, Size, Vector,
+    VerticalAlignment,
 };
 
 use crate::triangle;
+use crate::Transformation;
+use std::any::Any;
 use std::sync::Arc;
 
 /// A rendering primitive.
@@ -32,6 +35,29 @@ pub enum Primitive {
         horizontal_alignment: HorizontalAlignment,
         /// The vertical alignment of the text
         vertical_alignment: VerticalAlignment,
+        /// The line height of the text, if different from the one of the font
+        line_height: Option<LineHeight>,
+        /// The extra space between the characters of the text
+        letter_spacing: f32,
+        /// The base direction of the paragraphs of the text
+        direction: Direction,
+    },
+    /// A paragraph of text made of spans with their own style
+    RichText {
+        /// The spans of the text
+        spans: Vec<Span>,
+        /// The bounds of the text
+        bounds: Rectangle,
+        /// The horizontal alignment of the text
+        horizontal_alignment: HorizontalAlignment,
+        /// The vertical alignment of the text
+        vertical_alignment: VerticalAlignment,
+        /// The line height of the text, if different from the one of the font
+        line_height: Option<LineHeight>,
+        /// The extra space between the characters of the text
+        letter_spacing: f32,
+        /// The base direction of the paragraphs of the text
+        direction: Direction,
     },
     /// A quad primitive
     Quad {
@@ -39,12 +65,14 @@ pub enum Primitive {
         bounds: Rectangle,
         /// The background of the quad
         background: Background,
-        /// The border radius of the quad
-        border_radius: f32,
+        /// The radius of each corner of the quad
+        border_radius: BorderRadius,
         /// The border width of the quad
         border_width: f32,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow cast by the quad, if any
+        shadow: Option<Shadow>,
     },
     /// An image primitive
     Image {
@@ -67,6 +95,10 @@ pub enum Primitive {
         bounds: Rectangle,
         /// The offset transformation of the clip
         offset: Vector<u32>,
+        /// The radius of each corner of the clip
+        ///
+        /// Content outside of the rounded corners is hidden.
+        border_radius: BorderRadius,
         /// The content of the clip
         content: Box<Primitive>,
     },
@@ -78,6 +110,53 @@ pub enum Primitive {
         /// The primitive to translate
         content: Box<Primitive>,
     },
+    /// A primitive that applies an affine transformation.
+    ///
+    /// The transformation is relative to the current origin. Therefore, to
+    /// rotate or scale some content around its center, the content should be
+    /// translated so its center lies on the origin first.
+    ///
+    /// Quads, text, images, SVGs and meshes are all transformed. Clips inside
+    /// of the content are applied to the bounding box of their transformed
+    /// bounds.
+    Transform {
+        /// The affine transformation
+        transformation: Transformation,
+
+        /// The primitive to transform
+        content: Box<Primitive>,
+    },
+    /// A primitive that composites its content with some opacity.
+    ///
+    /// The content is rendered as a whole, so overlapping children do not
+    /// show through each other.
+    Opacity {
+        /// The opacity of the content, from 0 to 1
+        opacity: f32,
+
+        /// The primitive to composite
+        content: Box<Primitive>,
+    },
+    /// A backdrop blur primitive
+    ///
+    /// Whatever is drawn beneath its bounds before it gets blurred, like with
+    /// the `backdrop-filter` property of CSS.
+    ///
+    /// Inside a [`Primitive::Opacity`] group, the scene beneath the outermost
+    /// group is blurred; the contents of the groups drawn before it are not.
+    ///
+    /// Backends that cannot sample what they have drawn, like `iced_glow`,
+    /// ignore it.
+    Blur {
+        /// The bounds of the blurred region
+        bounds: Rectangle,
+
+        /// The radius of the blur, in logical pixels
+        ///
+        /// Like with the `blur` function of CSS, it is the standard deviation
+        /// of the Gaussian.
+        radius: f32,
+    },
     /// A low-level primitive to render a mesh of triangles.
     ///
     /// It can be used to render many kinds of geometry freely.
@@ -90,6 +169,20 @@ pub enum Primitive {
         /// Any geometry that falls out of this region will be clipped.
         size: Size,
     },
+    /// A primitive rendered by the backend with its own means.
+    ///
+    /// Each backend downcasts the content to the type it knows how to render,
+    /// like the custom shaders of `iced_wgpu`, and ignores it otherwise.
+    ///
+    /// It is drawn on top of the primitives before it and clipped to its
+    /// bounds. Transformations only move its bounds to their bounding box.
+    Custom {
+        /// The bounds of the custom primitive
+        bounds: Rectangle,
+
+        /// The backend-specific content of the custom primitive
+        content: Arc<dyn Any + Send + Sync>,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
@@ -105,3 +198,16 @@ impl Default for Primitive {
         Primitive::None
     }
 }
+
+/// A piece of text with its own style, part of a [`Primitive::RichText`].
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The color of the span
+    pub color: Color,
+    /// The size of the span
+    pub size: f32,
+    /// The font of the span
+    pub font: Font,
+}
@@ -1,16 +1,26 @@
-use crate::image::atlas::{self, Atlas};
+//! Load and cache raster images.
+use crate::atlas::{self, Atlas};
 use iced_native::image;
 use std::collections::{HashMap, HashSet};
 
+/// The memory of a raster image.
 #[derive(Debug)]
 pub enum Memory {
+    /// The image is loaded in the host, waiting to be uploaded.
     Host(::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>),
+
+    /// The image has been uploaded to an [`Atlas`].
     Device(atlas::Entry),
+
+    /// The image could not be found.
     NotFound,
+
+    /// The image could not be decoded.
     Invalid,
 }
 
 impl Memory {
+    /// Returns the dimensions of the image of the [`Memory`].
     pub fn dimensions(&self) -> (u32, u32) {
         match self {
             Memory::Host(image) => image.dimensions(),
@@ -21,13 +31,15 @@ impl Memory {
     }
 }
 
-#[derive(Debug)]
+/// A cache of raster images, uploaded to an [`Atlas`] on demand.
+#[derive(Debug, Default)]
 pub struct Cache {
     map: HashMap<u64, Memory>,
     hits: HashSet<u64>,
 }
 
 impl Cache {
+    /// Creates a new empty [`Cache`].
     pub fn new() -> Self {
         Self {
             map: HashMap::new(),
@@ -35,6 +47,8 @@ impl Cache {
         }
     }
 
+    /// Loads the image of the given [`image::Handle`], if it is not cached
+    /// already.
     pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
         if self.contains(handle) {
             return self.get(handle).unwrap();
@@ -49,7 +63,7 @@ impl Cache {
                 }
             }
             image::Data::Bytes(bytes) => {
-                if let Ok(image) = ::image::load_from_memory(&bytes) {
+                if let Ok(image) = ::image::load_from_memory(bytes) {
                     Memory::Host(image.to_bgra8())
                 } else {
                     Memory::Invalid
@@ -76,19 +90,20 @@ impl Cache {
         self.get(handle).unwrap()
     }
 
+    /// Uploads the image of the given [`image::Handle`] to the [`Atlas`], if
+    /// it is not uploaded already, and returns its [`atlas::Entry`].
     pub fn upload(
         &mut self,
         handle: &image::Handle,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
         atlas: &mut Atlas,
+        storage: &mut impl atlas::Storage,
     ) -> Option<&atlas::Entry> {
         let memory = self.load(handle);
 
         if let Memory::Host(image) = memory {
             let (width, height) = image.dimensions();
 
-            let entry = atlas.upload(width, height, &image, device, encoder)?;
+            let entry = atlas.upload(width, height, image, storage)?;
 
             *memory = Memory::Device(entry);
         }
@@ -100,6 +115,8 @@ impl Cache {
         }
     }
 
+    /// Removes the images that have not been used since the last trim, freeing
+    /// their allocations in the [`Atlas`].
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let hits = &self.hits;
 
@@ -1,20 +1,29 @@
+use crate::widget::Truncations;
 use crate::{Backend, Defaults, Primitive};
+use iced_native::font;
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::{
     Background, Color, Element, Point, Rectangle, Vector, Widget,
 };
 
+use std::borrow::Cow;
+use std::cell::RefCell;
+
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    truncations: RefCell<Truncations>,
 }
 
 impl<B: Backend> Renderer<B> {
     /// Creates a new [`Renderer`] from the given [`Backend`].
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            truncations: RefCell::new(Truncations::default()),
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -26,6 +35,10 @@ impl<B: Backend> Renderer<B> {
     pub fn backend_mut(&mut self) -> &mut B {
         &mut self.backend
     }
+
+    pub(crate) fn truncations(&self) -> &RefCell<Truncations> {
+        &self.truncations
+    }
 }
 
 impl<B> iced_native::Renderer for Renderer<B>
@@ -43,6 +56,7 @@ where
         let layout = element.layout(self, limits);
 
         self.backend.trim_measurements();
+        self.truncations.get_mut().trim();
 
         layout
     }
@@ -53,17 +67,24 @@ where
         (overlay_primitives, overlay_cursor): (Primitive, mouse::Interaction),
         overlay_bounds: Rectangle,
     ) -> (Primitive, mouse::Interaction) {
+        let bounds = Rectangle {
+            width: overlay_bounds.width + 0.5,
+            height: overlay_bounds.height + 0.5,
+            ..overlay_bounds
+        };
+
+        // The clip is grown to fit the shadows cast by the overlay
+        let bounds = shadow_bounds(&overlay_primitives, Vector::new(0.0, 0.0))
+            .map_or(bounds, |shadows| union(bounds, shadows));
+
         (
             Primitive::Group {
                 primitives: vec![
                     base_primitive,
                     Primitive::Clip {
-                        bounds: Rectangle {
-                            width: overlay_bounds.width + 0.5,
-                            height: overlay_bounds.height + 0.5,
-                            ..overlay_bounds
-                        },
+                        bounds,
                         offset: Vector::new(0, 0),
+                        border_radius: 0.0.into(),
                         content: Box::new(overlay_primitives),
                     },
                 ],
@@ -75,6 +96,13 @@ where
             },
         )
     }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        self.backend.load_font(bytes)
+    }
 }
 
 impl<B> layout::Debugger for Renderer<B>
@@ -102,6 +130,69 @@ where
     }
 }
 
+/// Returns the area covered by the shadows of the quads of the given
+/// [`Primitive`], if any.
+///
+/// Transformed content is ignored.
+fn shadow_bounds(
+    primitive: &Primitive,
+    translation: Vector,
+) -> Option<Rectangle> {
+    match primitive {
+        Primitive::Group { primitives } => primitives
+            .iter()
+            .filter_map(|primitive| shadow_bounds(primitive, translation))
+            .fold(None, |area, bounds| {
+                Some(area.map_or(bounds, |area| union(area, bounds)))
+            }),
+        Primitive::Quad {
+            bounds,
+            shadow: Some(shadow),
+            ..
+        } if shadow.color.a > 0.0 => {
+            let extent = (shadow.blur_radius + shadow.spread).max(0.0);
+
+            Some(Rectangle {
+                x: bounds.x + translation.x + shadow.offset.x - extent,
+                y: bounds.y + translation.y + shadow.offset.y - extent,
+                width: bounds.width + extent * 2.0,
+                height: bounds.height + extent * 2.0,
+            })
+        }
+        Primitive::Clip {
+            bounds,
+            offset,
+            content,
+            ..
+        } => shadow_bounds(
+            content,
+            translation - Vector::new(offset.x as f32, offset.y as f32),
+        )
+        .and_then(|shadows| shadows.intersection(&(*bounds + translation))),
+        Primitive::Translate {
+            translation: offset,
+            content,
+        } => shadow_bounds(content, translation + *offset),
+        Primitive::Opacity { content, .. } => {
+            shadow_bounds(content, translation)
+        }
+        Primitive::Cached { cache } => shadow_bounds(cache, translation),
+        _ => None,
+    }
+}
+
+fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
+
 fn explain_layout(
     layout: Layout<'_>,
     color: Color,
@@ -110,12 +201,49 @@ fn explain_layout(
     primitives.push(Primitive::Quad {
         bounds: layout.bounds(),
         background: Background::Color(Color::TRANSPARENT),
-        border_radius: 0.0,
+        border_radius: 0.0.into(),
         border_width: 1.0,
         border_color: [0.6, 0.6, 0.6, 0.5].into(),
+        shadow: None,
     });
 
     for child in layout.children() {
         explain_layout(child, color, primitives);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{Shadow, Size};
+
+    #[test]
+    fn shadow_bounds_cover_blur_spread_and_offset() {
+        let quad = Primitive::Quad {
+            bounds: Rectangle::new(
+                Point::new(10.0, 10.0),
+                Size::new(20.0, 20.0),
+            ),
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Some(
+                Shadow::new(Color::BLACK, Vector::new(0.0, 4.0), 8.0)
+                    .spread(2.0),
+            ),
+        };
+
+        let primitive = Primitive::Translate {
+            translation: Vector::new(5.0, 0.0),
+            content: Box::new(Primitive::Group {
+                primitives: vec![Primitive::None, quad],
+            }),
+        };
+
+        assert_eq!(
+            shadow_bounds(&primitive, Vector::new(0.0, 0.0)),
+            Some(Rectangle::new(Point::new(5.0, 4.0), Size::new(40.0, 40.0)))
+        );
+    }
+}
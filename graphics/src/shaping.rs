@@ -0,0 +1,1078 @@
+//! Shape and lay out text written in any script.
+//!
+//! A [`Layout`] can be used as a custom [`GlyphPositioner`] for a
+//! `glyph_brush` to render text with proper ligatures, complex scripts,
+//! bidirectional content, and font fallback.
+use crate::backend::Fragment;
+use glyph_brush::ab_glyph::{self, ScaleFont as _};
+use glyph_brush::{
+    FontId, GlyphPositioner, HorizontalAlign, SectionGeometry, SectionGlyph,
+    SectionText, ToSectionText, VerticalAlign,
+};
+use iced_native::font::{self, Descriptor, Family};
+use iced_native::{Direction, Rectangle};
+use ttf_parser::name_id;
+use unicode_bidi::{BidiInfo, Level, ParagraphInfo};
+
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The data of the fonts loaded in a `glyph_brush`.
+///
+/// Fonts need to be pushed in the same order they are added to the brush, so
+/// their indices match the [`FontId`] assigned to them.
+#[derive(Clone, Default)]
+pub struct Fonts {
+    faces: Arc<Vec<Face>>,
+}
+
+#[derive(Clone)]
+struct Face {
+    data: Cow<'static, [u8]>,
+    index: u32,
+}
+
+impl Fonts {
+    /// Creates an empty set of [`Fonts`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the data of a font, along with its index in the font
+    /// collection.
+    pub fn push(&mut self, data: impl Into<Cow<'static, [u8]>>, index: u32) {
+        Arc::make_mut(&mut self.faces).push(Face {
+            data: data.into(),
+            index,
+        });
+    }
+
+    /// Returns the [`FontId`] of the font with the given data and index, if
+    /// it has been pushed already.
+    pub fn position(&self, data: &[u8], index: u32) -> Option<FontId> {
+        self.faces
+            .iter()
+            .position(|face| face.index == index && face.data.as_ref() == data)
+            .map(FontId)
+    }
+
+    /// Returns the amount of [`Fonts`].
+    pub fn len(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// Returns true if there are no [`Fonts`].
+    pub fn is_empty(&self) -> bool {
+        self.faces.is_empty()
+    }
+
+    /// Finds the font of the family of the given [`Descriptor`] with the
+    /// closest properties.
+    ///
+    /// Returns the [`FontId`] of the font and whether its properties match
+    /// the [`Descriptor`] exactly.
+    ///
+    /// Only fonts of a [`Family::Name`] can be found.
+    pub fn find(&self, descriptor: &Descriptor) -> Option<(FontId, bool)> {
+        let name = match descriptor.family {
+            Family::Name(name) => name,
+            _ => return None,
+        };
+
+        let (font_id, distance) = self
+            .faces
+            .iter()
+            .enumerate()
+            .filter_map(|(i, face)| {
+                let face = ttf_parser::Face::from_slice(&face.data, face.index)
+                    .ok()?;
+
+                let is_family = face
+                    .names()
+                    .filter(|entry| {
+                        entry.name_id() == name_id::FAMILY
+                            || entry.name_id() == name_id::TYPOGRAPHIC_FAMILY
+                    })
+                    .filter_map(|entry| entry.to_string())
+                    .any(|family| family.eq_ignore_ascii_case(name));
+
+                if is_family {
+                    Some((FontId(i), distance(descriptor, &face)))
+                } else {
+                    None
+                }
+            })
+            .min_by(|(_, a), (_, b)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })?;
+
+        Some((font_id, distance == 0.0))
+    }
+
+    fn face(&self, font_id: FontId) -> Option<rustybuzz::Face<'_>> {
+        let face = self.faces.get(font_id.0)?;
+
+        rustybuzz::Face::from_slice(&face.data, face.index)
+    }
+}
+
+impl fmt::Debug for Fonts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fonts").field("len", &self.len()).finish()
+    }
+}
+
+/// Returns how far the properties of a face are from the ones of a
+/// [`Descriptor`], following the priorities of CSS: style, then stretch, then
+/// weight.
+fn distance(descriptor: &Descriptor, face: &ttf_parser::Face<'_>) -> f32 {
+    let style = if face.is_italic() {
+        font::Style::Italic
+    } else if face.is_oblique() {
+        font::Style::Oblique
+    } else {
+        font::Style::Normal
+    };
+
+    let style_distance = match (descriptor.style, style) {
+        (a, b) if a == b => 0.0,
+        (font::Style::Normal, _) | (_, font::Style::Normal) => 2.0,
+        _ => 1.0,
+    };
+
+    let stretch = match face.width().to_number() {
+        1 => font::Stretch::UltraCondensed,
+        2 => font::Stretch::ExtraCondensed,
+        3 => font::Stretch::Condensed,
+        4 => font::Stretch::SemiCondensed,
+        6 => font::Stretch::SemiExpanded,
+        7 => font::Stretch::Expanded,
+        8 => font::Stretch::ExtraExpanded,
+        9 => font::Stretch::UltraExpanded,
+        _ => font::Stretch::Normal,
+    };
+
+    let stretch_distance = (descriptor.stretch.ratio() - stretch.ratio()).abs();
+
+    let weight_distance = (f32::from(descriptor.weight.value())
+        - f32::from(face.weight().to_number()))
+    .abs();
+
+    style_distance * 100_000.0 + stretch_distance * 10_000.0 + weight_distance
+}
+
+/// Returns the characters of the given text that none of the fonts can
+/// display.
+pub fn uncovered<'a, F>(
+    fonts: &'a [F],
+    text: &'a str,
+) -> impl Iterator<Item = char> + 'a
+where
+    F: ab_glyph::Font,
+{
+    text.chars().filter(move |c| {
+        !c.is_control()
+            && !c.is_whitespace()
+            && !fonts.iter().any(|font| covers(font, *c))
+    })
+}
+
+/// Groups the given laid out glyphs in the [`Fragment`]s of their sections.
+///
+/// Consecutive glyphs of the same section that share a baseline are part of
+/// the same [`Fragment`].
+pub fn fragments<F>(fonts: &[F], glyphs: &[SectionGlyph]) -> Vec<Fragment>
+where
+    F: ab_glyph::Font,
+{
+    let mut fragments: Vec<Fragment> = Vec::new();
+
+    for glyph in glyphs {
+        let position = glyph.glyph.position;
+        let bounds = glyph_bounds(fonts, glyph, None);
+
+        match fragments.last_mut() {
+            Some(fragment)
+                if fragment.span == glyph.section_index
+                    && (fragment.baseline - position.y).abs() < 0.5 =>
+            {
+                let x = fragment.bounds.x.min(bounds.x);
+                let y = fragment.bounds.y.min(bounds.y);
+
+                let right = (fragment.bounds.x + fragment.bounds.width)
+                    .max(bounds.x + bounds.width);
+                let bottom = (fragment.bounds.y + fragment.bounds.height)
+                    .max(bounds.y + bounds.height);
+
+                fragment.bounds = Rectangle {
+                    x,
+                    y,
+                    width: right - x,
+                    height: bottom - y,
+                };
+            }
+            _ => fragments.push(Fragment {
+                span: glyph.section_index,
+                bounds,
+                baseline: position.y,
+            }),
+        }
+    }
+
+    fragments
+}
+
+/// Returns the byte offset and the bounds of the given laid out glyphs.
+///
+/// The bounds of a glyph span the whole height of a line of its font, or the
+/// given line height, if any.
+pub fn characters<F>(
+    fonts: &[F],
+    glyphs: &[SectionGlyph],
+    line_height: Option<f32>,
+) -> Vec<(usize, Rectangle)>
+where
+    F: ab_glyph::Font,
+{
+    glyphs
+        .iter()
+        .map(|glyph| {
+            (glyph.byte_index, glyph_bounds(fonts, glyph, line_height))
+        })
+        .collect()
+}
+
+/// Returns the size of the area covered by the given laid out glyphs, when
+/// every line has the given height.
+pub fn measure<F>(
+    fonts: &[F],
+    glyphs: &[SectionGlyph],
+    line_height: f32,
+) -> (f32, f32)
+where
+    F: ab_glyph::Font,
+{
+    let bounds = glyphs
+        .iter()
+        .map(|glyph| glyph_bounds(fonts, glyph, Some(line_height)))
+        .fold(None, |area: Option<(f32, f32, f32, f32)>, bounds| {
+            let right = bounds.x + bounds.width;
+            let bottom = bounds.y + bounds.height;
+
+            Some(match area {
+                Some((left, top, max_right, max_bottom)) => (
+                    left.min(bounds.x),
+                    top.min(bounds.y),
+                    max_right.max(right),
+                    max_bottom.max(bottom),
+                ),
+                None => (bounds.x, bounds.y, right, bottom),
+            })
+        });
+
+    match bounds {
+        Some((left, top, right, bottom)) => (right - left, bottom - top),
+        None => (0.0, 0.0),
+    }
+}
+
+fn glyph_bounds<F>(
+    fonts: &[F],
+    glyph: &SectionGlyph,
+    line_height: Option<f32>,
+) -> Rectangle
+where
+    F: ab_glyph::Font,
+{
+    let font = fonts.get(glyph.font_id.0).unwrap_or(&fonts[0]);
+    let metrics = metrics(font, glyph.glyph.scale);
+
+    let (ascent, height) = metrics.line(line_height);
+    let position = glyph.glyph.position;
+
+    Rectangle {
+        x: position.x,
+        y: position.y - ascent,
+        width: font.as_scaled(glyph.glyph.scale).h_advance(glyph.glyph.id),
+        height,
+    }
+}
+
+/// A text layout that shapes its contents.
+///
+/// Text is split in runs of the same direction and font. Every run is shaped
+/// with [`rustybuzz`], and characters that are missing in the font of a
+/// section are displayed with the first font that contains them.
+///
+/// Lines are wrapped using the Unicode line breaking rules, and then
+/// reordered visually using the Unicode Bidirectional Algorithm.
+///
+/// By default, lines are as tall as their tallest font, characters are
+/// placed right after each other, and the direction of every paragraph is
+/// given by its first strong character.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    fonts: Fonts,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+    line_height: Option<f32>,
+    letter_spacing: f32,
+    direction: Option<Direction>,
+}
+
+impl Layout {
+    /// Creates a new [`Layout`] using the given [`Fonts`] and the alignment
+    /// of a built-in `glyph_brush` layout.
+    pub fn new<L>(fonts: &Fonts, layout: glyph_brush::Layout<L>) -> Self
+    where
+        L: glyph_brush::LineBreaker,
+    {
+        let (h_align, v_align) = match layout {
+            glyph_brush::Layout::SingleLine {
+                h_align, v_align, ..
+            }
+            | glyph_brush::Layout::Wrap {
+                h_align, v_align, ..
+            } => (h_align, v_align),
+        };
+
+        Layout {
+            fonts: fonts.clone(),
+            h_align,
+            v_align,
+            line_height: None,
+            letter_spacing: 0.0,
+            direction: None,
+        }
+    }
+
+    /// Sets the height of every line of the [`Layout`], in pixels.
+    ///
+    /// The extra space is split evenly above and below the contents of a
+    /// line.
+    pub fn line_height(mut self, line_height: Option<f32>) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the extra space after every character of the [`Layout`], in
+    /// pixels.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the base [`Direction`] of the paragraphs of the [`Layout`].
+    ///
+    /// Neutral characters at the edges of a paragraph, like digits or
+    /// punctuation, are ordered in this direction.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    fn bidi<'a>(&self, text: &'a str) -> BidiInfo<'a> {
+        let level = self.direction.map(|direction| match direction {
+            Direction::LeftToRight => Level::ltr(),
+            Direction::RightToLeft => Level::rtl(),
+        });
+
+        BidiInfo::new(text, level)
+    }
+
+    /// Returns the byte ranges of the lines of the given sections when they
+    /// are wrapped to the given width.
+    ///
+    /// The lines are broken exactly like the glyphs positioned by the
+    /// [`Layout`], so the ranges can be used to find the line of a glyph.
+    pub fn line_breaks<F, S>(
+        &self,
+        fonts: &[F],
+        width: f32,
+        sections: &[S],
+    ) -> Vec<Range<usize>>
+    where
+        F: ab_glyph::Font,
+        S: ToSectionText,
+    {
+        let contents = Contents::new(sections);
+        let bidi = self.bidi(&contents.text);
+
+        bidi.paragraphs
+            .iter()
+            .flat_map(|paragraph| {
+                let (range, runs) =
+                    self.shape_paragraph(fonts, &contents, &bidi, paragraph);
+
+                wrap(&contents.text, range, &runs, width)
+            })
+            .collect()
+    }
+
+    /// Shapes the given paragraph, returning its range without the trailing
+    /// line terminator and its [`Run`]s.
+    fn shape_paragraph<F: ab_glyph::Font>(
+        &self,
+        fonts: &[F],
+        contents: &Contents<'_>,
+        bidi: &BidiInfo<'_>,
+        paragraph: &ParagraphInfo,
+    ) -> (Range<usize>, Vec<Run>) {
+        let content = contents.text[paragraph.range.clone()]
+            .trim_end_matches(&['\n', '\r', '\u{2029}'][..]);
+
+        let range =
+            paragraph.range.start..paragraph.range.start + content.len();
+
+        let runs = shape(
+            &self.fonts,
+            fonts,
+            &contents.text,
+            range.clone(),
+            &bidi.levels,
+            self.letter_spacing,
+            |byte| contents.section(byte),
+        );
+
+        (range, runs)
+    }
+}
+
+/// The sections of a paragraph joined together.
+struct Contents<'a> {
+    sections: Vec<SectionText<'a>>,
+    text: String,
+    starts: Vec<usize>,
+}
+
+impl<'a> Contents<'a> {
+    fn new<S: ToSectionText>(sections: &'a [S]) -> Self {
+        let sections: Vec<SectionText<'a>> = sections
+            .iter()
+            .map(ToSectionText::to_section_text)
+            .collect();
+
+        let text = sections.iter().map(|section| section.text).collect();
+
+        let starts = sections
+            .iter()
+            .scan(0, |start, section| {
+                let current = *start;
+                *start += section.text.len();
+
+                Some(current)
+            })
+            .collect();
+
+        Contents {
+            sections,
+            text,
+            starts,
+        }
+    }
+
+    /// Returns the index of the section containing the given byte.
+    fn section_at(&self, byte: usize) -> usize {
+        self.starts
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(i, start)| {
+                **start <= byte && !self.sections[*i].text.is_empty()
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+
+    fn section(&self, byte: usize) -> &SectionText<'a> {
+        &self.sections[self.section_at(byte)]
+    }
+}
+
+impl Hash for Layout {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Loading a new fallback font may change the result of a layout
+        self.fonts.len().hash(state);
+        self.h_align.hash(state);
+        self.v_align.hash(state);
+        self.line_height.map(f32::to_bits).hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.direction.hash(state);
+    }
+}
+
+impl GlyphPositioner for Layout {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: ab_glyph::Font,
+        S: ToSectionText,
+    {
+        let SectionGeometry {
+            screen_position: (screen_x, screen_y),
+            bounds: (bound_w, bound_h),
+        } = *geometry;
+
+        let contents = Contents::new(sections);
+        let text = &contents.text;
+        let bidi = self.bidi(text);
+
+        let mut glyphs = Vec::new();
+        let mut caret_y = 0.0;
+
+        for paragraph in &bidi.paragraphs {
+            let (range, runs) =
+                self.shape_paragraph(fonts, &contents, &bidi, paragraph);
+
+            for line in wrap(text, range, &runs, bound_w) {
+                if self.v_align == VerticalAlign::Top && caret_y >= bound_h {
+                    break;
+                }
+
+                let visible_end =
+                    line.start + text[line.clone()].trim_end().len();
+
+                let line_metrics = runs
+                    .iter()
+                    .filter(|run| {
+                        run.range.start < line.end && run.range.end > line.start
+                    })
+                    .map(|run| metrics(&fonts[run.font_id.0], run.scale))
+                    .fold(None, |max: Option<Metrics>, metrics| {
+                        Some(match max {
+                            Some(max) if max.height() >= metrics.height() => {
+                                max
+                            }
+                            _ => metrics,
+                        })
+                    })
+                    .unwrap_or_else(|| {
+                        let section = contents.section(line.start);
+
+                        metrics(
+                            fonts.get(section.font_id.0).unwrap_or(&fonts[0]),
+                            section.scale,
+                        )
+                    });
+
+                let (ascent, line_height) = line_metrics.line(self.line_height);
+
+                let baseline = caret_y + ascent;
+                let (_, visual_runs) =
+                    bidi.visual_runs(paragraph, line.start..visible_end);
+
+                let mut line_glyphs = Vec::new();
+                let mut caret_x = 0.0;
+
+                for visual_run in visual_runs {
+                    let is_rtl = bidi.levels[visual_run.start].is_rtl();
+
+                    let shaped_runs = runs.iter().filter(|run| {
+                        run.range.start < visual_run.end
+                            && run.range.end > visual_run.start
+                    });
+
+                    let shaped_runs: Vec<&Run> = if is_rtl {
+                        shaped_runs.rev().collect()
+                    } else {
+                        shaped_runs.collect()
+                    };
+
+                    for run in shaped_runs {
+                        for glyph in &run.glyphs {
+                            if glyph.byte < visual_run.start
+                                || glyph.byte >= visual_run.end
+                            {
+                                continue;
+                            }
+
+                            let section_index = contents.section_at(glyph.byte);
+
+                            line_glyphs.push(SectionGlyph {
+                                section_index,
+                                byte_index: glyph.byte
+                                    - contents.starts[section_index],
+                                glyph: ab_glyph::Glyph {
+                                    id: glyph.id,
+                                    scale: run.scale,
+                                    position: ab_glyph::point(
+                                        caret_x + glyph.x_offset,
+                                        baseline - glyph.y_offset,
+                                    ),
+                                },
+                                font_id: run.font_id,
+                            });
+
+                            caret_x += glyph.x_advance;
+                        }
+                    }
+                }
+
+                let shift = match self.h_align {
+                    HorizontalAlign::Left => 0.0,
+                    HorizontalAlign::Center => caret_x / 2.0,
+                    HorizontalAlign::Right => caret_x,
+                };
+
+                for glyph in &mut line_glyphs {
+                    glyph.glyph.position.x += screen_x - shift;
+                    glyph.glyph.position.y += screen_y;
+                }
+
+                glyphs.extend(line_glyphs);
+                caret_y += line_height;
+            }
+        }
+
+        let shift = match self.v_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => caret_y / 2.0,
+            VerticalAlign::Bottom => caret_y,
+        };
+
+        if shift != 0.0 {
+            for glyph in &mut glyphs {
+                glyph.glyph.position.y -= shift;
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> ab_glyph::Rect {
+        let SectionGeometry {
+            screen_position: (screen_x, screen_y),
+            bounds: (bound_w, bound_h),
+        } = *geometry;
+
+        let (x_min, x_max) = match self.h_align {
+            HorizontalAlign::Left => (screen_x, screen_x + bound_w),
+            HorizontalAlign::Center => {
+                (screen_x - bound_w / 2.0, screen_x + bound_w / 2.0)
+            }
+            HorizontalAlign::Right => (screen_x - bound_w, screen_x),
+        };
+
+        let (y_min, y_max) = match self.v_align {
+            VerticalAlign::Top => (screen_y, screen_y + bound_h),
+            VerticalAlign::Center => {
+                (screen_y - bound_h / 2.0, screen_y + bound_h / 2.0)
+            }
+            VerticalAlign::Bottom => (screen_y - bound_h, screen_y),
+        };
+
+        ab_glyph::Rect {
+            min: ab_glyph::point(x_min.floor(), y_min.floor()),
+            max: ab_glyph::point(x_max.ceil(), y_max.ceil()),
+        }
+    }
+}
+
+/// A shaped run of text with the same direction, section, and font.
+struct Run {
+    range: Range<usize>,
+    font_id: FontId,
+    scale: ab_glyph::PxScale,
+    glyphs: Vec<Glyph>,
+}
+
+/// A shaped glyph, in visual order inside its [`Run`].
+struct Glyph {
+    id: ab_glyph::GlyphId,
+    byte: usize,
+    x_advance: f32,
+    x_offset: f32,
+    y_offset: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    ascent: f32,
+    descent: f32,
+    line_gap: f32,
+}
+
+impl Metrics {
+    fn height(&self) -> f32 {
+        self.ascent - self.descent + self.line_gap
+    }
+
+    /// Returns the distance from the top of a line to its baseline and the
+    /// height of the line, given an explicit line height, if any.
+    fn line(&self, line_height: Option<f32>) -> (f32, f32) {
+        match line_height {
+            Some(line_height) => {
+                let leading = line_height - (self.ascent - self.descent);
+
+                (self.ascent + leading / 2.0, line_height)
+            }
+            None => (self.ascent, self.height()),
+        }
+    }
+}
+
+fn metrics<F: ab_glyph::Font>(font: &F, scale: ab_glyph::PxScale) -> Metrics {
+    let font = font.as_scaled(scale);
+
+    Metrics {
+        ascent: font.ascent(),
+        descent: font.descent(),
+        line_gap: font.line_gap(),
+    }
+}
+
+fn covers<F: ab_glyph::Font>(font: &F, c: char) -> bool {
+    font.glyph_id(c).0 != 0
+}
+
+/// Splits the given range of text in runs and shapes them, adding the given
+/// letter spacing after every cluster.
+fn shape<'a, F>(
+    data: &Fonts,
+    fonts: &[F],
+    text: &str,
+    range: Range<usize>,
+    levels: &[Level],
+    letter_spacing: f32,
+    section_at: impl Fn(usize) -> &'a SectionText<'a>,
+) -> Vec<Run>
+where
+    F: ab_glyph::Font,
+{
+    let mut runs: Vec<Run> = Vec::new();
+
+    for (offset, c) in text[range.clone()].char_indices() {
+        let byte = range.start + offset;
+        let section = section_at(byte);
+        let preferred = section.font_id;
+
+        let current = runs.last_mut().filter(|run| {
+            levels[run.range.start] == levels[byte]
+                && run.scale == section.scale
+                && std::ptr::eq(section_at(run.range.start), section)
+        });
+
+        let font_id = if covers(&fonts[preferred.0], c) {
+            preferred
+        } else if let Some(run) = current
+            .as_ref()
+            .filter(|run| covers(&fonts[run.font_id.0], c))
+        {
+            run.font_id
+        } else {
+            (0..fonts.len())
+                .find(|i| covers(&fonts[*i], c))
+                .map(FontId)
+                .unwrap_or(preferred)
+        };
+
+        match current {
+            Some(run) if run.font_id == font_id => {
+                run.range.end = byte + c.len_utf8();
+            }
+            _ => {
+                runs.push(Run {
+                    range: byte..byte + c.len_utf8(),
+                    font_id,
+                    scale: section.scale,
+                    glyphs: Vec::new(),
+                });
+            }
+        }
+    }
+
+    for run in &mut runs {
+        let font = fonts[run.font_id.0].as_scaled(run.scale);
+        let content = &text[run.range.clone()];
+
+        let face = match data.face(run.font_id) {
+            Some(face) => face,
+            None => {
+                // We cannot shape text without the font data, so we simply
+                // map every character to a glyph.
+                run.glyphs = content
+                    .char_indices()
+                    .map(|(offset, c)| {
+                        let id = font.glyph_id(c);
+
+                        Glyph {
+                            id,
+                            byte: run.range.start + offset,
+                            x_advance: font.h_advance(id) + letter_spacing,
+                            x_offset: 0.0,
+                            y_offset: 0.0,
+                        }
+                    })
+                    .collect();
+
+                continue;
+            }
+        };
+
+        let mut buffer = rustybuzz::UnicodeBuffer::new();
+        buffer.push_str(content);
+        buffer.set_direction(if levels[run.range.start].is_rtl() {
+            rustybuzz::Direction::RightToLeft
+        } else {
+            rustybuzz::Direction::LeftToRight
+        });
+
+        let output = rustybuzz::shape(&face, &[], buffer);
+
+        let h_scale = font.h_scale_factor();
+        let v_scale = font.v_scale_factor();
+
+        let infos = output.glyph_infos();
+
+        run.glyphs = infos
+            .iter()
+            .zip(output.glyph_positions())
+            .enumerate()
+            .map(|(i, (info, position))| {
+                // Only the last glyph of a cluster is spaced
+                let is_cluster_end = infos
+                    .get(i + 1)
+                    .filter(|next| next.cluster == info.cluster)
+                    .is_none();
+
+                let spacing = if is_cluster_end { letter_spacing } else { 0.0 };
+
+                Glyph {
+                    id: ab_glyph::GlyphId(info.codepoint as u16),
+                    byte: run.range.start + info.cluster as usize,
+                    x_advance: position.x_advance as f32 * h_scale + spacing,
+                    x_offset: position.x_offset as f32 * h_scale,
+                    y_offset: position.y_offset as f32 * v_scale,
+                }
+            })
+            .collect();
+    }
+
+    runs
+}
+
+/// Breaks the given range of shaped text in lines that fit the given width.
+fn wrap(
+    text: &str,
+    range: Range<usize>,
+    runs: &[Run],
+    max_width: f32,
+) -> Vec<Range<usize>> {
+    // The advance of every cluster, indexed by its offset in the range
+    let mut advances = vec![0.0; range.len() + 1];
+
+    for glyph in runs.iter().flat_map(|run| &run.glyphs) {
+        advances[glyph.byte - range.start + 1] += glyph.x_advance;
+    }
+
+    let mut total = 0.0;
+
+    for advance in advances.iter_mut() {
+        total += *advance;
+        *advance = total;
+    }
+
+    let width = |start: usize, end: usize| {
+        advances[end - range.start] - advances[start - range.start]
+    };
+
+    let mut lines = Vec::new();
+    let mut line_start = range.start;
+    let mut line_width = 0.0;
+    let mut segment_start = range.start;
+
+    for (offset, _) in xi_unicode::LineBreakIterator::new(&text[range.clone()])
+    {
+        let segment_end = range.start + offset;
+        let trimmed_end =
+            segment_start + text[segment_start..segment_end].trim_end().len();
+
+        let is_overflowing =
+            line_width + width(segment_start, trimmed_end) > max_width + 0.01;
+
+        if is_overflowing && segment_start > line_start {
+            lines.push(line_start..segment_start);
+
+            line_start = segment_start;
+            line_width = 0.0;
+        }
+
+        line_width += width(segment_start, segment_end);
+        segment_start = segment_end;
+    }
+
+    lines.push(line_start..range.end);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATO: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
+    const ICONS: &[u8] = include_bytes!("../fonts/Icons.ttf");
+
+    fn fonts() -> (Fonts, Vec<ab_glyph::FontRef<'static>>) {
+        let mut data = Fonts::new();
+        data.push(LATO, 0);
+        data.push(ICONS, 0);
+
+        let fonts = vec![
+            ab_glyph::FontRef::try_from_slice(LATO).unwrap(),
+            ab_glyph::FontRef::try_from_slice(ICONS).unwrap(),
+        ];
+
+        (data, fonts)
+    }
+
+    fn section(content: &str) -> SectionText<'_> {
+        SectionText {
+            text: content,
+            scale: ab_glyph::PxScale::from(20.0),
+            font_id: FontId(0),
+        }
+    }
+
+    fn layout(
+        content: &str,
+        width: f32,
+    ) -> (Vec<ab_glyph::FontRef<'static>>, Vec<SectionGlyph>) {
+        let (data, fonts) = self::fonts();
+
+        let glyphs = Layout::new(&data, glyph_brush::Layout::default())
+            .calculate_glyphs(
+                &fonts,
+                &SectionGeometry {
+                    screen_position: (0.0, 0.0),
+                    bounds: (width, f32::INFINITY),
+                },
+                &[section(content)],
+            );
+
+        (fonts, glyphs)
+    }
+
+    fn x_of(glyphs: &[SectionGlyph], byte: usize) -> f32 {
+        glyphs
+            .iter()
+            .find(|glyph| glyph.byte_index == byte)
+            .map(|glyph| glyph.glyph.position.x)
+            .expect("Find glyph")
+    }
+
+    #[test]
+    fn wraps_words_that_do_not_fit() {
+        let (_, unbounded) = layout("hello world", f32::INFINITY);
+        let (_, wrapped) = layout("hello world", 60.0);
+
+        let baselines = |glyphs: &[SectionGlyph]| {
+            let mut baselines: Vec<f32> =
+                glyphs.iter().map(|glyph| glyph.glyph.position.y).collect();
+
+            baselines.dedup();
+            baselines
+        };
+
+        assert_eq!(baselines(&unbounded).len(), 1);
+        assert_eq!(baselines(&wrapped).len(), 2);
+
+        // The second word starts a new line
+        assert_eq!(x_of(&wrapped, 6), 0.0);
+        assert!(x_of(&unbounded, 6) > 0.0);
+    }
+
+    #[test]
+    fn breaks_lines_like_the_positioned_glyphs() {
+        let (data, fonts) = self::fonts();
+        let layout = Layout::new(&data, glyph_brush::Layout::default());
+
+        assert_eq!(
+            layout.line_breaks(&fonts, 60.0, &[section("hello world")]),
+            [0..6, 6..11]
+        );
+        assert_eq!(
+            layout.line_breaks(&fonts, f32::INFINITY, &[section("a\nb")]),
+            [0..1, 2..3]
+        );
+    }
+
+    #[test]
+    fn reorders_right_to_left_runs() {
+        // "ab" followed by the Hebrew letters alef and bet
+        let content = "ab \u{5D0}\u{5D1}";
+        let (_, glyphs) = layout(content, f32::INFINITY);
+
+        let alef = content.find('\u{5D0}').unwrap();
+        let bet = content.find('\u{5D1}').unwrap();
+
+        assert!(x_of(&glyphs, 0) < x_of(&glyphs, 1));
+        assert!(x_of(&glyphs, 1) < x_of(&glyphs, bet));
+        assert!(x_of(&glyphs, bet) < x_of(&glyphs, alef));
+    }
+
+    #[test]
+    fn orders_neutral_characters_in_the_paragraph_direction() {
+        let (data, fonts) = self::fonts();
+
+        let positions = |layout: Layout| {
+            let glyphs = layout.calculate_glyphs(
+                &fonts,
+                &SectionGeometry {
+                    screen_position: (0.0, 0.0),
+                    bounds: (f32::INFINITY, f32::INFINITY),
+                },
+                &[section("ab!")],
+            );
+
+            (x_of(&glyphs, 0), x_of(&glyphs, 2))
+        };
+
+        let layout = Layout::new(&data, glyph_brush::Layout::default());
+
+        let (a, exclamation) = positions(layout.clone());
+        assert!(a < exclamation);
+
+        let (a, exclamation) =
+            positions(layout.direction(Direction::RightToLeft));
+        assert!(exclamation < a);
+    }
+
+    #[test]
+    fn falls_back_to_fonts_containing_a_character() {
+        let content = "a\u{F00C}";
+        let (fonts, glyphs) = layout(content, f32::INFINITY);
+
+        let font_of = |byte: usize| {
+            glyphs
+                .iter()
+                .find(|glyph| glyph.byte_index == byte)
+                .map(|glyph| glyph.font_id)
+        };
+
+        assert_eq!(font_of(0), Some(FontId(0)));
+        assert_eq!(font_of(1), Some(FontId(1)));
+
+        assert_eq!(
+            uncovered(&fonts[..1], content).collect::<Vec<_>>(),
+            ['\u{F00C}']
+        );
+        assert_eq!(uncovered(&fonts, content).count(), 0);
+    }
+
+    #[test]
+    fn finds_fonts_by_their_data() {
+        let (fonts, _) = self::fonts();
+
+        assert_eq!(fonts.position(LATO, 0), Some(FontId(0)));
+        assert_eq!(fonts.position(ICONS, 0), Some(FontId(1)));
+        assert_eq!(fonts.position(LATO, 1), None);
+        assert_eq!(fonts.position(&LATO[1..], 0), None);
+    }
+}
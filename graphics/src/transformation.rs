@@ -1,4 +1,6 @@
-use glam::{Mat4, Vec3};
+use crate::{Point, Rectangle};
+
+use glam::{Mat4, Vec3, Vec4};
 use std::ops::Mul;
 
 /// A 2D transformation matrix.
@@ -30,6 +32,83 @@ impl Transformation {
     pub fn scale(x: f32, y: f32) -> Transformation {
         Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
     }
+
+    /// Creates a rotation transformation, given an angle in radians.
+    ///
+    /// Positive angles rotate clockwise, since the Y axis points down.
+    pub fn rotate(angle: f32) -> Transformation {
+        Transformation(Mat4::from_rotation_z(angle))
+    }
+
+    /// Creates an affine transformation from its coefficients.
+    ///
+    /// A point `(x, y)` is mapped to `(a * x + c * y + e, b * x + d * y + f)`,
+    /// like the `matrix` transform function of CSS and SVG.
+    pub fn affine(
+        a: f32,
+        b: f32,
+        c: f32,
+        d: f32,
+        e: f32,
+        f: f32,
+    ) -> Transformation {
+        Transformation(Mat4::from_cols(
+            Vec4::new(a, b, 0.0, 0.0),
+            Vec4::new(c, d, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(e, f, 0.0, 1.0),
+        ))
+    }
+
+    /// Returns the inverse of the [`Transformation`], if it has one.
+    pub fn inverse(self) -> Option<Transformation> {
+        if self.0.determinant().abs() > f32::EPSILON {
+            Some(Transformation(self.0.inverse()))
+        } else {
+            None
+        }
+    }
+
+    /// Applies the [`Transformation`] to the given [`Point`].
+    pub fn transform_point(&self, point: Point) -> Point {
+        let point = self.0.transform_point3(Vec3::new(point.x, point.y, 0.0));
+
+        Point::new(point.x, point.y)
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the given one once
+    /// transformed.
+    pub fn transform_rectangle(&self, rectangle: Rectangle) -> Rectangle {
+        let corners = [
+            Point::new(rectangle.x, rectangle.y),
+            Point::new(rectangle.x + rectangle.width, rectangle.y),
+            Point::new(rectangle.x, rectangle.y + rectangle.height),
+            Point::new(
+                rectangle.x + rectangle.width,
+                rectangle.y + rectangle.height,
+            ),
+        ];
+
+        let (min, max) = corners.iter().map(|p| self.transform_point(*p)).fold(
+            (
+                Point::new(f32::INFINITY, f32::INFINITY),
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+            ),
+            |(min, max), p| {
+                (
+                    Point::new(min.x.min(p.x), min.y.min(p.y)),
+                    Point::new(max.x.max(p.x), max.y.max(p.y)),
+                )
+            },
+        );
+
+        Rectangle {
+            x: min.x,
+            y: min.y,
+            width: max.x - min.x,
+            height: max.y - min.y,
+        }
+    }
 }
 
 impl Mul for Transformation {
@@ -51,3 +130,35 @@ impl From<Transformation> for [f32; 16] {
         *t.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transforms_rectangle_bounding_box() {
+        let transformation = Transformation::translate(10.0, 0.0)
+            * Transformation::rotate(std::f32::consts::FRAC_PI_2);
+
+        let bounds = transformation.transform_rectangle(Rectangle::new(
+            Point::ORIGIN,
+            crate::Size::new(20.0, 10.0),
+        ));
+
+        assert!((bounds.x - 0.0).abs() < 1e-4);
+        assert!((bounds.y - 0.0).abs() < 1e-4);
+        assert!((bounds.width - 10.0).abs() < 1e-4);
+        assert!((bounds.height - 20.0).abs() < 1e-4);
+
+        let affine = Transformation::affine(2.0, 0.0, 0.0, 3.0, 1.0, 1.0);
+        let point = affine.transform_point(Point::new(1.0, 1.0));
+
+        assert_eq!(point, Point::new(3.0, 4.0));
+        assert_eq!(
+            affine
+                .inverse()
+                .map(|inverse| inverse.transform_point(point)),
+            Some(Point::new(1.0, 1.0))
+        );
+    }
+}
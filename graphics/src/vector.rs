@@ -1,13 +1,19 @@
-use crate::image::atlas::{self, Atlas};
+//! Load, rasterize, and cache vector graphics.
+use crate::atlas::{self, Atlas};
 use iced_native::svg;
 use std::collections::{HashMap, HashSet};
 
+/// A loaded vector graphic.
 pub enum Svg {
+    /// The vector graphic has been parsed.
     Loaded(usvg::Tree),
+
+    /// The vector graphic could not be found or parsed.
     NotFound,
 }
 
 impl Svg {
+    /// Returns the dimensions of the viewport of the [`Svg`].
     pub fn viewport_dimensions(&self) -> (u32, u32) {
         match self {
             Svg::Loaded(tree) => {
@@ -20,7 +26,9 @@ impl Svg {
     }
 }
 
-#[derive(Debug)]
+/// A cache of vector graphics, rasterized and uploaded to an [`Atlas`] on
+/// demand.
+#[derive(Debug, Default)]
 pub struct Cache {
     svgs: HashMap<u64, Svg>,
     rasterized: HashMap<(u64, u32, u32), atlas::Entry>,
@@ -29,6 +37,7 @@ pub struct Cache {
 }
 
 impl Cache {
+    /// Creates a new empty [`Cache`].
     pub fn new() -> Self {
         Self {
             svgs: HashMap::new(),
@@ -38,6 +47,8 @@ impl Cache {
         }
     }
 
+    /// Loads the vector graphic of the given [`svg::Handle`], if it is not
+    /// cached already.
     pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
         if self.svgs.contains_key(&handle.id()) {
             return self.svgs.get(&handle.id()).unwrap();
@@ -51,7 +62,7 @@ impl Cache {
                 }
             }
             svg::Data::Bytes(bytes) => {
-                match usvg::Tree::from_data(&bytes, &Default::default()) {
+                match usvg::Tree::from_data(bytes, &Default::default()) {
                     Ok(tree) => Svg::Loaded(tree),
                     Err(_) => Svg::NotFound,
                 }
@@ -62,14 +73,16 @@ impl Cache {
         self.svgs.get(&handle.id()).unwrap()
     }
 
+    /// Rasterizes the vector graphic of the given [`svg::Handle`] at the given
+    /// size and scale, uploads it to the [`Atlas`] if it is not uploaded
+    /// already, and returns its [`atlas::Entry`].
     pub fn upload(
         &mut self,
         handle: &svg::Handle,
         [width, height]: [f32; 2],
         scale: f32,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
-        texture_atlas: &mut Atlas,
+        atlas: &mut Atlas,
+        storage: &mut impl atlas::Storage,
     ) -> Option<&atlas::Entry> {
         let id = handle.id();
 
@@ -127,12 +140,11 @@ impl Cache {
                 .flatten()
                 .collect();
 
-                let allocation = texture_atlas.upload(
+                let allocation = atlas.upload(
                     width,
                     height,
                     bytemuck::cast_slice(bgra.as_slice()),
-                    device,
-                    encoder,
+                    storage,
                 )?;
 
                 let _ = self.svg_hits.insert(id);
@@ -145,6 +157,8 @@ impl Cache {
         }
     }
 
+    /// Removes the vector graphics that have not been used since the last
+    /// trim, freeing their allocations in the [`Atlas`].
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let svg_hits = &self.svg_hits;
         let rasterized_hits = &self.rasterized_hits;
@@ -7,16 +7,21 @@
 //! ```
 //! use iced_graphics::{button, Button};
 //! ```
+pub mod blur;
 pub mod button;
 pub mod checkbox;
+pub mod code_view;
 pub mod container;
 pub mod image;
+pub mod opacity;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod svg;
 pub mod text_input;
@@ -26,13 +31,19 @@ mod row;
 mod space;
 mod text;
 
+#[doc(no_inline)]
+pub use blur::Blur;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use code_view::CodeView;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -41,10 +52,14 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use text_input::TextInput;
@@ -56,6 +71,8 @@ pub use space::Space;
 pub use svg::Svg;
 pub use text::Text;
 
+pub(crate) use text::Truncations;
+
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
 pub mod canvas;
@@ -64,6 +81,14 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub mod qr_code;
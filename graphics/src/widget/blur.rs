@@ -0,0 +1,52 @@
+//! Blur whatever is drawn beneath some content.
+use crate::defaults::Defaults;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::blur;
+use iced_native::{Element, Layout, Point, Rectangle};
+
+/// An element blurring whatever is drawn beneath its bounds, before drawing
+/// its content on top.
+///
+/// This is an alias of an `iced_native` blur with a default `Renderer`.
+pub type Blur<'a, Message, Backend> =
+    iced_native::Blur<'a, Message, Renderer<Backend>>;
+
+impl<B> blur::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        radius: f32,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_interaction) = content.draw(
+            self,
+            defaults,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        if radius > 0.0 {
+            (
+                Primitive::Group {
+                    primitives: vec![
+                        Primitive::Blur {
+                            bounds: content_layout.bounds(),
+                            radius,
+                        },
+                        content,
+                    ],
+                },
+                mouse_interaction,
+            )
+        } else {
+            (content, mouse_interaction)
+        }
+    }
+}
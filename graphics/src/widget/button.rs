@@ -63,7 +63,10 @@ where
         );
 
         (
-            if styling.background.is_some() || styling.border_width > 0.0 {
+            if styling.background.is_some()
+                || styling.border_width > 0.0
+                || styling.shadow.is_some()
+            {
                 let background = Primitive::Quad {
                     bounds,
                     background: styling
@@ -72,6 +75,7 @@ where
                     border_radius: styling.border_radius,
                     border_width: styling.border_width,
                     border_color: styling.border_color,
+                    shadow: styling.shadow,
                 };
 
                 if styling.shadow_offset == Vector::default() {
@@ -92,6 +96,7 @@ where
                         border_radius: styling.border_radius,
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        shadow: None,
                     };
 
                     Primitive::Group {
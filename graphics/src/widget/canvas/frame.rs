@@ -1,4 +1,4 @@
-use iced_native::{Point, Rectangle, Size, Vector};
+use iced_native::{Direction, Point, Rectangle, Size, Vector};
 
 use crate::{
     canvas::{Fill, Geometry, Path, Stroke, Text},
@@ -212,6 +212,9 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            line_height: None,
+            letter_spacing: 0.0,
+            direction: Direction::LeftToRight,
         });
     }
 
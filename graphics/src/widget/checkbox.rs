@@ -3,7 +3,9 @@ use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::checkbox;
 use iced_native::mouse;
-use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
+use iced_native::{
+    Direction, HorizontalAlignment, Rectangle, VerticalAlignment,
+};
 
 pub use iced_style::checkbox::{Style, StyleSheet};
 
@@ -42,6 +44,7 @@ where
             border_radius: style.border_radius,
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: None,
         };
 
         (
@@ -59,6 +62,9 @@ where
                         color: style.checkmark_color,
                         horizontal_alignment: HorizontalAlignment::Center,
                         vertical_alignment: VerticalAlignment::Center,
+                        line_height: None,
+                        letter_spacing: 0.0,
+                        direction: Direction::LeftToRight,
                     };
 
                     vec![checkbox, check, label]
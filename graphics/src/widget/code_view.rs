@@ -0,0 +1,173 @@
+//! Display and edit code with line numbers and syntax highlighting.
+//!
+//! A [`CodeView`] has some local [`State`].
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::code_view::{self, Line};
+use iced_native::font::Family;
+use iced_native::mouse;
+use iced_native::{
+    Background, Color, Direction, Font, HorizontalAlignment, Point, Rectangle,
+    Vector, VerticalAlignment,
+};
+
+pub use iced_native::code_view::{highlighter, Highlight, Highlighter, State};
+pub use iced_style::code_view::{Style, StyleSheet};
+
+/// A view of some code, with line numbers and syntax highlighting.
+///
+/// This is an alias of an `iced_native` code view with an
+/// `iced_wgpu::Renderer`.
+pub type CodeView<'a, Message, Backend> =
+    iced_native::CodeView<'a, Message, Renderer<Backend>>;
+
+impl<B> code_view::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn default_font(&self) -> Font {
+        Font::from(Family::Monospace)
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        gutter_width: f32,
+        lines: &[Line<'_>],
+        size: u16,
+        font: Font,
+        selection: &[Rectangle],
+        caret: Option<Rectangle>,
+        is_focused: bool,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = if is_focused {
+            style_sheet.focused()
+        } else {
+            style_sheet.active()
+        };
+
+        let quad = |bounds, background| Primitive::Quad {
+            bounds,
+            background,
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: None,
+        };
+
+        let text = |content: &str, bounds, color, alignment| Primitive::Text {
+            content: content.to_string(),
+            bounds,
+            color,
+            size: f32::from(size),
+            font,
+            horizontal_alignment: alignment,
+            vertical_alignment: VerticalAlignment::Top,
+            line_height: None,
+            letter_spacing: 0.0,
+            direction: Direction::LeftToRight,
+        };
+
+        let mut primitives = Vec::new();
+
+        if let Some(background) = style.gutter_background {
+            if gutter_width > 0.0 {
+                primitives.push(quad(
+                    Rectangle {
+                        width: gutter_width,
+                        ..bounds
+                    },
+                    background,
+                ));
+            }
+        }
+
+        primitives.extend(selection.iter().map(|bounds| {
+            quad(*bounds, Background::Color(style.selection_color))
+        }));
+
+        for line in lines {
+            if gutter_width > 0.0 {
+                primitives.push(text(
+                    &line.number.to_string(),
+                    Rectangle {
+                        x: line.number_bounds.x + line.number_bounds.width,
+                        ..line.number_bounds
+                    },
+                    style.line_number_color,
+                    HorizontalAlignment::Right,
+                ));
+            }
+
+            primitives.extend(line.fragments.iter().map(|fragment| {
+                let color = match fragment.highlight {
+                    Some(Highlight::Comment) => style.comment_color,
+                    Some(Highlight::Keyword) => style.keyword_color,
+                    Some(Highlight::Literal) => style.literal_color,
+                    Some(Highlight::Number) => style.number_color,
+                    Some(Highlight::String) => style.string_color,
+                    Some(Highlight::Type) => style.type_color,
+                    Some(Highlight::Function) => style.function_color,
+                    None => style.text_color,
+                };
+
+                text(
+                    fragment.content,
+                    Rectangle {
+                        x: fragment.position.x,
+                        y: fragment.position.y,
+                        width: f32::INFINITY,
+                        height: line.number_bounds.height,
+                    },
+                    color,
+                    HorizontalAlignment::Left,
+                )
+            }));
+        }
+
+        if let Some(caret) = caret {
+            primitives.push(quad(caret, Background::Color(style.caret_color)));
+        }
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: None,
+        };
+
+        // The border is drawn on top, so the gutter does not cover it
+        let border = Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::TRANSPARENT),
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+            shadow: None,
+        };
+
+        let contents = Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, 0),
+            border_radius: 0.0.into(),
+            content: Box::new(Primitive::Group { primitives }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![background, contents, border],
+            },
+            if bounds.contains(cursor_position) {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -2,7 +2,9 @@
 use crate::container;
 use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
-use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
+use iced_native::{
+    Background, BorderRadius, Color, Element, Layout, Point, Rectangle, Vector,
+};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -26,6 +28,7 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
         style_sheet: &Self::Style,
+        clip: bool,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
@@ -45,6 +48,39 @@ where
             viewport,
         );
 
+        let content = if clip {
+            // Like `overflow: hidden` in CSS, the content is clipped inside
+            // of the border
+            let border_width = style.border_width;
+            let BorderRadius {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } = style.border_radius;
+
+            let inner = |radius: f32| (radius - border_width).max(0.0);
+
+            Primitive::Clip {
+                bounds: Rectangle {
+                    x: bounds.x + border_width,
+                    y: bounds.y + border_width,
+                    width: (bounds.width - border_width * 2.0).max(0.0),
+                    height: (bounds.height - border_width * 2.0).max(0.0),
+                },
+                offset: Vector::new(0, 0),
+                border_radius: BorderRadius {
+                    top_left: inner(top_left),
+                    top_right: inner(top_right),
+                    bottom_right: inner(bottom_right),
+                    bottom_left: inner(bottom_left),
+                },
+                content: Box::new(content),
+            }
+        } else {
+            content
+        };
+
         if let Some(background) = background(bounds, &style) {
             (
                 Primitive::Group {
@@ -62,7 +98,10 @@ pub(crate) fn background(
     bounds: Rectangle,
     style: &container::Style,
 ) -> Option<Primitive> {
-    if style.background.is_some() || style.border_width > 0.0 {
+    if style.background.is_some()
+        || style.border_width > 0.0
+        || style.shadow.is_some()
+    {
         Some(Primitive::Quad {
             bounds,
             background: style
@@ -71,6 +110,7 @@ pub(crate) fn background(
             border_radius: style.border_radius,
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: style.shadow,
         })
     } else {
         None
@@ -36,6 +36,7 @@ where
                         }),
                     }),
                     offset: Vector::new(0, 0),
+                    border_radius: 0.0.into(),
                 }
             },
             {
@@ -0,0 +1,94 @@
+//! Display documents written in Markdown.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::markdown::{self, Appearance, Decoration};
+use iced_native::{Background, Color, Element, Font, Layout, Point, Rectangle};
+
+pub use iced_native::markdown::{parse, Block, Inline};
+pub use iced_style::markdown::{Style, StyleSheet};
+
+/// A document written in Markdown.
+///
+/// This is an alias of an `iced_native` markdown document with an
+/// `iced_wgpu::Renderer`.
+pub type Markdown<'a, Message, Backend> =
+    iced_native::Markdown<'a, Message, Renderer<Backend>>;
+
+impl<B> markdown::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text + backend::Image,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn appearance(style: &Self::Style) -> Appearance<Font> {
+        let style = style.style();
+
+        Appearance {
+            text_size: style.text_size,
+            heading_sizes: style.heading_sizes,
+            text_color: style.text_color,
+            font: style.font,
+            heading_font: style.heading_font,
+            strong_font: style.strong_font,
+            emphasis_font: style.emphasis_font,
+            code_font: style.code_font,
+            code_size: style.code_size,
+            code_color: style.code_color,
+            code_background: style.code_background,
+            code_border_radius: style.code_border_radius,
+            link_color: style.link_color,
+            quote_color: style.quote_color,
+            quote_border_color: style.quote_border_color,
+            quote_border_width: style.quote_border_width,
+        }
+    }
+
+    fn decorate<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        decoration: Decoration,
+        appearance: &Appearance<Font>,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_interaction) = content.draw(
+            self,
+            defaults,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        let decoration = match decoration {
+            Decoration::CodeBlock => Primitive::Quad {
+                bounds,
+                background: appearance.code_background,
+                border_radius: appearance.code_border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: None,
+            },
+            Decoration::Quote => Primitive::Quad {
+                bounds: Rectangle {
+                    width: appearance.quote_border_width,
+                    ..bounds
+                },
+                background: Background::Color(appearance.quote_border_color),
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: None,
+            },
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![decoration, content],
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -0,0 +1,47 @@
+//! Composite some content with a given opacity.
+use crate::defaults::Defaults;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::opacity;
+use iced_native::{Element, Layout, Point, Rectangle};
+
+/// An element drawing its content with some opacity.
+///
+/// This is an alias of an `iced_native` opacity with a default
+/// `Renderer`.
+pub type Opacity<'a, Message, Backend> =
+    iced_native::Opacity<'a, Message, Renderer<Backend>>;
+
+impl<B> opacity::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Defaults,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        opacity: f32,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_interaction) = content.draw(
+            self,
+            defaults,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        if opacity < 1.0 {
+            (
+                Primitive::Opacity {
+                    opacity,
+                    content: Box::new(content),
+                },
+                mouse_interaction,
+            )
+        } else {
+            (content, mouse_interaction)
+        }
+    }
+}
@@ -94,6 +94,7 @@ where
                     height: bounds.height + 0.5,
                 },
                 offset: Vector::new(0, 0),
+                border_radius: 0.0.into(),
                 content: Box::new(Primitive::Translate {
                     translation: Vector::new(
                         cursor_position.x - bounds.x - origin.x,
@@ -141,9 +142,10 @@ where
                             },
                         },
                         background: highlight.color.into(),
-                        border_radius: 0.0,
+                        border_radius: 0.0.into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        shadow: None,
                     });
                 }
 
@@ -2,7 +2,8 @@
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::{
-    mouse, Font, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+    mouse, Direction, Font, HorizontalAlignment, Point, Rectangle,
+    VerticalAlignment,
 };
 use iced_style::menu;
 
@@ -49,6 +50,7 @@ where
             border_color: style.border_color,
             border_width: style.border_width,
             border_radius: style.border_radius,
+            shadow: None,
         };
 
         let arrow_down = Primitive::Text {
@@ -63,6 +65,9 @@ where
             color: style.text_color,
             horizontal_alignment: HorizontalAlignment::Right,
             vertical_alignment: VerticalAlignment::Center,
+            line_height: None,
+            letter_spacing: 0.0,
+            direction: Direction::LeftToRight,
         };
 
         (
@@ -80,6 +85,9 @@ where
                         },
                         horizontal_alignment: HorizontalAlignment::Left,
                         vertical_alignment: VerticalAlignment::Center,
+                        line_height: None,
+                        letter_spacing: 0.0,
+                        direction: Direction::LeftToRight,
                     };
 
                     vec![background, label, arrow_down]
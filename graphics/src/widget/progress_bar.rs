@@ -46,6 +46,7 @@ where
                 border_radius: style.border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: None,
             }],
         };
 
@@ -60,6 +61,7 @@ where
                     border_radius: style.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: None,
                 };
 
                 Primitive::Group {
@@ -42,9 +42,10 @@ where
         let radio = Primitive::Quad {
             bounds,
             background: style.background,
-            border_radius: size / 2.0,
+            border_radius: (size / 2.0).into(),
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: None,
         };
 
         (
@@ -58,9 +59,10 @@ where
                             height: bounds.height - dot_size,
                         },
                         background: Background::Color(style.dot_color),
-                        border_radius: dot_size / 2.0,
+                        border_radius: (dot_size / 2.0).into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        shadow: None,
                     };
 
                     vec![radio, radio_circle, label]
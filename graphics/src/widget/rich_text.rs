@@ -0,0 +1,210 @@
+//! Write a paragraph of text made of spans with their own style.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::rich_text;
+use iced_native::text;
+use iced_native::{
+    Background, Color, Direction, Font, HorizontalAlignment, Point, Rectangle,
+    Size, VerticalAlignment,
+};
+
+/// A piece of text with its own style, part of a [`RichText`].
+///
+/// This is an alias of an `iced_native` span with an `iced_graphics::Font`.
+pub type Span<Message> = rich_text::Span<Message, Font>;
+
+/// A paragraph of text made of spans with their own style.
+///
+/// This is an alias of an `iced_native` rich text with an `iced_wgpu::Renderer`.
+pub type RichText<Message, Backend> =
+    iced_native::RichText<Message, Renderer<Backend>>;
+
+impl<B> rich_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn measure_spans<Message>(
+        &self,
+        spans: &[Span<Message>],
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Size,
+    ) -> (f32, f32) {
+        let spans = self.backend_spans(spans);
+
+        self.backend()
+            .measure_spans(&spans, spacing, direction, bounds)
+    }
+
+    fn span_at<Message>(
+        &self,
+        spans: &[Span<Message>],
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize> {
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        let spans = self.backend_spans(spans);
+        let point = Point::new(point.x - bounds.x, point.y - bounds.y);
+
+        self.backend()
+            .span_fragments(
+                &spans,
+                spacing,
+                direction,
+                bounds.size(),
+                horizontal_alignment,
+                vertical_alignment,
+            )
+            .into_iter()
+            .find(|fragment| fragment.bounds.contains(point))
+            .map(|fragment| fragment.span)
+    }
+
+    fn draw_spans<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        spans: &[Span<Message>],
+        spacing: text::Spacing,
+        direction: Direction,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self::Output {
+        let x = match horizontal_alignment {
+            HorizontalAlignment::Left => bounds.x,
+            HorizontalAlignment::Center => bounds.center_x(),
+            HorizontalAlignment::Right => bounds.x + bounds.width,
+        };
+
+        let y = match vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => bounds.center_y(),
+            VerticalAlignment::Bottom => bounds.y + bounds.height,
+        };
+
+        let default_size = self.backend().default_size();
+
+        let color =
+            |span: &Span<Message>| span.color.unwrap_or(defaults.text.color);
+
+        let text = Primitive::RichText {
+            spans: spans
+                .iter()
+                .map(|span| crate::Span {
+                    content: span.content.clone(),
+                    color: color(span),
+                    size: f32::from(span.size.unwrap_or(default_size)),
+                    font: span.font,
+                })
+                .collect(),
+            bounds: Rectangle { x, y, ..bounds },
+            horizontal_alignment,
+            vertical_alignment,
+            line_height: spacing.line_height,
+            letter_spacing: spacing.letter_spacing,
+            direction,
+        };
+
+        let is_decorated = spans
+            .iter()
+            .any(|span| span.underline || span.strikethrough);
+
+        let has_links = spans.iter().any(|span| span.link.is_some());
+
+        if !is_decorated && !has_links {
+            return (text, mouse::Interaction::default());
+        }
+
+        let fragments = {
+            let spans = self.backend_spans(spans);
+
+            self.backend().span_fragments(
+                &spans,
+                spacing,
+                direction,
+                bounds.size(),
+                horizontal_alignment,
+                vertical_alignment,
+            )
+        };
+
+        let mut primitives = vec![text];
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for fragment in fragments {
+            let span = &spans[fragment.span];
+
+            let fragment_bounds = Rectangle {
+                x: bounds.x + fragment.bounds.x,
+                y: bounds.y + fragment.bounds.y,
+                ..fragment.bounds
+            };
+
+            if span.link.is_some() && fragment_bounds.contains(cursor_position)
+            {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            let size = f32::from(span.size.unwrap_or(default_size));
+            let thickness = (size / 16.0).max(1.0);
+            let baseline = bounds.y + fragment.baseline;
+
+            let line = |y: f32| Primitive::Quad {
+                bounds: Rectangle {
+                    x: fragment_bounds.x,
+                    y,
+                    width: fragment_bounds.width,
+                    height: thickness,
+                },
+                background: Background::Color(color(span)),
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: None,
+            };
+
+            if span.underline {
+                primitives.push(line(baseline + thickness));
+            }
+
+            if span.strikethrough {
+                let ascent = baseline - fragment_bounds.y;
+                let y = baseline - ascent * 0.3 - thickness / 2.0;
+
+                primitives.push(line(y));
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+impl<B> Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn backend_spans<'a, Message>(
+        &self,
+        spans: &'a [Span<Message>],
+    ) -> Vec<backend::Span<'a>> {
+        let default_size = self.backend().default_size();
+
+        spans
+            .iter()
+            .map(|span| backend::Span {
+                content: &span.content,
+                size: f32::from(span.size.unwrap_or(default_size)),
+                font: span.font,
+            })
+            .collect()
+    }
+}
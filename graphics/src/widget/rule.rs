@@ -42,9 +42,10 @@ where
                     height: style.width as f32,
                 },
                 background: Background::Color(style.color),
-                border_radius: style.radius,
+                border_radius: style.radius.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: None,
             }
         } else {
             let line_x = (bounds.x + (bounds.width / 2.0)
@@ -62,9 +63,10 @@ where
                     height: line_height,
                 },
                 background: Background::Color(style.color),
-                border_radius: style.radius,
+                border_radius: style.radius.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: None,
             }
         };
 
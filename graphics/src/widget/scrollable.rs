@@ -91,6 +91,7 @@ where
                 let clip = Primitive::Clip {
                     bounds,
                     offset: Vector::new(0, offset),
+                    border_radius: 0.0.into(),
                     content: Box::new(content),
                 };
 
@@ -115,6 +116,7 @@ where
                         border_radius: style.scroller.border_radius,
                         border_width: style.scroller.border_width,
                         border_color: style.scroller.border_color,
+                        shadow: None,
                     }
                 } else {
                     Primitive::None
@@ -129,6 +131,7 @@ where
                         border_radius: style.border_radius,
                         border_width: style.border_width,
                         border_color: style.border_color,
+                        shadow: None,
                     }
                 } else {
                     Primitive::None
@@ -0,0 +1,156 @@
+//! Display read-only text that can be selected and copied.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::selectable_text;
+use iced_native::text;
+use iced_native::{
+    Background, Color, Direction, Font, HorizontalAlignment, Point, Rectangle,
+    Size, VerticalAlignment,
+};
+
+pub use iced_style::selectable_text::StyleSheet;
+
+/// A paragraph of read-only text that can be selected and copied.
+///
+/// This is an alias of an `iced_native` selectable text with an
+/// `iced_wgpu::Renderer`.
+pub type SelectableText<'a, Backend> =
+    iced_native::SelectableText<'a, Renderer<Backend>>;
+
+impl<B> selectable_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn character_bounds(
+        &self,
+        content: &str,
+        size: u16,
+        font: Font,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<(usize, Rectangle)> {
+        self.backend().character_bounds(
+            backend::Span {
+                content,
+                size: f32::from(size),
+                font,
+            },
+            text::Spacing::default(),
+            direction,
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+        )
+    }
+
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Font,
+        color: Option<Color>,
+        direction: Direction,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        selection: Option<(usize, usize)>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let x = match horizontal_alignment {
+            HorizontalAlignment::Left => bounds.x,
+            HorizontalAlignment::Center => bounds.center_x(),
+            HorizontalAlignment::Right => bounds.x + bounds.width,
+        };
+
+        let y = match vertical_alignment {
+            VerticalAlignment::Top => bounds.y,
+            VerticalAlignment::Center => bounds.center_y(),
+            VerticalAlignment::Bottom => bounds.y + bounds.height,
+        };
+
+        let text = Primitive::Text {
+            content: content.to_string(),
+            size: f32::from(size),
+            bounds: Rectangle { x, y, ..bounds },
+            color: color.unwrap_or(defaults.text.color),
+            font,
+            horizontal_alignment,
+            vertical_alignment,
+            line_height: None,
+            letter_spacing: 0.0,
+            direction,
+        };
+
+        let mouse_interaction = if bounds.contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        };
+
+        let (start, end) = match selection {
+            Some(selection) => selection,
+            None => return (text, mouse_interaction),
+        };
+
+        let characters = self.backend().character_bounds(
+            backend::Span {
+                content,
+                size: f32::from(size),
+                font,
+            },
+            text::Spacing::default(),
+            direction,
+            bounds.size(),
+            horizontal_alignment,
+            vertical_alignment,
+        );
+
+        // Merge the bounds of the selected characters of every line
+        let mut lines: Vec<Rectangle> = Vec::new();
+
+        for (_, character) in characters
+            .iter()
+            .filter(|(index, _)| *index >= start && *index < end)
+        {
+            match lines.last_mut() {
+                Some(line) if (line.y - character.y).abs() < 0.5 => {
+                    let left = line.x.min(character.x);
+                    let right = (line.x + line.width)
+                        .max(character.x + character.width);
+
+                    line.x = left;
+                    line.width = right - left;
+                    line.height = line.height.max(character.height);
+                }
+                _ => lines.push(*character),
+            }
+        }
+
+        let mut primitives: Vec<Primitive> = lines
+            .into_iter()
+            .map(|line| Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + line.x,
+                    y: bounds.y + line.y,
+                    ..line
+                },
+                background: Background::Color(style_sheet.selection_color()),
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                shadow: None,
+            })
+            .collect();
+
+        primitives.push(text);
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -54,9 +54,10 @@ where
                     height: 2.0,
                 },
                 background: Background::Color(style.rail_colors.0),
-                border_radius: 0.0,
+                border_radius: 0.0.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: None,
             },
             Primitive::Quad {
                 bounds: Rectangle {
@@ -66,9 +67,10 @@ where
                     height: 2.0,
                 },
                 background: Background::Color(style.rail_colors.1),
-                border_radius: 0.0,
+                border_radius: 0.0.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                shadow: None,
             },
         );
 
@@ -102,9 +104,10 @@ where
                 height: handle_height,
             },
             background: Background::Color(style.handle.color),
-            border_radius: handle_border_radius,
+            border_radius: handle_border_radius.into(),
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            shadow: None,
         };
 
         (
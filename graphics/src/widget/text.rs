@@ -2,11 +2,16 @@
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::mouse;
-use iced_native::text;
+use iced_native::text::{self, Overflow, Spacing, Truncation};
 use iced_native::{
-    Color, Font, HorizontalAlignment, Rectangle, Size, VerticalAlignment,
+    Color, Direction, Font, Hasher, HorizontalAlignment, Rectangle, Size,
+    Vector, VerticalAlignment,
 };
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher as _};
+
 /// A paragraph of text.
 ///
 /// This is an alias of an `iced_native` text with an `iced_wgpu::Renderer`.
@@ -14,6 +19,8 @@ pub type Text<Backend> = iced_native::Text<Renderer<Backend>>;
 
 use std::f32;
 
+const ELLIPSIS: &str = "…";
+
 impl<B> text::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
@@ -30,9 +37,27 @@ where
         size: u16,
         font: Font,
         bounds: Size,
+        overflow: Overflow,
+        spacing: Spacing,
+        direction: Direction,
     ) -> (f32, f32) {
+        let size = f32::from(size);
+        let content =
+            self.fit(content, size, font, bounds, overflow, spacing, direction);
+
+        self.backend().measure(
+            &content,
+            size,
+            font,
+            layout_bounds(bounds, overflow),
+            spacing,
+            direction,
+        )
+    }
+
+    fn baseline(&self, size: u16, font: Font, spacing: Spacing) -> f32 {
         self.backend()
-            .measure(content, f32::from(size), font, bounds)
+            .baseline(f32::from(size), font, spacing.line_height)
     }
 
     fn draw(
@@ -45,6 +70,9 @@ where
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
+        overflow: Overflow,
+        spacing: Spacing,
+        direction: Direction,
     ) -> Self::Output {
         let x = match horizontal_alignment {
             iced_native::HorizontalAlignment::Left => bounds.x,
@@ -58,17 +86,336 @@ where
             iced_native::VerticalAlignment::Bottom => bounds.y + bounds.height,
         };
 
-        (
-            Primitive::Text {
-                content: content.to_string(),
-                size: f32::from(size),
-                bounds: Rectangle { x, y, ..bounds },
-                color: color.unwrap_or(defaults.text.color),
-                font,
-                horizontal_alignment,
-                vertical_alignment,
+        let size = f32::from(size);
+
+        let content = self.fit(
+            content,
+            size,
+            font,
+            bounds.size(),
+            overflow,
+            spacing,
+            direction,
+        );
+
+        let text = Primitive::Text {
+            content: content.into_owned(),
+            size,
+            bounds: Rectangle {
+                x,
+                y,
+                ..Rectangle::new(
+                    bounds.position(),
+                    layout_bounds(bounds.size(), overflow),
+                )
             },
-            mouse::Interaction::default(),
-        )
+            color: color.unwrap_or(defaults.text.color),
+            font,
+            horizontal_alignment,
+            vertical_alignment,
+            line_height: spacing.line_height,
+            letter_spacing: spacing.letter_spacing,
+            direction,
+        };
+
+        // Lines that do not wrap may still overflow when they are not
+        // truncated with an ellipsis
+        let primitive =
+            if !overflow.wrap && overflow.truncation == Truncation::Clip {
+                Primitive::Clip {
+                    bounds,
+                    offset: Vector::new(0, 0),
+                    border_radius: 0.0.into(),
+                    content: Box::new(text),
+                }
+            } else {
+                text
+            };
+
+        (primitive, mouse::Interaction::default())
+    }
+}
+
+impl<B> Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    /// Fits the contents of a paragraph in the given bounds, reusing the
+    /// result of the last time the same paragraph was fitted in the same
+    /// bounds.
+    fn fit<'a>(
+        &self,
+        content: &'a str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        overflow: Overflow,
+        spacing: Spacing,
+        direction: Direction,
+    ) -> Cow<'a, str> {
+        if overflow.wrap && overflow.max_lines.is_none() {
+            return Cow::Borrowed(content);
+        }
+
+        let key = {
+            let mut hasher = Hasher::default();
+
+            content.hash(&mut hasher);
+            size.to_bits().hash(&mut hasher);
+            font.hash(&mut hasher);
+            bounds.width.to_bits().hash(&mut hasher);
+            bounds.height.to_bits().hash(&mut hasher);
+            overflow.hash(&mut hasher);
+            spacing.hash(&mut hasher);
+            direction.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        let mut truncations = self.truncations().borrow_mut();
+
+        let entry = truncations.entries.entry(key).or_insert_with(|| {
+            let fitted = fit(
+                self.backend(),
+                content,
+                size,
+                font,
+                bounds,
+                overflow,
+                spacing,
+                direction,
+            );
+
+            Entry {
+                content: if fitted == content {
+                    None
+                } else {
+                    Some(fitted.into_owned())
+                },
+                is_used: false,
+            }
+        });
+
+        entry.is_used = true;
+
+        match &entry.content {
+            Some(fitted) => Cow::Owned(fitted.clone()),
+            None => Cow::Borrowed(content),
+        }
+    }
+}
+
+/// The contents of the paragraphs that have been truncated to fit their
+/// bounds.
+#[derive(Debug, Default)]
+pub(crate) struct Truncations {
+    entries: HashMap<u64, Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    content: Option<String>,
+    is_used: bool,
+}
+
+impl Truncations {
+    /// Discards the truncations that have not been used since the last
+    /// trim.
+    pub(crate) fn trim(&mut self) {
+        self.entries.retain(|_, entry| entry.is_used);
+
+        for entry in self.entries.values_mut() {
+            entry.is_used = false;
+        }
+    }
+}
+
+/// Returns the bounds used to lay out text with the given [`Overflow`].
+fn layout_bounds(bounds: Size, overflow: Overflow) -> Size {
+    if overflow.wrap {
+        bounds
+    } else {
+        Size::new(f32::INFINITY, bounds.height)
+    }
+}
+
+/// Fits the contents of a paragraph with the given [`Spacing`] and
+/// [`Direction`] in the given bounds, applying the provided [`Overflow`].
+fn fit<'a, B: backend::Text>(
+    backend: &B,
+    content: &'a str,
+    size: f32,
+    font: Font,
+    bounds: Size,
+    overflow: Overflow,
+    spacing: Spacing,
+    direction: Direction,
+) -> Cow<'a, str> {
+    let max_lines = overflow.max_lines.map(usize::from);
+
+    if overflow.wrap {
+        let max_lines = match max_lines {
+            Some(max_lines) => max_lines,
+            None => return Cow::Borrowed(content),
+        };
+
+        let lines = |content: &str| {
+            backend.line_breaks(
+                backend::Span {
+                    content,
+                    size,
+                    font,
+                },
+                spacing,
+                direction,
+                bounds.width,
+            )
+        };
+
+        // The start of the first line that does not fit, if any
+        let cut = match lines(content).get(max_lines) {
+            Some(line) => line.start,
+            None => return Cow::Borrowed(content),
+        };
+
+        match overflow.truncation {
+            Truncation::Clip => Cow::Borrowed(content[..cut].trim_end()),
+            truncation => Cow::Owned(ellipsize(content, truncation, |text| {
+                lines(text).len() <= max_lines
+            })),
+        }
+    } else {
+        let fits = |line: &str| {
+            let (width, _) = backend.measure(
+                line,
+                size,
+                font,
+                Size::INFINITY,
+                spacing,
+                direction,
+            );
+
+            width <= bounds.width
+        };
+
+        let lines: Vec<&str> = content.lines().collect();
+
+        let is_cut = max_lines.filter(|max| lines.len() > *max).is_some();
+        let kept = max_lines.unwrap_or(lines.len()).min(lines.len());
+
+        if overflow.truncation == Truncation::Clip {
+            return if is_cut {
+                Cow::Owned(lines[..kept].join("\n"))
+            } else {
+                Cow::Borrowed(content)
+            };
+        }
+
+        let lines: Vec<Cow<'_, str>> = lines[..kept]
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if is_cut && i + 1 == kept {
+                    Cow::Owned(ellipsize(line, Truncation::End, fits))
+                } else if !fits(line) {
+                    Cow::Owned(ellipsize(line, overflow.truncation, fits))
+                } else {
+                    Cow::Borrowed(*line)
+                }
+            })
+            .collect();
+
+        Cow::Owned(lines.join("\n"))
+    }
+}
+
+/// Replaces the end or the middle of the given text with an ellipsis,
+/// keeping as many characters as possible while the result fits.
+fn ellipsize(
+    text: &str,
+    truncation: Truncation,
+    fits: impl Fn(&str) -> bool,
+) -> String {
+    let boundaries: Vec<usize> = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .collect();
+
+    let characters = boundaries.len() - 1;
+
+    let candidate = |kept: usize| match truncation {
+        Truncation::Middle => {
+            let start = boundaries[kept - kept / 2];
+            let end = boundaries[characters - kept / 2];
+
+            format!("{}{}{}", &text[..start], ELLIPSIS, &text[end..])
+        }
+        _ => format!("{}{}", text[..boundaries[kept]].trim_end(), ELLIPSIS),
+    };
+
+    // Binary search the amount of characters to keep
+    let (mut low, mut high) = (0, characters);
+
+    while low < high {
+        let middle = high - (high - low) / 2;
+
+        if fits(&candidate(middle)) {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    candidate(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_most(characters: usize) -> impl Fn(&str) -> bool {
+        move |text| text.chars().count() <= characters
+    }
+
+    #[test]
+    fn ellipsize_keeps_the_start() {
+        assert_eq!(
+            ellipsize("Hello, world", Truncation::End, at_most(6)),
+            "Hello…"
+        );
+    }
+
+    #[test]
+    fn ellipsize_trims_whitespace_before_the_ellipsis() {
+        assert_eq!(
+            ellipsize("Hello, world", Truncation::End, at_most(7)),
+            "Hello,…"
+        );
+    }
+
+    #[test]
+    fn ellipsize_keeps_both_ends() {
+        assert_eq!(
+            ellipsize("abcdefghij", Truncation::Middle, at_most(7)),
+            "abc…hij"
+        );
+    }
+
+    #[test]
+    fn ellipsize_respects_character_boundaries() {
+        assert_eq!(
+            ellipsize("日本語のテキスト", Truncation::End, at_most(4)),
+            "日本語…"
+        );
+        assert_eq!(
+            ellipsize("日本語のテキスト", Truncation::Middle, at_most(5)),
+            "日本…スト"
+        );
+    }
+
+    #[test]
+    fn ellipsize_leaves_an_ellipsis_when_nothing_fits() {
+        assert_eq!(ellipsize("Hello", Truncation::End, |_| false), "…");
     }
 }
@@ -4,10 +4,11 @@
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::mouse;
+use iced_native::text;
 use iced_native::text_input::{self, cursor};
 use iced_native::{
-    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
-    Vector, VerticalAlignment,
+    Background, Color, Direction, Font, HorizontalAlignment, Point, Rectangle,
+    Size, Vector, VerticalAlignment,
 };
 use std::f32;
 
@@ -29,8 +30,14 @@ where
     fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
         let backend = self.backend();
 
-        let (width, _) =
-            backend.measure(value, f32::from(size), font, Size::INFINITY);
+        let (width, _) = backend.measure(
+            value,
+            f32::from(size),
+            font,
+            Size::INFINITY,
+            text::Spacing::default(),
+            Direction::LeftToRight,
+        );
 
         width
     }
@@ -44,11 +51,14 @@ where
         state: &text_input::State,
     ) -> f32 {
         if state.is_focused() {
-            let cursor = state.cursor();
+            let composition = state.composition(value);
 
-            let focus_position = match cursor.state(value) {
-                cursor::State::Index(i) => i,
-                cursor::State::Selection { end, .. } => end,
+            let (value, focus_position) = match &composition {
+                Some(composition) => (&composition.value, composition.caret),
+                None => match state.cursor().state(value) {
+                    cursor::State::Index(i) => (value, i),
+                    cursor::State::Selection { end, .. } => (value, end),
+                },
             };
 
             let (_, offset) = measure_cursor_and_scroll_offset(
@@ -80,6 +90,14 @@ where
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
+        let composition =
+            state.composition(value).filter(|_| state.is_focused());
+
+        let value = composition
+            .as_ref()
+            .map(|composition| &composition.value)
+            .unwrap_or(value);
+
         let style = if state.is_focused() {
             style_sheet.focused()
         } else if is_mouse_over {
@@ -94,6 +112,7 @@ where
             border_radius: style.border_radius,
             border_width: style.border_width,
             border_color: style.border_color,
+            shadow: None,
         };
 
         let text = value.to_string();
@@ -118,12 +137,19 @@ where
             size: f32::from(size),
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Center,
+            line_height: None,
+            letter_spacing: 0.0,
+            direction: Direction::LeftToRight,
         };
 
         let (contents_primitive, offset) = if state.is_focused() {
-            let cursor = state.cursor();
+            // The caret is placed inside the text being composed, if any
+            let cursor_state = match &composition {
+                Some(composition) => cursor::State::Index(composition.caret),
+                None => state.cursor().state(value),
+            };
 
-            let (cursor_primitive, offset) = match cursor.state(value) {
+            let (cursor_primitive, offset) = match cursor_state {
                 cursor::State::Index(position) => {
                     let (text_value_width, offset) =
                         measure_cursor_and_scroll_offset(
@@ -146,9 +172,10 @@ where
                             background: Background::Color(
                                 style_sheet.value_color(),
                             ),
-                            border_radius: 0.0,
+                            border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            shadow: None,
                         },
                         offset,
                     )
@@ -190,9 +217,10 @@ where
                             background: Background::Color(
                                 style_sheet.selection_color(),
                             ),
-                            border_radius: 0.0,
+                            border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            shadow: None,
                         },
                         if end == right {
                             right_offset
@@ -203,10 +231,45 @@ where
                 }
             };
 
+            let mut primitives = vec![cursor_primitive, text_value];
+
+            if let Some(composition) = &composition {
+                let (start, _) = measure_cursor_and_scroll_offset(
+                    self,
+                    text_bounds,
+                    value,
+                    size,
+                    composition.start,
+                    font,
+                );
+
+                let (end, _) = measure_cursor_and_scroll_offset(
+                    self,
+                    text_bounds,
+                    value,
+                    size,
+                    composition.end,
+                    font,
+                );
+
+                // The preedit text is underlined
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + start,
+                        y: text_bounds.y + text_bounds.height - 1.0,
+                        width: end - start,
+                        height: 1.0,
+                    },
+                    background: Background::Color(style_sheet.value_color()),
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    shadow: None,
+                });
+            }
+
             (
-                Primitive::Group {
-                    primitives: vec![cursor_primitive, text_value],
-                },
+                Primitive::Group { primitives },
                 Vector::new(offset as u32, 0),
             )
         } else {
@@ -223,6 +286,7 @@ where
             Primitive::Clip {
                 bounds: text_bounds,
                 offset,
+                border_radius: 0.0.into(),
                 content: Box::new(contents_primitive),
             }
         } else {
@@ -50,4 +50,29 @@ pub trait Compositor: Sized {
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         overlay: &[T],
     ) -> mouse::Interaction;
+
+    /// Draws the output primitives to the next frame of the given
+    /// [`SwapChain`], like [`draw`], and reads the pixels of the frame back.
+    ///
+    /// The pixels are returned as an RGBA buffer, row by row from the top-left
+    /// corner. Its dimensions are the physical size of the [`Viewport`]. The
+    /// overlay is drawn to the frame, but it is not part of the pixels.
+    ///
+    /// Nothing is drawn and `None` is returned if the frame cannot be
+    /// captured, like when the texture format of the surface is not supported.
+    /// By default, frames are never captured.
+    ///
+    /// [`SwapChain`]: Self::SwapChain
+    /// [`draw`]: Self::draw
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _swap_chain: &mut Self::SwapChain,
+        _viewport: &Viewport,
+        _background_color: Color,
+        _output: &<Self::Renderer as iced_native::Renderer>::Output,
+        _overlay: &[T],
+    ) -> Option<(Vec<u8>, mouse::Interaction)> {
+        None
+    }
 }
@@ -0,0 +1,388 @@
+//! Benchmarks the layout caching of a `UserInterface`.
+//!
+//! Every view is laid out in three ways per benchmark: without any cache, from
+//! an empty `Cache`, and from the `Cache` of a previous view where only a
+//! single `Text` was different.
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use iced_native::{
+    button, checkbox, column, container, layout, row, text, text_input, Align,
+    Button, Cache, Checkbox, Color, Column, Container, Direction, Element,
+    Font, HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Size,
+    Text, TextInput, UserInterface, VerticalAlignment,
+};
+
+fn todos(c: &mut Criterion) {
+    let mut todos = Todos::new(500);
+
+    bench_view(c, "todos", &mut todos, |todos, revision| {
+        todos.view(revision)
+    });
+}
+
+fn game_of_life(c: &mut Criterion) {
+    let mut grid = Grid::new(64, 64);
+
+    bench_view(c, "game_of_life", &mut grid, |grid, generation| {
+        grid.view(generation)
+    });
+}
+
+fn bench_view<T>(
+    c: &mut Criterion,
+    name: &str,
+    state: &mut T,
+    view: impl Fn(&mut T, usize) -> Element<'_, (), Renderer>,
+) {
+    let bounds = Size::new(1024.0, 768.0);
+    let mut renderer = Renderer;
+
+    let cache = UserInterface::build(
+        view(state, 0),
+        bounds,
+        Cache::new(),
+        &mut renderer,
+    )
+    .into_cache();
+
+    let mut group = c.benchmark_group(name);
+
+    let _ = group.bench_function("uncached", |b| {
+        b.iter(|| {
+            view(state, 1)
+                .layout(&Renderer, &layout::Limits::new(Size::ZERO, bounds))
+        })
+    });
+
+    let _ = group.bench_function("cold", |b| {
+        b.iter_batched(
+            Cache::new,
+            |cache| {
+                UserInterface::build(
+                    view(state, 1),
+                    bounds,
+                    cache,
+                    &mut Renderer,
+                )
+                .into_cache()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    let _ = group.bench_function("incremental", |b| {
+        b.iter_batched(
+            || cache.clone(),
+            |cache| {
+                UserInterface::build(
+                    view(state, 1),
+                    bounds,
+                    cache,
+                    &mut Renderer,
+                )
+                .into_cache()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+struct Todos {
+    input: text_input::State,
+    filters: [button::State; 3],
+    tasks: Vec<(bool, String, button::State)>,
+}
+
+impl Todos {
+    fn new(count: usize) -> Self {
+        Todos {
+            input: text_input::State::new(),
+            filters: Default::default(),
+            tasks: (0..count)
+                .map(|i| {
+                    (
+                        i % 3 == 0,
+                        format!("Task number {}", i),
+                        Default::default(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn view(&mut self, revision: usize) -> Element<'_, (), Renderer> {
+        let title = Text::new("todos")
+            .width(Length::Fill)
+            .size(100)
+            .horizontal_alignment(HorizontalAlignment::Center);
+
+        let input = TextInput::new(
+            &mut self.input,
+            "What needs to be done?",
+            "",
+            |_| (),
+        )
+        .padding(15)
+        .size(30);
+
+        let [all, active, completed] = &mut self.filters;
+
+        let controls = Row::new()
+            .spacing(20)
+            .align_items(Align::Center)
+            .push(
+                Text::new(format!("{} tasks left", revision))
+                    .width(Length::Fill),
+            )
+            .push(Button::new(all, Text::new("All")))
+            .push(Button::new(active, Text::new("Active")))
+            .push(Button::new(completed, Text::new("Completed")));
+
+        let tasks = self.tasks.iter_mut().fold(
+            Column::new().spacing(20),
+            |column, (is_completed, description, edit)| {
+                column.push(
+                    Row::new()
+                        .spacing(20)
+                        .align_items(Align::Center)
+                        .push(
+                            Checkbox::new(
+                                *is_completed,
+                                description.as_str(),
+                                |_| (),
+                            )
+                            .width(Length::Fill),
+                        )
+                        .push(Button::new(edit, Text::new("Edit"))),
+                )
+            },
+        );
+
+        Container::new(
+            Column::new()
+                .max_width(800)
+                .spacing(20)
+                .push(title)
+                .push(input)
+                .push(controls)
+                .push(tasks),
+        )
+        .width(Length::Fill)
+        .center_x()
+        .into()
+    }
+}
+
+struct Grid {
+    cells: Vec<Vec<bool>>,
+}
+
+impl Grid {
+    fn new(rows: usize, columns: usize) -> Self {
+        Grid {
+            cells: (0..rows)
+                .map(|i| (0..columns).map(|j| (i + j) % 2 == 0).collect())
+                .collect(),
+        }
+    }
+
+    fn view(&mut self, generation: usize) -> Element<'_, (), Renderer> {
+        let grid = self.cells.iter().fold(Column::new(), |column, row| {
+            column.push(row.iter().fold(Row::new(), |row, _is_alive| {
+                row.push(
+                    Container::new(Text::new(""))
+                        .width(Length::Units(10))
+                        .height(Length::Units(10)),
+                )
+            }))
+        });
+
+        Column::new()
+            .spacing(10)
+            .push(Text::new(format!("Generation: {}", generation)))
+            .push(grid)
+            .into()
+    }
+}
+
+/// A renderer that measures text by approximating the width of its
+/// characters.
+#[derive(Debug, Clone, Copy)]
+struct Renderer;
+
+impl iced_native::Renderer for Renderer {
+    type Output = ();
+    type Defaults = ();
+
+    fn overlay(&mut self, _base: (), _overlay: (), _overlay_bounds: Rectangle) {
+    }
+}
+
+impl column::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl row::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl text::Renderer for Renderer {
+    type Font = Font;
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure(
+        &self,
+        content: &str,
+        size: u16,
+        _font: Font,
+        bounds: Size,
+        _overflow: text::Overflow,
+        _spacing: text::Spacing,
+        _direction: Direction,
+    ) -> (f32, f32) {
+        let width = content.chars().count() as f32 * f32::from(size) * 0.5;
+        let lines = (width / bounds.width).ceil().max(1.0);
+
+        (width.min(bounds.width), lines * f32::from(size))
+    }
+
+    fn baseline(&self, size: u16, _font: Font, _spacing: text::Spacing) -> f32 {
+        f32::from(size) * 0.8
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _color: Option<Color>,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _overflow: text::Overflow,
+        _spacing: text::Spacing,
+        _direction: Direction,
+    ) {
+    }
+}
+
+impl container::Renderer for Renderer {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _style: &Self::Style,
+        _clip: bool,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl button::Renderer for Renderer {
+    const DEFAULT_PADDING: u16 = 5;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _is_disabled: bool,
+        _is_pressed: bool,
+        _style: &Self::Style,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl checkbox::Renderer for Renderer {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_checked: bool,
+        _is_mouse_over: bool,
+        _label: (),
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl text_input::Renderer for Renderer {
+    type Style = ();
+
+    fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
+        text::Renderer::measure(
+            self,
+            value,
+            size,
+            font,
+            Size::INFINITY,
+            text::Overflow::default(),
+            text::Spacing::default(),
+            Direction::LeftToRight,
+        )
+        .0
+    }
+
+    fn offset(
+        &self,
+        _text_bounds: Rectangle,
+        _font: Font,
+        _size: u16,
+        _value: &text_input::Value,
+        _state: &text_input::State,
+    ) -> f32 {
+        0.0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _text_bounds: Rectangle,
+        _cursor_position: Point,
+        _font: Font,
+        _size: u16,
+        _placeholder: &str,
+        _value: &text_input::Value,
+        _state: &text_input::State,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+criterion_group!(benches, todos, game_of_life);
+criterion_main!(benches);
@@ -3,4 +3,9 @@
 pub trait Clipboard {
     /// Returns the current content of the [`Clipboard`] as text.
     fn content(&self) -> Option<String>;
+
+    /// Writes the given text contents to the [`Clipboard`].
+    ///
+    /// By default, it does nothing.
+    fn write(&self, _contents: String) {}
 }
@@ -0,0 +1,126 @@
+//! Run asynchronous actions.
+mod action;
+
+pub use action::Action;
+
+use iced_futures::futures::Future;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A set of asynchronous actions to be performed by some runtime.
+///
+/// Besides running futures, a [`Command`] can ask the runtime to perform an
+/// [`Action`] that needs access to its internals, like loading a font in
+/// the renderer.
+///
+/// A [`iced_futures::Command`] only contains futures, which any executor can
+/// run without knowing about the shell. Loading a font or capturing a frame
+/// needs the renderer and the window owned by the shell instead, so these
+/// cannot be expressed as futures. Therefore, this [`Command`] wraps the
+/// futures together with the actions the shell performs itself.
+///
+/// You should be able to turn a future easily into a [`Command`], either by
+/// using the `From` trait or [`Command::perform`].
+pub struct Command<T> {
+    futures: iced_futures::Command<T>,
+    actions: Vec<Action<T>>,
+}
+
+impl<T> Command<T> {
+    /// Creates an empty [`Command`].
+    ///
+    /// In other words, a [`Command`] that does nothing.
+    pub fn none() -> Self {
+        Self {
+            futures: iced_futures::Command::none(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs a single [`Action`].
+    pub fn single(action: Action<T>) -> Self {
+        Self {
+            futures: iced_futures::Command::none(),
+            actions: vec![action],
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command {
+            futures: iced_futures::Command::perform(future, f),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = Arc::new(f);
+        let g = f.clone();
+
+        Command {
+            futures: self.futures.map(move |result| g(result)),
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| action.map(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        let mut futures = Vec::new();
+        let mut actions = Vec::new();
+
+        for command in commands {
+            futures.push(command.futures);
+            actions.extend(command.actions);
+        }
+
+        Self {
+            futures: iced_futures::Command::batch(futures),
+            actions,
+        }
+    }
+
+    /// Splits a [`Command`] into its futures and the actions that a runtime
+    /// needs to perform.
+    pub fn into_parts(self) -> (iced_futures::Command<T>, Vec<Action<T>>) {
+        (self.futures, self.actions)
+    }
+}
+
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static + Send,
+{
+    fn from(future: A) -> Self {
+        Self {
+            futures: iced_futures::Command::from(future),
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Command<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("actions", &self.actions)
+            .finish()
+    }
+}
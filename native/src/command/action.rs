@@ -0,0 +1,60 @@
+use crate::font;
+use crate::window;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// An action that a [`Command`] asks its runtime to perform.
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Load a font from its bytes and make it available to the renderer.
+    LoadFont {
+        /// The bytes of the font.
+        bytes: Cow<'static, [u8]>,
+
+        /// The function that turns the result of loading the font into a
+        /// message.
+        tagger: Box<dyn Fn(Result<(), font::Error>) -> T + Send>,
+    },
+
+    /// Capture the next frame presented in the window.
+    Screenshot {
+        /// The function that turns the [`window::Screenshot`] into a message.
+        tagger: Box<dyn Fn(window::Screenshot) -> T + Send>,
+    },
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the result of an [`Action`].
+    pub fn map<A>(self, f: Arc<dyn Fn(T) -> A + Send + Sync>) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Action::LoadFont { bytes, tagger } => Action::LoadFont {
+                bytes,
+                tagger: Box::new(move |result| f(tagger(result))),
+            },
+            Action::Screenshot { tagger } => Action::Screenshot {
+                tagger: Box::new(move |screenshot| f(tagger(screenshot))),
+            },
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::LoadFont { bytes, .. } => f
+                .debug_struct("Action::LoadFont")
+                .field("bytes", &bytes.len())
+                .finish(),
+            Action::Screenshot { .. } => {
+                f.debug_struct("Action::Screenshot").finish()
+            }
+        }
+    }
+}
@@ -2,9 +2,13 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
 use crate::{
-    Clipboard, Color, Hasher, Layout, Length, Point, Rectangle, Widget,
+    Clipboard, Color, Direction, Hasher, Layout, Length, Point, Rectangle,
+    Widget,
 };
 
+use std::cell::Cell;
+use std::hash::Hasher as _;
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -17,6 +21,7 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
     pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    layout_hash: Cell<Option<(u64, u64)>>,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -29,6 +34,7 @@ where
     ) -> Element<'a, Message, Renderer> {
         Element {
             widget: Box::new(widget),
+            layout_hash: Cell::new(None),
         }
     }
 
@@ -174,6 +180,7 @@ where
     {
         Element {
             widget: Box::new(Map::new(self.widget, f)),
+            layout_hash: Cell::new(None),
         }
     }
 
@@ -193,6 +200,28 @@ where
     {
         Element {
             widget: Box::new(Explain::new(self, color.into())),
+            layout_hash: Cell::new(None),
+        }
+    }
+
+    /// Sets the [`Direction`] in which the contents of the [`Element`] flow.
+    ///
+    /// The [`Direction`] will be inherited by any descendant that does not
+    /// set its own.
+    pub fn direction(
+        self,
+        direction: Direction,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(Directional {
+                element: self,
+                direction,
+            }),
+            layout_hash: Cell::new(None),
         }
     }
 
@@ -208,13 +237,33 @@ where
 
     /// Computes the layout of the [`Element`] in the given [`Limits`].
     ///
+    /// When called while building a [`UserInterface`], the layout of the
+    /// [`Element`] will be reused from the previous build if neither its
+    /// layout hash nor the given [`Limits`] have changed.
+    ///
     /// [`Limits`]: layout::Limits
+    /// [`UserInterface`]: crate::UserInterface
     pub fn layout(
         &self,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        if !layout::Cache::is_enabled() {
+            return self.widget.layout(renderer, limits);
+        }
+
+        let key = layout::Key::new(self.layout_hash(), limits);
+
+        if let Some(node) = layout::Cache::get(&key) {
+            return node;
+        }
+
+        layout::Cache::start();
+
+        let node = self.widget.layout(renderer, limits);
+        layout::Cache::insert(key, &node);
+
+        node
     }
 
     /// Processes a runtime [`Event`].
@@ -252,7 +301,36 @@ where
 
     /// Computes the _layout_ hash of the [`Element`].
     pub fn hash_layout(&self, state: &mut Hasher) {
-        self.widget.hash_layout(state);
+        state.write_u64(self.layout_hash());
+    }
+
+    /// Returns the layout hash of the subtree of the [`Element`].
+    ///
+    /// During a layout pass, the hash is computed only once and reused by
+    /// every ancestor, so the whole tree is hashed in linear time.
+    fn layout_hash(&self) -> u64 {
+        let pass = layout::Cache::pass();
+
+        if let (Some(pass), Some((hashed_pass, hash))) =
+            (pass, self.layout_hash.get())
+        {
+            if pass == hashed_pass {
+                return hash;
+            }
+        }
+
+        let hash = {
+            let hasher = &mut Hasher::default();
+            self.widget.hash_layout(hasher);
+
+            hasher.finish()
+        };
+
+        if let Some(pass) = pass {
+            self.layout_hash.set(Some((pass, hash)));
+        }
+
+        hash
     }
 
     /// Returns the overlay of the [`Element`], if there is any.
@@ -262,6 +340,15 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout)
     }
+
+    /// Returns the bounds of the text caret of the [`Element`], if any.
+    pub fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.widget.caret_bounds(layout, renderer)
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -359,6 +446,14 @@ where
             .overlay(layout)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.widget.caret_bounds(layout, renderer)
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -443,4 +538,99 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout)
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.element.caret_bounds(layout, renderer)
+    }
+}
+
+struct Directional<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    direction: Direction,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Directional<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.element.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.element.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.element
+            .widget
+            .layout(renderer, &limits.with_direction(self.direction))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.element.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.element.widget.draw(
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        self.direction.hash(state);
+        self.element.widget.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.element.overlay(layout)
+    }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.element.caret_bounds(layout, renderer)
+    }
 }
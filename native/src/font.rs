@@ -0,0 +1,31 @@
+//! Describe and load the fonts of your text.
+pub use iced_core::font::*;
+
+use crate::command::{self, Command};
+
+use std::borrow::Cow;
+
+/// An error that occurred while loading a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The renderer does not support loading fonts.
+    Unsupported,
+
+    /// The bytes do not contain a valid font.
+    Invalid,
+}
+
+/// Creates a [`Command`] that loads a font from its bytes.
+///
+/// Once loaded, the font can be used in any [`Text`] by describing its
+/// family with a [`Descriptor`].
+///
+/// [`Text`]: crate::widget::Text
+pub fn load(
+    bytes: impl Into<Cow<'static, [u8]>>,
+) -> Command<Result<(), Error>> {
+    Command::single(command::Action::LoadFont {
+        bytes: bytes.into(),
+        tagger: Box::new(|result| result),
+    })
+}
@@ -1,4 +1,5 @@
 //! Position your widgets properly.
+mod cache;
 mod debugger;
 mod limits;
 mod node;
@@ -9,7 +10,9 @@ pub use debugger::Debugger;
 pub use limits::Limits;
 pub use node::Node;
 
-use crate::{Point, Rectangle, Vector};
+pub(crate) use cache::{Cache, Key};
+
+use crate::{Direction, Point, Rectangle, Vector};
 
 /// The bounds of a [`Node`] and its children, using absolute coordinates.
 #[derive(Debug, Clone, Copy)]
@@ -52,6 +55,11 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Direction`] of the contents of the [`Layout`].
+    pub fn direction(&self) -> Direction {
+        self.node.direction()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
@@ -0,0 +1,278 @@
+use crate::layout::{Limits, Node};
+use crate::Direction;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem;
+use std::sync::atomic::{self, AtomicU64};
+
+/// A cache of the layout nodes of the subtrees of a user interface.
+///
+/// Every node is keyed by the layout hash of its subtree and the [`Limits`]
+/// used to compute it. This way, unchanged subtrees can reuse their layout
+/// even when some other part of the user interface changes.
+///
+/// Nodes that are not used during a layout pass are discarded at the end of
+/// it. Reusing a node also keeps the nodes of its descendants, so they can
+/// still be reused once some other part of the subtree changes.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Cache {
+    entries: HashMap<Key, Entry>,
+    unused: HashMap<Key, Entry>,
+    pass: u64,
+    // The keys of the children of every node being laid out, innermost last
+    children: Vec<Vec<Key>>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    node: Node,
+    children: Vec<Key>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Key {
+    hash: u64,
+    limits: [u32; 6],
+    direction: Direction,
+}
+
+impl Key {
+    pub fn new(hash: u64, limits: &Limits) -> Self {
+        let min = limits.min();
+        let max = limits.max();
+        let fill = limits.fill();
+
+        Key {
+            hash,
+            limits: [
+                min.width.to_bits(),
+                min.height.to_bits(),
+                max.width.to_bits(),
+                max.height.to_bits(),
+                fill.width.to_bits(),
+                fill.height.to_bits(),
+            ],
+            direction: limits.direction(),
+        }
+    }
+}
+
+impl Cache {
+    /// Runs a layout pass with the [`Cache`] enabled.
+    ///
+    /// Any [`Element`] laid out during the pass will try to reuse the cached
+    /// nodes.
+    ///
+    /// [`Element`]: crate::Element
+    pub fn scoped<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        if Self::is_enabled() {
+            return f();
+        }
+
+        self.unused = mem::take(&mut self.entries);
+        self.children.clear();
+        self.pass = PASSES.fetch_add(1, atomic::Ordering::Relaxed) + 1;
+
+        CURRENT.with(|current| {
+            *current.borrow_mut() = Some(mem::take(self));
+        });
+
+        let result = f();
+
+        CURRENT.with(|current| {
+            if let Some(cache) = current.borrow_mut().take() {
+                *self = cache;
+            }
+        });
+
+        self.unused.clear();
+
+        result
+    }
+
+    /// Returns true if a [`Cache`] is enabled for the current layout pass.
+    pub fn is_enabled() -> bool {
+        CURRENT.with(|current| current.borrow().is_some())
+    }
+
+    /// Returns the identifier of the layout pass running in the current
+    /// thread, if a [`Cache`] is enabled.
+    ///
+    /// Every pass gets a different identifier, so values computed during a
+    /// pass can be told apart from the ones of previous passes.
+    pub fn pass() -> Option<u64> {
+        CURRENT
+            .with(|current| current.borrow().as_ref().map(|cache| cache.pass))
+    }
+
+    /// Returns the cached [`Node`] for the given [`Key`], if any.
+    pub fn get(key: &Key) -> Option<Node> {
+        CURRENT.with(|current| {
+            let mut current = current.borrow_mut();
+            let cache = current.as_mut()?;
+
+            let node = cache.promote(key)?;
+            cache.add_child(*key);
+
+            Some(node)
+        })
+    }
+
+    /// Starts laying out the [`Node`] of a cache miss.
+    ///
+    /// The nodes laid out until the next call to [`Cache::insert`] are
+    /// recorded as its children.
+    pub fn start() {
+        CURRENT.with(|current| {
+            if let Some(cache) = current.borrow_mut().as_mut() {
+                cache.children.push(Vec::new());
+            }
+        });
+    }
+
+    /// Stores the [`Node`] for the given [`Key`] in the current [`Cache`],
+    /// finishing the layout started by the last call to [`Cache::start`].
+    pub fn insert(key: Key, node: &Node) {
+        CURRENT.with(|current| {
+            if let Some(cache) = current.borrow_mut().as_mut() {
+                let children = cache.children.pop().unwrap_or_default();
+
+                let _ = cache.entries.insert(
+                    key,
+                    Entry {
+                        node: node.clone(),
+                        children,
+                    },
+                );
+
+                cache.add_child(key);
+            }
+        });
+    }
+
+    /// Moves the entry of the given [`Key`] and the entries of all of its
+    /// descendants out of the unused ones, returning its [`Node`].
+    fn promote(&mut self, key: &Key) -> Option<Node> {
+        if let Some(entry) = self.entries.get(key) {
+            return Some(entry.node.clone());
+        }
+
+        let entry = self.unused.remove(key)?;
+
+        for child in &entry.children {
+            let _ = self.promote(child);
+        }
+
+        let node = entry.node.clone();
+        let _ = self.entries.insert(*key, entry);
+
+        Some(node)
+    }
+
+    fn add_child(&mut self, key: Key) {
+        if let Some(children) = self.children.last_mut() {
+            children.push(key);
+        }
+    }
+}
+
+thread_local! {
+    // The cache of the layout pass running in the current thread.
+    //
+    // `Widget::layout` does not receive a cache, so we need to make it
+    // available to every `Element` while a pass is running.
+    static CURRENT: RefCell<Option<Cache>> = RefCell::default();
+}
+
+// The amount of layout passes that have started, used to tell them apart.
+static PASSES: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Size};
+
+    fn node(width: f32) -> Node {
+        Node::new(Size::new(width, 10.0))
+    }
+
+    #[test]
+    fn reuses_nodes_of_unchanged_subtrees() {
+        let mut cache = Cache::default();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        cache.scoped(|| Cache::insert(Key::new(1, &limits), &node(50.0)));
+
+        let reused = cache.scoped(|| Cache::get(&Key::new(1, &limits)));
+
+        assert_eq!(reused.map(|node| node.size()), Some(Size::new(50.0, 10.0)));
+    }
+
+    #[test]
+    fn discards_nodes_when_limits_change() {
+        let mut cache = Cache::default();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let wider = Limits::new(Size::ZERO, Size::new(200.0, 100.0));
+
+        cache.scoped(|| Cache::insert(Key::new(1, &limits), &node(50.0)));
+
+        assert!(cache.scoped(|| Cache::get(&Key::new(1, &wider))).is_none());
+        assert!(cache.scoped(|| Cache::get(&Key::new(1, &limits))).is_none());
+    }
+
+    #[test]
+    fn discards_nodes_when_direction_changes() {
+        let mut cache = Cache::default();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let right_to_left = limits.with_direction(Direction::RightToLeft);
+
+        cache.scoped(|| Cache::insert(Key::new(1, &limits), &node(50.0)));
+
+        assert!(cache
+            .scoped(|| Cache::get(&Key::new(1, &right_to_left)))
+            .is_none());
+    }
+
+    #[test]
+    fn discards_nodes_when_hash_changes() {
+        let mut cache = Cache::default();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        cache.scoped(|| Cache::insert(Key::new(1, &limits), &node(50.0)));
+
+        assert!(cache.scoped(|| Cache::get(&Key::new(2, &limits))).is_none());
+    }
+
+    #[test]
+    fn keeps_the_descendants_of_reused_nodes() {
+        let mut cache = Cache::default();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+
+        let parent = Key::new(1, &limits);
+        let child = Key::new(2, &limits);
+
+        cache.scoped(|| {
+            Cache::start();
+            Cache::start();
+            Cache::insert(child, &node(10.0));
+            Cache::insert(parent, &node(50.0));
+        });
+
+        assert!(cache.scoped(|| Cache::get(&parent)).is_some());
+        assert!(cache.scoped(|| Cache::get(&child)).is_some());
+    }
+
+    #[test]
+    fn identifies_every_pass() {
+        let mut cache = Cache::default();
+
+        assert_eq!(Cache::pass(), None);
+
+        let first = cache.scoped(Cache::pass);
+        let second = cache.scoped(Cache::pass);
+
+        assert!(first.is_some() && second.is_some());
+        assert_ne!(first, second);
+    }
+}
@@ -92,7 +92,8 @@ where
             let (max_width, max_height) = axis.pack(available, max_cross);
 
             let child_limits =
-                Limits::new(Size::ZERO, Size::new(max_width, max_height));
+                Limits::new(Size::ZERO, Size::new(max_width, max_height))
+                    .with_direction(limits.direction());
 
             let layout = child.layout(renderer, &child_limits);
             let size = layout.size();
@@ -132,7 +133,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_main, min_cross),
                 Size::new(max_main, max_cross),
-            );
+            )
+            .with_direction(limits.direction());
 
             let layout = child.layout(renderer, &child_limits);
             cross = cross.max(axis.cross(layout.size()));
@@ -141,6 +143,27 @@ where
         }
     }
 
+    let baseline = match (&axis, align_items) {
+        (Axis::Horizontal, Align::Baseline) => {
+            let (ascent, descent) = nodes.iter().fold(
+                (0.0f32, 0.0f32),
+                |(ascent, descent), node| {
+                    let baseline = node_baseline(node);
+
+                    (
+                        ascent.max(baseline),
+                        descent.max(node.size().height - baseline),
+                    )
+                },
+            );
+
+            cross = cross.max(ascent + descent);
+
+            Some(ascent)
+        }
+        _ => None,
+    };
+
     let mut main = padding;
 
     for (i, node) in nodes.iter_mut().enumerate() {
@@ -154,7 +177,17 @@ where
 
         match axis {
             Axis::Horizontal => {
-                node.align(Align::Start, align_items, Size::new(0.0, cross));
+                if let Some(baseline) = baseline {
+                    let offset = baseline - node_baseline(node);
+
+                    node.move_to(Point::new(x, y + offset));
+                } else {
+                    node.align(
+                        Align::Start,
+                        align_items,
+                        Size::new(0.0, cross),
+                    );
+                }
             }
             Axis::Vertical => {
                 node.align(align_items, Align::Start, Size::new(cross, 0.0));
@@ -169,5 +202,27 @@ where
     let (width, height) = axis.pack(main - padding, cross);
     let size = limits.resolve(Size::new(width, height));
 
+    // Content flowing from right to left is laid out as usual and then
+    // mirrored horizontally. This reverses the order of the children of a
+    // horizontal axis and swaps the start and end alignments of a vertical
+    // one.
+    if limits.direction().is_rtl() {
+        for node in nodes.iter_mut() {
+            let bounds = node.bounds();
+
+            node.move_to(Point::new(
+                size.width - bounds.x - bounds.width + 2.0 * padding,
+                bounds.y,
+            ));
+        }
+    }
+
     Node::with_children(size.pad(padding), nodes)
 }
+
+/// Returns the baseline of a [`Node`] to be used during baseline alignment.
+///
+/// Nodes without a baseline are aligned by their bottom edge.
+fn node_baseline(node: &Node) -> f32 {
+    node.baseline().unwrap_or(node.size().height)
+}
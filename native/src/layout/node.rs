@@ -1,20 +1,22 @@
-use crate::{Align, Point, Rectangle, Size};
+use crate::{Align, Direction, Point, Rectangle, Size};
+
+use std::sync::Arc;
 
 /// The bounds of an element and its children.
+///
+/// The children of a [`Node`] cannot change once it is created, so they are
+/// shared between its clones. Cloning a [`Node`] is cheap!
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
-    children: Vec<Node>,
+    baseline: Option<f32>,
+    direction: Direction,
+    children: Option<Arc<[Node]>>,
 }
 
 impl Node {
     /// Creates a new [`Node`] with the given [`Size`].
     pub const fn new(size: Size) -> Self {
-        Self::with_children(size, Vec::new())
-    }
-
-    /// Creates a new [`Node`] with the given [`Size`] and children.
-    pub const fn with_children(size: Size, children: Vec<Node>) -> Self {
         Node {
             bounds: Rectangle {
                 x: 0.0,
@@ -22,10 +24,38 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
-            children,
+            baseline: None,
+            direction: Direction::LeftToRight,
+            children: None,
+        }
+    }
+
+    /// Creates a new [`Node`] with the given [`Size`] and children.
+    pub fn with_children(size: Size, children: Vec<Node>) -> Self {
+        Node {
+            children: Some(children.into()),
+            ..Self::new(size)
         }
     }
 
+    /// Sets the baseline of the [`Node`], as the distance from its top edge.
+    ///
+    /// Nodes of widgets displaying text should set their baseline, so they
+    /// can be aligned by it.
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Sets the [`Direction`] of the contents of the [`Node`].
+    ///
+    /// Nodes of widgets that need to know the [`Direction`] they were laid
+    /// out with when drawing should set it.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     pub fn size(&self) -> Size {
         Size::new(self.bounds.width, self.bounds.height)
@@ -36,9 +66,27 @@ impl Node {
         self.bounds
     }
 
+    /// Returns the baseline of the [`Node`], as the distance from its top
+    /// edge.
+    ///
+    /// If the [`Node`] does not have a baseline of its own, the baseline of
+    /// its first child that has one is used.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline.or_else(|| {
+            self.children().iter().find_map(|child| {
+                child.baseline().map(|baseline| child.bounds.y + baseline)
+            })
+        })
+    }
+
+    /// Returns the [`Direction`] of the contents of the [`Node`].
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
     /// Returns the children of the [`Node`].
     pub fn children(&self) -> &[Node] {
-        &self.children
+        self.children.as_deref().unwrap_or(&[])
     }
 
     /// Aligns the [`Node`] in the given space.
@@ -49,7 +97,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -59,7 +107,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
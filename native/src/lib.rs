@@ -33,7 +33,9 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod command;
 pub mod event;
+pub mod font;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
@@ -61,15 +63,17 @@ mod debug;
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Size, Vector, VerticalAlignment,
+    Align, Background, BorderRadius, Color, Direction, Font,
+    HorizontalAlignment, Length, LineHeight, Point, Rectangle, Shadow, Size,
+    Vector, VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{executor, futures};
 
 #[doc(no_inline)]
 pub use executor::Executor;
 
 pub use clipboard::Clipboard;
+pub use command::Command;
 pub use debug::Debug;
 pub use element::Element;
 pub use event::Event;
@@ -25,7 +25,9 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
-use crate::{layout, Element, Rectangle};
+use crate::{font, layout, Element, Rectangle};
+
+use std::borrow::Cow;
 
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
@@ -61,4 +63,15 @@ pub trait Renderer: Sized {
         overlay: Self::Output,
         overlay_bounds: Rectangle,
     ) -> Self::Output;
+
+    /// Loads a font from its bytes, making it available to the widgets drawn
+    /// by the [`Renderer`].
+    ///
+    /// By default, it returns [`font::Error::Unsupported`].
+    fn load_font(
+        &mut self,
+        _bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        Err(font::Error::Unsupported)
+    }
 }
@@ -1,10 +1,14 @@
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, Color, Element, Font,
+    blur, button, checkbox, code_view, column, container, image, opacity,
+    pane_grid, progress_bar, radio, rich_text, row, scrollable,
+    selectable_text, slider, text, text_input, Color, Direction, Element, Font,
     HorizontalAlignment, Layout, Point, Rectangle, Renderer, Size,
     VerticalAlignment,
 };
 
+#[cfg(feature = "markdown")]
+use crate::markdown;
+
 /// A renderer that does nothing.
 ///
 /// It can be useful if you are writing tests!
@@ -63,10 +67,22 @@ impl text::Renderer for Null {
         _size: u16,
         _font: Font,
         _bounds: Size,
+        _overflow: text::Overflow,
+        _spacing: text::Spacing,
+        _direction: Direction,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
 
+    fn baseline(
+        &self,
+        _size: u16,
+        _font: Font,
+        _spacing: text::Spacing,
+    ) -> f32 {
+        0.0
+    }
+
     fn draw(
         &mut self,
         _defaults: &Self::Defaults,
@@ -77,6 +93,81 @@ impl text::Renderer for Null {
         _color: Option<Color>,
         _horizontal_alignment: HorizontalAlignment,
         _vertical_alignment: VerticalAlignment,
+        _overflow: text::Overflow,
+        _spacing: text::Spacing,
+        _direction: Direction,
+    ) {
+    }
+}
+
+impl rich_text::Renderer for Null {
+    fn measure_spans<Message>(
+        &self,
+        _spans: &[rich_text::Span<Message, Font>],
+        _spacing: text::Spacing,
+        _direction: Direction,
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn span_at<Message>(
+        &self,
+        _spans: &[rich_text::Span<Message, Font>],
+        _spacing: text::Spacing,
+        _direction: Direction,
+        _bounds: Rectangle,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _point: Point,
+    ) -> Option<usize> {
+        None
+    }
+
+    fn draw_spans<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _spans: &[rich_text::Span<Message, Font>],
+        _spacing: text::Spacing,
+        _direction: Direction,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+    ) {
+    }
+}
+
+impl image::Renderer for Null {
+    fn dimensions(&self, _handle: &image::Handle) -> (u32, u32) {
+        (1, 1)
+    }
+
+    fn draw(&mut self, _handle: image::Handle, _layout: Layout<'_>) {}
+}
+
+impl blur::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _radius: f32,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl opacity::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _opacity: f32,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
     ) {
     }
 }
@@ -111,6 +202,63 @@ impl scrollable::Renderer for Null {
     }
 }
 
+impl selectable_text::Renderer for Null {
+    type Style = ();
+
+    fn character_bounds(
+        &self,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _direction: Direction,
+        _bounds: Size,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+    ) -> Vec<(usize, Rectangle)> {
+        Vec::new()
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _color: Option<Color>,
+        _direction: Direction,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+        _selection: Option<(usize, usize)>,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl code_view::Renderer for Null {
+    type Style = ();
+
+    fn default_font(&self) -> Font {
+        Font::Default
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _gutter_width: f32,
+        _lines: &[code_view::Line<'_>],
+        _size: u16,
+        _font: Font,
+        _selection: &[Rectangle],
+        _caret: Option<Rectangle>,
+        _is_focused: bool,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl text_input::Renderer for Null {
     type Style = ();
 
@@ -239,6 +387,7 @@ impl container::Renderer for Null {
         _cursor_position: Point,
         _viewport: &Rectangle,
         _style: &Self::Style,
+        _clip: bool,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
     ) {
@@ -285,3 +434,25 @@ impl pane_grid::Renderer for Null {
     ) {
     }
 }
+
+#[cfg(feature = "markdown")]
+impl markdown::Renderer for Null {
+    type Style = ();
+
+    fn appearance(_style: &()) -> markdown::Appearance<Font> {
+        markdown::Appearance::default()
+    }
+
+    fn decorate<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _decoration: markdown::Decoration,
+        _appearance: &markdown::Appearance<Font>,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
@@ -23,6 +23,7 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    layout_cache: layout::Cache,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -85,7 +86,7 @@ where
     pub fn build<E: Into<Element<'a, Message, Renderer>>>(
         root: E,
         bounds: Size,
-        cache: Cache,
+        mut cache: Cache,
         renderer: &mut Renderer,
     ) -> Self {
         let root = root.into();
@@ -104,13 +105,12 @@ where
             let (layout, overlay) = if layout_is_cached {
                 (cache.base.layout, cache.overlay)
             } else {
-                (
-                    renderer.layout(
-                        &root,
-                        &layout::Limits::new(Size::ZERO, bounds),
-                    ),
-                    None,
-                )
+                let layout = cache.layout.scoped(|| {
+                    renderer
+                        .layout(&root, &layout::Limits::new(Size::ZERO, bounds))
+                });
+
+                (layout, None)
             };
 
             (Layer { layout, hash }, overlay)
@@ -121,6 +121,7 @@ where
             base,
             overlay,
             bounds,
+            layout_cache: cache.layout,
         }
     }
 
@@ -391,6 +392,16 @@ where
         }
     }
 
+    /// Returns the bounds of the text caret of the focused widget of the
+    /// [`UserInterface`], if any.
+    ///
+    /// This is useful to position the candidate window of an input method
+    /// editor (IME) next to the text being composed.
+    pub fn caret_bounds(&self, renderer: &Renderer) -> Option<Rectangle> {
+        self.root
+            .caret_bounds(Layout::new(&self.base.layout), renderer)
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
@@ -401,6 +412,7 @@ where
                 base: self.base,
                 overlay: self.overlay,
                 bounds: self.bounds,
+                layout: self.layout_cache,
             },
             renderer,
         )
@@ -413,6 +425,7 @@ where
             base: self.base,
             overlay: self.overlay,
             bounds: self.bounds,
+            layout: self.layout_cache,
         }
     }
 
@@ -453,6 +466,7 @@ pub struct Cache {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    layout: layout::Cache,
 }
 
 impl Cache {
@@ -468,6 +482,7 @@ impl Cache {
             },
             overlay: None,
             bounds: Size::ZERO,
+            layout: layout::Cache::default(),
         }
     }
 }
@@ -19,35 +19,50 @@
 //! ```
 //!
 //! [renderer]: crate::renderer
+pub mod blur;
 pub mod button;
 pub mod checkbox;
+pub mod code_view;
 pub mod column;
+pub mod constrained;
 pub mod container;
 pub mod image;
+pub mod opacity;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod responsive;
+pub mod rich_text;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod space;
 pub mod svg;
 pub mod text;
 pub mod text_input;
 
+#[doc(no_inline)]
+pub use blur::Blur;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use code_view::CodeView;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
+pub use constrained::Constrained;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -56,12 +71,18 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
@@ -72,6 +93,13 @@ pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
@@ -175,4 +203,19 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Returns the bounds of the text caret of the [`Widget`], if it is
+    /// focused and accepts text input.
+    ///
+    /// Runtimes use it to position the candidate window of an input method
+    /// editor (IME) next to the caret.
+    ///
+    /// By default, it returns `None`.
+    fn caret_bounds(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        None
+    }
 }
@@ -0,0 +1,174 @@
+//! Blur whatever is drawn beneath some content.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+/// An element blurring whatever is drawn beneath its bounds, before drawing
+/// its content on top.
+///
+/// It is useful to build frosted-glass headers or modal backdrops. The
+/// content usually has a translucent background, so the blur shows through.
+///
+/// Some renderers may not be able to blur. In that case, only the content is
+/// drawn.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::Text;
+/// # type Blur<'a> = iced_native::Blur<'a, (), iced_native::renderer::Null>;
+/// # type Container<'a> =
+/// #     iced_native::Container<'a, (), iced_native::renderer::Null>;
+/// #
+/// let header = Blur::new(Container::new(Text::new("Frosted")), 10.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Blur<'a, Message, Renderer> {
+    radius: f32,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Blur<'a, Message, Renderer> {
+    /// Creates a [`Blur`] with the given content and radius, in logical
+    /// pixels.
+    ///
+    /// Like with the `blur` function of CSS, the radius is the standard
+    /// deviation of the Gaussian.
+    pub fn new<T>(content: T, radius: f32) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Blur {
+            radius: radius.max(0.0),
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Blur<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            cursor_position,
+            viewport,
+            self.radius,
+            &self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.content
+            .caret_bounds(layout.children().next().unwrap(), renderer)
+    }
+}
+
+/// The renderer of a [`Blur`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Blur`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Blur`].
+    ///
+    /// It receives:
+    ///   * the cursor position
+    ///   * the visible region of the content
+    ///   * the radius of the blur, in logical pixels
+    ///   * the content of the [`Blur`]
+    ///   * the [`Layout`] of the content
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        radius: f32,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Blur<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        blur: Blur<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(blur)
+    }
+}
@@ -196,6 +196,9 @@ where
             None,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
+            text::Overflow::default(),
+            text::Spacing::default(),
+            label_layout.direction(),
         );
 
         let is_mouse_over = bounds.contains(cursor_position);
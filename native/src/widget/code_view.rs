@@ -0,0 +1,1089 @@
+//! Display and edit code with line numbers and syntax highlighting.
+//!
+//! A [`CodeView`] has some local [`State`].
+pub mod highlighter;
+
+mod document;
+
+pub use highlighter::{Highlight, Highlighter};
+
+use document::{Document, Position};
+
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::text;
+use crate::touch;
+use crate::{
+    Clipboard, Direction, Element, Hasher, Layout, Length, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::cell::RefCell;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A view of some code, with line numbers and syntax highlighting.
+///
+/// A [`CodeView`] is read-only unless an `on_change` function is provided.
+/// It takes all the height its lines need, and only the lines inside of the
+/// viewport are highlighted and drawn. Therefore, a [`CodeView`] inside of a
+/// [`Scrollable`] can display large files efficiently.
+///
+/// The characters of the code are laid out in a grid, so the font of a
+/// [`CodeView`] should be monospaced.
+///
+/// # Example
+/// ```
+/// # use iced_native::code_view::{self, highlighter};
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type CodeView<'a, Message> = iced_native::CodeView<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CodeChanged(String),
+/// }
+///
+/// let mut state = code_view::State::with_highlighter(
+///     highlighter::Syntax::new(highlighter::Language::Rust),
+/// );
+/// let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+///
+/// let code_view = CodeView::new(&mut state, code)
+///     .on_change(Message::CodeChanged);
+/// ```
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct CodeView<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    on_change: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    font: Option<Renderer::Font>,
+    size: Option<u16>,
+    width: Length,
+    padding: u16,
+    line_numbers: bool,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> CodeView<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`CodeView`] with the given [`State`] and code.
+    pub fn new(state: &'a mut State, code: &str) -> Self {
+        state.update(code);
+
+        CodeView {
+            state,
+            on_change: None,
+            font: None,
+            size: None,
+            width: Length::Fill,
+            padding: 5,
+            line_numbers: true,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Makes the [`CodeView`] editable.
+    ///
+    /// The given function is called with the new code every time it is
+    /// edited.
+    pub fn on_change<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the font of the [`CodeView`].
+    ///
+    /// By default, the monospaced font of the renderer is used.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the text size of the [`CodeView`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the [`CodeView`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the [`CodeView`].
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets whether the [`CodeView`] shows line numbers or not.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the style of the [`CodeView`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let (width, height) = renderer.measure(
+            "0",
+            size,
+            font,
+            Size::INFINITY,
+            text::Overflow {
+                wrap: false,
+                ..text::Overflow::default()
+            },
+            text::Spacing::default(),
+            Direction::LeftToRight,
+        );
+
+        let padding = f32::from(self.padding);
+
+        let gutter = if self.line_numbers {
+            let digits = self.state.document.line_count().to_string().len();
+
+            digits.max(2) as f32 * width + padding * 2.0
+        } else {
+            0.0
+        };
+
+        Metrics {
+            size,
+            font,
+            cell: Size::new(width, height),
+            gutter,
+            padding,
+        }
+    }
+
+    fn position_at(
+        &self,
+        metrics: &Metrics<Renderer::Font>,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Position {
+        let document = &self.state.document;
+        let origin = metrics.origin(bounds);
+
+        let line = ((point.y - origin.y) / metrics.cell.height).max(0.0);
+        let line = (line as usize).min(document.line_count() - 1);
+
+        let column =
+            document.column_at(line, (point.x - origin.x) / metrics.cell.width);
+
+        Position::new(line, column)
+    }
+
+    fn edit(&mut self, text: &str, messages: &mut Vec<Message>) {
+        if let Some(on_change) = &self.on_change {
+            self.state.insert(text);
+
+            messages.push(on_change(self.state.document.content().to_owned()));
+        }
+    }
+
+    fn remove(
+        &mut self,
+        next: impl Fn(&Document, Position) -> Position,
+        messages: &mut Vec<Message>,
+    ) {
+        if self.state.selection().is_none() {
+            let position = next(&self.state.document, self.state.cursor);
+
+            self.state.move_to(position, true);
+        }
+
+        self.edit("", messages);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CodeView<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = self.metrics(renderer);
+        let lines = self.state.document.line_count() as f32;
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let size = limits.resolve(Size::new(
+            metrics.gutter + metrics.padding * 2.0,
+            lines * metrics.cell.height + metrics.padding * 2.0,
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let is_editable = self.on_change.is_some();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                self.state.is_focused = is_clicked;
+
+                if is_clicked {
+                    let metrics = self.metrics(renderer);
+                    let position =
+                        self.position_at(&metrics, bounds, cursor_position);
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    match click.kind() {
+                        click::Kind::Single => {
+                            let is_extending =
+                                self.state.keyboard_modifiers.shift;
+
+                            self.state.move_to(position, is_extending);
+                            self.state.is_dragging = true;
+                        }
+                        click::Kind::Double => {
+                            let (start, end) =
+                                self.state.document.word(position);
+
+                            self.state.select(start, end);
+                            self.state.is_dragging = false;
+                        }
+                        click::Kind::Triple => {
+                            let start = Position::new(position.line, 0);
+                            let end = self.state.document.next(Position::new(
+                                position.line,
+                                self.state.document.line(position.line).len(),
+                            ));
+
+                            self.state.select(start, end);
+                            self.state.is_dragging = false;
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.state.is_dragging =>
+            {
+                let metrics = self.metrics(renderer);
+                let position = self.position_at(&metrics, bounds, position);
+
+                self.state.move_to(position, true);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ImeCommit(text))
+                if self.state.is_focused && is_editable =>
+            {
+                let text: String =
+                    text.chars().filter(|c| !c.is_control()).collect();
+
+                self.edit(&text, messages);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && is_editable
+                    && !self.state.keyboard_modifiers.is_command_pressed()
+                    && !c.is_control() =>
+            {
+                self.edit(c.encode_utf8(&mut [0; 4]), messages);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => {
+                let document = &self.state.document;
+                let cursor = self.state.cursor;
+                let is_selecting = modifiers.shift;
+
+                match key_code {
+                    keyboard::KeyCode::Left => {
+                        let position = match self.state.selection() {
+                            Some((start, _)) if !is_selecting => start,
+                            _ => document.previous(cursor),
+                        };
+
+                        self.state.move_to(position, is_selecting);
+                    }
+                    keyboard::KeyCode::Right => {
+                        let position = match self.state.selection() {
+                            Some((_, end)) if !is_selecting => end,
+                            _ => document.next(cursor),
+                        };
+
+                        self.state.move_to(position, is_selecting);
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        let column = self
+                            .state
+                            .preferred_column
+                            .unwrap_or_else(|| document.visual_column(cursor));
+
+                        let position = match key_code {
+                            keyboard::KeyCode::Up if cursor.line > 0 => {
+                                let line = cursor.line - 1;
+
+                                Position::new(
+                                    line,
+                                    document.column_at(line, column as f32),
+                                )
+                            }
+                            keyboard::KeyCode::Up => Position::default(),
+                            _ if cursor.line + 1 < document.line_count() => {
+                                let line = cursor.line + 1;
+
+                                Position::new(
+                                    line,
+                                    document.column_at(line, column as f32),
+                                )
+                            }
+                            _ => document.end(),
+                        };
+
+                        self.state.move_to(position, is_selecting);
+                        self.state.preferred_column = Some(column);
+                    }
+                    keyboard::KeyCode::Home => {
+                        let position = if modifiers.is_command_pressed() {
+                            Position::default()
+                        } else {
+                            Position::new(cursor.line, 0)
+                        };
+
+                        self.state.move_to(position, is_selecting);
+                    }
+                    keyboard::KeyCode::End => {
+                        let position = if modifiers.is_command_pressed() {
+                            document.end()
+                        } else {
+                            Position::new(
+                                cursor.line,
+                                document.line(cursor.line).len(),
+                            )
+                        };
+
+                        self.state.move_to(position, is_selecting);
+                    }
+                    keyboard::KeyCode::A if modifiers.is_command_pressed() => {
+                        let end = document.end();
+
+                        self.state.select(Position::default(), end);
+                    }
+                    keyboard::KeyCode::C | keyboard::KeyCode::X
+                        if modifiers.is_command_pressed() =>
+                    {
+                        if let Some((start, end)) = self.state.selection() {
+                            if let Some(clipboard) = clipboard {
+                                clipboard.write(
+                                    document.text(start, end).to_owned(),
+                                );
+                            }
+
+                            if key_code == keyboard::KeyCode::X {
+                                self.edit("", messages);
+                            }
+                        }
+                    }
+                    keyboard::KeyCode::V if modifiers.is_command_pressed() => {
+                        if let Some(content) =
+                            clipboard.and_then(|clipboard| clipboard.content())
+                        {
+                            let content: String = content
+                                .replace("\r\n", "\n")
+                                .chars()
+                                .filter(|c| {
+                                    !c.is_control() || *c == '\n' || *c == '\t'
+                                })
+                                .collect();
+
+                            self.edit(&content, messages);
+                        }
+                    }
+                    keyboard::KeyCode::Enter => {
+                        let indentation =
+                            format!("\n{}", document.indentation(cursor.line));
+
+                        self.edit(&indentation, messages);
+                    }
+                    keyboard::KeyCode::Tab => {
+                        self.edit("    ", messages);
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        self.remove(Document::previous, messages);
+                    }
+                    keyboard::KeyCode::Delete => {
+                        self.remove(Document::next, messages);
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.unfocus();
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let metrics = self.metrics(renderer);
+        let origin = metrics.origin(bounds);
+        let document = &self.state.document;
+
+        let visible_lines =
+            metrics.visible_lines(bounds, viewport, document.line_count());
+
+        let mut highlighting = self.state.highlighting.borrow_mut();
+        highlighting.highlight_until(document, visible_lines.end);
+
+        let lines: Vec<_> = visible_lines
+            .clone()
+            .map(|line| {
+                let highlights = &highlighting.lines[line];
+                let content = document.line(line);
+                let y = metrics.line_y(bounds, line);
+
+                let boundaries = highlights
+                    .iter()
+                    .flat_map(|(range, _)| vec![range.start, range.end]);
+
+                let fragments = document
+                    .fragments(line, boundaries)
+                    .into_iter()
+                    .map(|(range, column)| Fragment {
+                        content: &content[range.clone()],
+                        position: Point::new(
+                            origin.x + column as f32 * metrics.cell.width,
+                            y,
+                        ),
+                        highlight: highlights
+                            .iter()
+                            .find(|(highlight, _)| {
+                                highlight.start <= range.start
+                                    && range.start < highlight.end
+                            })
+                            .map(|(_, highlight)| *highlight),
+                    })
+                    .collect();
+
+                Line {
+                    number: line + 1,
+                    number_bounds: Rectangle {
+                        x: bounds.x + metrics.padding,
+                        y,
+                        width: (metrics.gutter - metrics.padding * 2.0)
+                            .max(0.0),
+                        height: metrics.cell.height,
+                    },
+                    fragments,
+                }
+            })
+            .collect();
+
+        let selection: Vec<_> = match self.state.selection() {
+            Some((start, end)) => visible_lines
+                .filter(|line| *line >= start.line && *line <= end.line)
+                .map(|line| {
+                    let from = if line == start.line {
+                        document.visual_column(start)
+                    } else {
+                        0
+                    };
+
+                    let to = if line == end.line {
+                        document.visual_column(end)
+                    } else {
+                        let line_end = document.line(line).len();
+
+                        document.visual_column(Position::new(line, line_end))
+                            + 1
+                    };
+
+                    Rectangle {
+                        x: origin.x + from as f32 * metrics.cell.width,
+                        y: metrics.line_y(bounds, line),
+                        width: to.saturating_sub(from) as f32
+                            * metrics.cell.width,
+                        height: metrics.cell.height,
+                    }
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let caret = if self.state.is_focused && self.on_change.is_some() {
+            Some(metrics.caret(bounds, document, self.state.cursor))
+        } else {
+            None
+        };
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            cursor_position,
+            metrics.gutter,
+            &lines,
+            metrics.size,
+            metrics.font,
+            &selection,
+            caret,
+            self.state.is_focused,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.state.document.line_count().hash(state);
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+        self.font.hash(state);
+        self.line_numbers.hash(state);
+    }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        if !self.state.is_focused || self.on_change.is_none() {
+            return None;
+        }
+
+        let metrics = self.metrics(renderer);
+
+        Some(metrics.caret(
+            layout.bounds(),
+            &self.state.document,
+            self.state.cursor,
+        ))
+    }
+}
+
+struct Metrics<Font> {
+    size: u16,
+    font: Font,
+    cell: Size,
+    gutter: f32,
+    padding: f32,
+}
+
+impl<Font> Metrics<Font> {
+    fn origin(&self, bounds: Rectangle) -> Point {
+        Point::new(
+            bounds.x + self.gutter + self.padding,
+            bounds.y + self.padding,
+        )
+    }
+
+    fn line_y(&self, bounds: Rectangle, line: usize) -> f32 {
+        self.origin(bounds).y + line as f32 * self.cell.height
+    }
+
+    /// Returns the range of lines that are inside of the given viewport.
+    fn visible_lines(
+        &self,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        line_count: usize,
+    ) -> Range<usize> {
+        let origin = self.origin(bounds);
+
+        let top = (viewport.y - origin.y).max(0.0);
+        let bottom = viewport.y + viewport.height - origin.y;
+
+        let first = (top / self.cell.height) as usize;
+        let last = (bottom / self.cell.height).ceil().max(0.0) as usize;
+
+        first.min(line_count)..last.min(line_count)
+    }
+
+    fn caret(
+        &self,
+        bounds: Rectangle,
+        document: &Document,
+        position: Position,
+    ) -> Rectangle {
+        let column = document.visual_column(position);
+
+        Rectangle {
+            x: self.origin(bounds).x + column as f32 * self.cell.width,
+            y: self.line_y(bounds, position.line),
+            width: 1.0,
+            height: self.cell.height,
+        }
+    }
+}
+
+/// The local state of a [`CodeView`].
+pub struct State {
+    document: Document,
+    cursor: Position,
+    anchor: Option<Position>,
+    preferred_column: Option<usize>,
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
+    highlighting: RefCell<Highlighting>,
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`CodeView`] without syntax
+    /// highlighting.
+    pub fn new() -> Self {
+        Self::with_highlighter(highlighter::Syntax::new(
+            highlighter::Language::PlainText,
+        ))
+    }
+
+    /// Creates a new [`State`] for a [`CodeView`] that uses the given
+    /// [`Highlighter`].
+    pub fn with_highlighter(highlighter: impl Highlighter + 'static) -> Self {
+        State {
+            document: Document::new(""),
+            cursor: Position::default(),
+            anchor: None,
+            preferred_column: None,
+            is_focused: false,
+            is_dragging: false,
+            last_click: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            highlighting: RefCell::new(Highlighting {
+                highlighter: Box::new(highlighter),
+                lines: Vec::new(),
+            }),
+        }
+    }
+
+    /// Replaces the [`Highlighter`] of the [`CodeView`].
+    pub fn set_highlighter(&mut self, highlighter: impl Highlighter + 'static) {
+        *self.highlighting.get_mut() = Highlighting {
+            highlighter: Box::new(highlighter),
+            lines: Vec::new(),
+        };
+    }
+
+    /// Returns whether the [`CodeView`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`CodeView`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`CodeView`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_dragging = false;
+    }
+
+    /// Returns the line and the column of the cursor of the [`CodeView`],
+    /// both starting at 0.
+    ///
+    /// The column is a byte offset in the line.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.cursor.line, self.cursor.column)
+    }
+
+    fn update(&mut self, code: &str) {
+        let current = self.document.content();
+
+        if current == code {
+            return;
+        }
+
+        let changed = current
+            .bytes()
+            .zip(code.bytes())
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| current.len().min(code.len()));
+
+        let line = code.as_bytes()[..changed]
+            .iter()
+            .filter(|byte| **byte == b'\n')
+            .count();
+
+        self.document = Document::new(code);
+        self.cursor = self.document.clamp(self.cursor);
+        self.anchor = self.anchor.map(|anchor| self.document.clamp(anchor));
+        self.invalidate(line);
+    }
+
+    fn selection(&self) -> Option<(Position, Position)> {
+        match self.anchor {
+            Some(anchor) if anchor < self.cursor => Some((anchor, self.cursor)),
+            Some(anchor) if anchor > self.cursor => Some((self.cursor, anchor)),
+            _ => None,
+        }
+    }
+
+    fn move_to(&mut self, position: Position, is_selecting: bool) {
+        if is_selecting {
+            self.anchor = self.anchor.or(Some(self.cursor));
+        } else {
+            self.anchor = None;
+        }
+
+        self.cursor = position;
+        self.preferred_column = None;
+    }
+
+    fn select(&mut self, start: Position, end: Position) {
+        self.anchor = Some(start);
+        self.cursor = end;
+        self.preferred_column = None;
+    }
+
+    fn insert(&mut self, text: &str) {
+        let (start, end) =
+            self.selection().unwrap_or((self.cursor, self.cursor));
+
+        self.cursor = self.document.replace(start, end, text);
+        self.anchor = None;
+        self.preferred_column = None;
+        self.invalidate(start.line);
+    }
+
+    fn invalidate(&mut self, line: usize) {
+        let highlighting = self.highlighting.get_mut();
+
+        highlighting.lines.truncate(line);
+        highlighting.highlighter.change_line(line);
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("cursor", &self.cursor)
+            .field("anchor", &self.anchor)
+            .field("is_focused", &self.is_focused)
+            .field("is_dragging", &self.is_dragging)
+            .finish()
+    }
+}
+
+struct Highlighting {
+    highlighter: Box<dyn Highlighter>,
+    lines: Vec<Vec<(Range<usize>, Highlight)>>,
+}
+
+impl Highlighting {
+    fn highlight_until(&mut self, document: &Document, end: usize) {
+        if self.lines.len() >= end {
+            return;
+        }
+
+        if self.highlighter.current_line() != self.lines.len() {
+            self.highlighter.change_line(self.lines.len());
+        }
+
+        while self.lines.len() < end {
+            let line = document.line(self.lines.len());
+
+            self.lines.push(self.highlighter.highlight_line(line));
+        }
+    }
+}
+
+/// A visible line of a [`CodeView`].
+#[derive(Debug, Clone)]
+pub struct Line<'a> {
+    /// The number of the line, starting at 1.
+    pub number: usize,
+
+    /// The bounds of the number of the line, inside of the gutter.
+    pub number_bounds: Rectangle,
+
+    /// The [`Fragment`]s of the line.
+    pub fragments: Vec<Fragment<'a>>,
+}
+
+/// A piece of a [`Line`] with a single [`Highlight`].
+#[derive(Debug, Clone, Copy)]
+pub struct Fragment<'a> {
+    /// The text of the [`Fragment`].
+    pub content: &'a str,
+
+    /// The position of the top-left corner of the [`Fragment`].
+    pub position: Point,
+
+    /// The [`Highlight`] of the [`Fragment`], if it is not plain text.
+    pub highlight: Option<Highlight>,
+}
+
+/// The renderer of a [`CodeView`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`CodeView`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the default font of a [`CodeView`], which should be
+    /// monospaced.
+    fn default_font(&self) -> Self::Font;
+
+    /// Draws a [`CodeView`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`CodeView`]
+    ///   * the cursor position
+    ///   * the width of the gutter with the line numbers
+    ///   * the visible [`Line`]s of the [`CodeView`]
+    ///   * the size and the font of the code
+    ///   * the bounds of the selection in each visible line
+    ///   * the bounds of the caret, if it is visible
+    ///   * whether the [`CodeView`] is focused or not
+    ///   * the style of the [`CodeView`]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        gutter_width: f32,
+        lines: &[Line<'_>],
+        size: u16,
+        font: Self::Font,
+        selection: &[Rectangle],
+        caret: Option<Rectangle>,
+        is_focused: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<CodeView<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        code_view: CodeView<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(code_view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::rc::Rc;
+
+    /// A [`Highlighter`] that records the lines it is moved back to.
+    struct Recorder {
+        changes: Rc<RefCell<Vec<usize>>>,
+        line: usize,
+    }
+
+    impl Highlighter for Recorder {
+        fn change_line(&mut self, line: usize) {
+            self.changes.borrow_mut().push(line);
+            self.line = line;
+        }
+
+        fn highlight_line(
+            &mut self,
+            _line: &str,
+        ) -> Vec<(Range<usize>, Highlight)> {
+            self.line += 1;
+
+            Vec::new()
+        }
+
+        fn current_line(&self) -> usize {
+            self.line
+        }
+    }
+
+    fn highlighted(code: &str) -> (State, Rc<RefCell<Vec<usize>>>) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+
+        let mut state = State::with_highlighter(Recorder {
+            changes: changes.clone(),
+            line: 0,
+        });
+
+        state.update(code);
+
+        state
+            .highlighting
+            .get_mut()
+            .highlight_until(&state.document, 3);
+
+        changes.borrow_mut().clear();
+
+        (state, changes)
+    }
+
+    #[test]
+    fn update_invalidates_from_the_first_changed_line() {
+        let (mut state, changes) = highlighted("one\ntwo\nthree");
+
+        state.update("one\ntwo\nthree!");
+
+        assert_eq!(*changes.borrow(), vec![2]);
+        assert_eq!(state.highlighting.get_mut().lines.len(), 2);
+    }
+
+    #[test]
+    fn update_keeps_the_highlighting_of_the_same_code() {
+        let (mut state, changes) = highlighted("one\ntwo\nthree");
+
+        state.update("one\ntwo\nthree");
+
+        assert!(changes.borrow().is_empty());
+        assert_eq!(state.highlighting.get_mut().lines.len(), 3);
+    }
+
+    #[test]
+    fn update_clamps_the_cursor() {
+        let (mut state, _) = highlighted("one\ntwo\nthree");
+
+        state.cursor = Position::new(2, 5);
+        state.anchor = Some(Position::new(1, 3));
+
+        state.update("one\ntw");
+
+        assert_eq!(state.cursor(), (1, 2));
+        assert_eq!(state.anchor, Some(Position::new(1, 2)));
+    }
+
+    #[test]
+    fn insert_invalidates_from_the_start_of_the_selection() {
+        let (mut state, changes) = highlighted("one\ntwo\nthree");
+
+        state.select(Position::new(1, 1), Position::new(2, 2));
+        state.insert("x");
+
+        assert_eq!(state.document.content(), "one\ntxree");
+        assert_eq!(state.cursor(), (1, 2));
+        assert_eq!(*changes.borrow(), vec![1]);
+        assert_eq!(state.highlighting.get_mut().lines.len(), 1);
+    }
+
+    #[test]
+    fn visible_lines_are_inside_of_the_viewport() {
+        let metrics = Metrics {
+            size: 10,
+            font: (),
+            cell: Size::new(5.0, 10.0),
+            gutter: 20.0,
+            padding: 5.0,
+        };
+
+        let bounds = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 1000.0,
+        };
+
+        let viewport = |y, height| Rectangle {
+            x: 0.0,
+            y,
+            width: 100.0,
+            height,
+        };
+
+        assert_eq!(
+            metrics.visible_lines(bounds, &viewport(100.0, 50.0), 100),
+            9..15
+        );
+        assert_eq!(
+            metrics.visible_lines(bounds, &viewport(100.0, 50.0), 12),
+            9..12
+        );
+        assert_eq!(
+            metrics.visible_lines(bounds, &viewport(0.0, 20.0), 100),
+            0..2
+        );
+        assert_eq!(
+            metrics.visible_lines(bounds, &viewport(-100.0, 50.0), 100),
+            0..0
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The amount of columns a tab advances to.
+const TAB_WIDTH: usize = 4;
+
+/// A position in a [`Document`], given by a line and a byte offset in it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Position { line, column }
+    }
+}
+
+/// Some text split in lines.
+#[derive(Debug, Clone)]
+pub struct Document {
+    content: String,
+    line_starts: Vec<usize>,
+}
+
+impl Document {
+    pub fn new(content: &str) -> Self {
+        let mut document = Document {
+            content: String::from(content),
+            line_starts: Vec::new(),
+        };
+
+        document.split_lines();
+        document
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    pub fn line(&self, line: usize) -> &str {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map(|next| next - 1)
+            .unwrap_or_else(|| self.content.len());
+
+        self.content[start..end].trim_end_matches('\r')
+    }
+
+    pub fn end(&self) -> Position {
+        let line = self.line_count() - 1;
+
+        Position::new(line, self.line(line).len())
+    }
+
+    pub fn offset(&self, position: Position) -> usize {
+        self.line_starts[position.line] + position.column
+    }
+
+    pub fn clamp(&self, position: Position) -> Position {
+        let line = position.line.min(self.line_count() - 1);
+        let content = self.line(line);
+        let mut column = position.column.min(content.len());
+
+        while !content.is_char_boundary(column) {
+            column -= 1;
+        }
+
+        Position::new(line, column)
+    }
+
+    pub fn previous(&self, position: Position) -> Position {
+        if position.column > 0 {
+            let content = &self.line(position.line)[..position.column];
+
+            let column = content
+                .grapheme_indices(true)
+                .next_back()
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+
+            Position::new(position.line, column)
+        } else if position.line > 0 {
+            let line = position.line - 1;
+
+            Position::new(line, self.line(line).len())
+        } else {
+            position
+        }
+    }
+
+    pub fn next(&self, position: Position) -> Position {
+        let content = self.line(position.line);
+
+        if position.column < content.len() {
+            let column = content[position.column..]
+                .graphemes(true)
+                .next()
+                .map(|grapheme| position.column + grapheme.len())
+                .unwrap_or_else(|| content.len());
+
+            Position::new(position.line, column)
+        } else if position.line + 1 < self.line_count() {
+            Position::new(position.line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    /// Returns the start and the end of the word around the given position.
+    pub fn word(&self, position: Position) -> (Position, Position) {
+        let content = self.line(position.line);
+
+        content
+            .split_word_bound_indices()
+            .find(|(start, word)| {
+                position.column >= *start
+                    && position.column < start + word.len()
+            })
+            .map(|(start, word)| {
+                (
+                    Position::new(position.line, start),
+                    Position::new(position.line, start + word.len()),
+                )
+            })
+            .unwrap_or((position, position))
+    }
+
+    /// Returns the visual column of the given position, once tabs are
+    /// expanded.
+    pub fn visual_column(&self, position: Position) -> usize {
+        self.line(position.line)[..position.column]
+            .graphemes(true)
+            .fold(0, advance)
+    }
+
+    /// Returns the byte offset in the given line that is closest to the
+    /// given visual column.
+    pub fn column_at(&self, line: usize, visual_column: f32) -> usize {
+        let mut current = 0;
+
+        for (i, grapheme) in self.line(line).grapheme_indices(true) {
+            let next = advance(current, grapheme);
+
+            if visual_column < (current + next) as f32 / 2.0 {
+                return i;
+            }
+
+            current = next;
+        }
+
+        self.line(line).len()
+    }
+
+    /// Splits the given line in fragments of text, computing the visual
+    /// column of each one. Tabs are left out.
+    pub fn fragments(
+        &self,
+        line: usize,
+        boundaries: impl IntoIterator<Item = usize>,
+    ) -> Vec<(Range<usize>, usize)> {
+        let content = self.line(line);
+        let mut boundaries = boundaries.into_iter().peekable();
+        let mut fragments = Vec::new();
+
+        let mut start = 0;
+        let mut start_column = 0;
+        let mut column = 0;
+
+        for (i, grapheme) in content.grapheme_indices(true) {
+            while matches!(boundaries.peek(), Some(boundary) if *boundary <= i)
+            {
+                let _ = boundaries.next();
+
+                if start < i {
+                    fragments.push((start..i, start_column));
+
+                    start = i;
+                    start_column = column;
+                }
+            }
+
+            column = advance(column, grapheme);
+
+            if grapheme == "\t" {
+                if start < i {
+                    fragments.push((start..i, start_column));
+                }
+
+                start = i + 1;
+                start_column = column;
+            }
+        }
+
+        if start < content.len() {
+            fragments.push((start..content.len(), start_column));
+        }
+
+        fragments
+    }
+
+    /// Returns the leading whitespace of the given line.
+    pub fn indentation(&self, line: usize) -> &str {
+        let content = self.line(line);
+        let end = content
+            .find(|c: char| c != ' ' && c != '\t')
+            .unwrap_or(content.len());
+
+        &content[..end]
+    }
+
+    pub fn text(&self, start: Position, end: Position) -> &str {
+        &self.content[self.offset(start)..self.offset(end)]
+    }
+
+    /// Replaces the text between the given positions and returns the position
+    /// at the end of the new text.
+    pub fn replace(
+        &mut self,
+        start: Position,
+        end: Position,
+        text: &str,
+    ) -> Position {
+        let start = self.offset(start);
+        let end = self.offset(end);
+
+        self.content.replace_range(start..end, text);
+        self.split_lines();
+
+        self.position(start + text.len())
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+
+        Position::new(line, offset - self.line_starts[line])
+    }
+
+    fn split_lines(&mut self) {
+        self.line_starts = std::iter::once(0)
+            .chain(self.content.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+    }
+}
+
+fn advance(column: usize, grapheme: &str) -> usize {
+    if grapheme == "\t" {
+        (column / TAB_WIDTH + 1) * TAB_WIDTH
+    } else {
+        column + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_text_across_lines() {
+        let mut document = Document::new("hello\nworld");
+
+        let end = document.replace(
+            Position::new(0, 1),
+            Position::new(1, 2),
+            "ey\nthere\nwo",
+        );
+
+        assert_eq!(document.content(), "hey\nthere\nworld");
+        assert_eq!(document.line_count(), 3);
+        assert_eq!(document.line(1), "there");
+        assert_eq!(end, Position::new(2, 2));
+    }
+
+    #[test]
+    fn replaces_a_newline() {
+        let mut document = Document::new("a\nb\n");
+
+        let end =
+            document.replace(Position::new(0, 1), Position::new(1, 0), "");
+
+        assert_eq!(document.content(), "ab\n");
+        assert_eq!(document.line_count(), 2);
+        assert_eq!(end, Position::new(0, 1));
+    }
+
+    #[test]
+    fn finds_the_position_of_an_offset() {
+        let document = Document::new("ab\ncd\n");
+
+        assert_eq!(document.position(0), Position::new(0, 0));
+        assert_eq!(document.position(2), Position::new(0, 2));
+        assert_eq!(document.position(3), Position::new(1, 0));
+        assert_eq!(document.position(4), Position::new(1, 1));
+        assert_eq!(document.position(6), Position::new(2, 0));
+    }
+
+    #[test]
+    fn splits_fragments_at_tabs() {
+        let document = Document::new("\tfoo\tbar\nab\tc");
+
+        assert_eq!(document.fragments(0, None), vec![(1..4, 4), (5..8, 8)]);
+        assert_eq!(document.fragments(1, None), vec![(0..2, 0), (3..4, 4)]);
+    }
+
+    #[test]
+    fn splits_fragments_at_boundaries() {
+        let document = Document::new("foo bar");
+
+        assert_eq!(
+            document.fragments(0, vec![3, 4]),
+            vec![(0..3, 0), (3..4, 3), (4..7, 4)]
+        );
+    }
+
+    #[test]
+    fn finds_the_column_closest_to_a_visual_column() {
+        let document = Document::new("\tab");
+
+        assert_eq!(document.column_at(0, 1.9), 0);
+        assert_eq!(document.column_at(0, 2.1), 1);
+        assert_eq!(document.column_at(0, 4.6), 2);
+        assert_eq!(document.column_at(0, 10.0), 3);
+    }
+
+    #[test]
+    fn finds_the_word_around_a_position() {
+        let document = Document::new("let foo = 1;");
+
+        assert_eq!(
+            document.word(Position::new(0, 5)),
+            (Position::new(0, 4), Position::new(0, 7))
+        );
+        assert_eq!(
+            document.word(Position::new(0, 3)),
+            (Position::new(0, 3), Position::new(0, 4))
+        );
+        assert_eq!(
+            document.word(Position::new(0, 12)),
+            (Position::new(0, 12), Position::new(0, 12))
+        );
+    }
+}
@@ -0,0 +1,908 @@
+//! Highlight the syntax of code, line by line.
+use std::ops::Range;
+
+/// The kind of a highlighted fragment of code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Highlight {
+    /// A comment.
+    Comment,
+
+    /// A keyword of the language.
+    Keyword,
+
+    /// A literal value, like `true` or `null`.
+    Literal,
+
+    /// A number.
+    Number,
+
+    /// A string or character literal.
+    String,
+
+    /// The name of a type.
+    Type,
+
+    /// The name of a function being called or defined.
+    Function,
+}
+
+/// A syntax highlighter that processes code line by line.
+///
+/// A [`Highlighter`] keeps its own state from one line to the next, so it
+/// can deal with constructs that span multiple lines, like block comments.
+pub trait Highlighter {
+    /// Moves the [`Highlighter`] back to the start of the given line.
+    ///
+    /// It is called when the code changes. The state of the lines before the
+    /// given one is still valid.
+    fn change_line(&mut self, line: usize);
+
+    /// Highlights the current line and moves on to the next one.
+    ///
+    /// The returned ranges are byte ranges of the line. The text outside of
+    /// them is plain.
+    fn highlight_line(&mut self, line: &str) -> Vec<(Range<usize>, Highlight)>;
+
+    /// Returns the index of the line that will be highlighted next.
+    fn current_line(&self) -> usize;
+}
+
+/// A [`Highlighter`] for common languages, based on their keywords, comments
+/// and literals.
+#[derive(Debug, Clone)]
+pub struct Syntax {
+    language: Language,
+    modes: Vec<Mode>,
+}
+
+impl Syntax {
+    /// Creates a new [`Syntax`] highlighter for the given [`Language`].
+    pub fn new(language: Language) -> Self {
+        Syntax {
+            language,
+            modes: vec![Mode::Code],
+        }
+    }
+
+    /// Returns the [`Language`] of the [`Syntax`] highlighter.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+}
+
+impl Highlighter for Syntax {
+    fn change_line(&mut self, line: usize) {
+        self.modes.truncate(line + 1);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Vec<(Range<usize>, Highlight)> {
+        let mode = self.modes.last().copied().unwrap_or(Mode::Code);
+        let (highlights, mode) =
+            self.language.definition().highlight(line, mode);
+
+        self.modes.push(mode);
+
+        highlights
+    }
+
+    fn current_line(&self) -> usize {
+        self.modes.len() - 1
+    }
+}
+
+/// A language supported by the [`Syntax`] highlighter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Text without any highlighting.
+    PlainText,
+
+    /// Rust
+    Rust,
+
+    /// C
+    C,
+
+    /// C++
+    Cpp,
+
+    /// Go
+    Go,
+
+    /// Java
+    Java,
+
+    /// JavaScript
+    JavaScript,
+
+    /// TypeScript
+    TypeScript,
+
+    /// Python
+    Python,
+
+    /// Ruby
+    Ruby,
+
+    /// Lua
+    Lua,
+
+    /// Shell scripts
+    Shell,
+
+    /// SQL
+    Sql,
+
+    /// TOML
+    Toml,
+
+    /// YAML
+    Yaml,
+
+    /// JSON
+    Json,
+}
+
+impl Language {
+    /// Returns the [`Language`] of the files with the given extension, if
+    /// it is supported.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let language = match extension.to_ascii_lowercase().as_str() {
+            "txt" => Language::PlainText,
+            "rs" => Language::Rust,
+            "c" | "h" => Language::C,
+            "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Language::Cpp,
+            "go" => Language::Go,
+            "java" => Language::Java,
+            "js" | "mjs" | "cjs" | "jsx" => Language::JavaScript,
+            "ts" | "tsx" => Language::TypeScript,
+            "py" | "pyw" => Language::Python,
+            "rb" => Language::Ruby,
+            "lua" => Language::Lua,
+            "sh" | "bash" | "zsh" => Language::Shell,
+            "sql" => Language::Sql,
+            "toml" => Language::Toml,
+            "yml" | "yaml" => Language::Yaml,
+            "json" => Language::Json,
+            _ => return None,
+        };
+
+        Some(language)
+    }
+
+    fn definition(self) -> &'static Definition {
+        match self {
+            Language::PlainText => &PLAIN_TEXT,
+            Language::Rust => &RUST,
+            Language::C => &C,
+            Language::Cpp => &CPP,
+            Language::Go => &GO,
+            Language::Java => &JAVA,
+            Language::JavaScript => &JAVASCRIPT,
+            Language::TypeScript => &TYPESCRIPT,
+            Language::Python => &PYTHON,
+            Language::Ruby => &RUBY,
+            Language::Lua => &LUA,
+            Language::Shell => &SHELL,
+            Language::Sql => &SQL,
+            Language::Toml => &TOML,
+            Language::Yaml => &YAML,
+            Language::Json => &JSON,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Code,
+    Comment,
+    String(&'static str),
+}
+
+struct Definition {
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    literals: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    strings: &'static [&'static str],
+    multiline_strings: &'static [&'static str],
+    capitalized_types: bool,
+    case_insensitive: bool,
+}
+
+impl Definition {
+    fn highlight(
+        &self,
+        line: &str,
+        mode: Mode,
+    ) -> (Vec<(Range<usize>, Highlight)>, Mode) {
+        let mut highlights = Vec::new();
+        let mut i = 0;
+
+        // Finish the construct left open by the previous line, if any
+        match mode {
+            Mode::Code => {}
+            Mode::Comment => {
+                let end = self.block_comment.map(|(_, end)| end).unwrap_or("");
+
+                match line.find(end) {
+                    Some(j) if !end.is_empty() => {
+                        i = j + end.len();
+                        highlights.push((0..i, Highlight::Comment));
+                    }
+                    _ => {
+                        highlights.push((0..line.len(), Highlight::Comment));
+
+                        return (highlights, mode);
+                    }
+                }
+            }
+            Mode::String(delimiter) => match closing(line, 0, delimiter) {
+                Some(j) => {
+                    i = j;
+                    highlights.push((0..i, Highlight::String));
+                }
+                None => {
+                    highlights.push((0..line.len(), Highlight::String));
+
+                    return (highlights, mode);
+                }
+            },
+        }
+
+        while let Some(c) = line[i..].chars().next() {
+            let rest = &line[i..];
+
+            if c.is_whitespace() {
+                i += c.len_utf8();
+                continue;
+            }
+
+            if self
+                .line_comments
+                .iter()
+                .any(|start| rest.starts_with(start))
+            {
+                highlights.push((i..line.len(), Highlight::Comment));
+                break;
+            }
+
+            if let Some((start, end)) = self.block_comment {
+                if let Some(comment) = rest.strip_prefix(start) {
+                    match comment.find(end) {
+                        Some(j) => {
+                            let end = i + start.len() + j + end.len();
+
+                            highlights.push((i..end, Highlight::Comment));
+                            i = end;
+                            continue;
+                        }
+                        None => {
+                            highlights
+                                .push((i..line.len(), Highlight::Comment));
+
+                            return (highlights, Mode::Comment);
+                        }
+                    }
+                }
+            }
+
+            if let Some(delimiter) = self
+                .multiline_strings
+                .iter()
+                .find(|delimiter| rest.starts_with(*delimiter))
+            {
+                match closing(line, i + delimiter.len(), delimiter) {
+                    Some(end) => {
+                        highlights.push((i..end, Highlight::String));
+                        i = end;
+                        continue;
+                    }
+                    None => {
+                        highlights.push((i..line.len(), Highlight::String));
+
+                        return (highlights, Mode::String(delimiter));
+                    }
+                }
+            }
+
+            if let Some(delimiter) = self
+                .strings
+                .iter()
+                .find(|delimiter| rest.starts_with(*delimiter))
+            {
+                let end = closing(line, i + delimiter.len(), delimiter)
+                    .unwrap_or(line.len());
+
+                highlights.push((i..end, Highlight::String));
+                i = end;
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                let end = i + number_length(rest);
+
+                highlights.push((i..end, Highlight::Number));
+                i = end;
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' {
+                let end = i + rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+
+                if let Some(highlight) =
+                    self.classify(&line[i..end], line[end..].trim_start())
+                {
+                    highlights.push((i..end, highlight));
+                }
+
+                i = end;
+                continue;
+            }
+
+            i += c.len_utf8();
+        }
+
+        (highlights, Mode::Code)
+    }
+
+    fn classify(&self, word: &str, after: &str) -> Option<Highlight> {
+        let is = |words: &[&str]| {
+            if self.case_insensitive {
+                words.iter().any(|w| w.eq_ignore_ascii_case(word))
+            } else {
+                words.contains(&word)
+            }
+        };
+
+        if is(self.keywords) {
+            Some(Highlight::Keyword)
+        } else if is(self.literals) {
+            Some(Highlight::Literal)
+        } else if is(self.types)
+            || (self.capitalized_types
+                && word.starts_with(|c: char| c.is_uppercase()))
+        {
+            Some(Highlight::Type)
+        } else if after.starts_with('(') && !self.keywords.is_empty() {
+            Some(Highlight::Function)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the byte index right after the closing delimiter of a string that
+/// starts before the given index, skipping escaped characters.
+fn closing(line: &str, from: usize, delimiter: &str) -> Option<usize> {
+    let mut chars = line[from..].char_indices();
+
+    while let Some((j, c)) = chars.next() {
+        if c == '\\' {
+            let _ = chars.next();
+        } else if line[from + j..].starts_with(delimiter) {
+            return Some(from + j + delimiter.len());
+        }
+    }
+
+    None
+}
+
+fn number_length(text: &str) -> usize {
+    let mut chars = text.char_indices().peekable();
+    let mut length = 0;
+
+    while let Some((j, c)) = chars.next() {
+        let is_decimal_point = c == '.'
+            && chars
+                .peek()
+                .map(|(_, next)| next.is_ascii_digit())
+                .unwrap_or(false);
+
+        if c.is_alphanumeric() || c == '_' || is_decimal_point {
+            length = j + c.len_utf8();
+        } else {
+            break;
+        }
+    }
+
+    length
+}
+
+const C_STYLE: Definition = Definition {
+    keywords: &[],
+    types: &[],
+    literals: &[],
+    line_comments: &["//"],
+    block_comment: Some(("/*", "*/")),
+    strings: &["\"", "'"],
+    multiline_strings: &[],
+    capitalized_types: false,
+    case_insensitive: false,
+};
+
+const PLAIN_TEXT: Definition = Definition {
+    line_comments: &[],
+    block_comment: None,
+    strings: &[],
+    ..C_STYLE
+};
+
+const RUST: Definition = Definition {
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn",
+        "else", "enum", "extern", "fn", "for", "if", "impl", "in", "let",
+        "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+        "Self", "static", "struct", "super", "trait", "type", "unsafe", "use",
+        "where", "while",
+    ],
+    types: &[
+        "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize",
+        "i8", "i16", "i32", "i64", "i128", "isize", "f32", "f64",
+    ],
+    literals: &["true", "false"],
+    strings: &[],
+    multiline_strings: &["\""],
+    capitalized_types: true,
+    ..C_STYLE
+};
+
+const C: Definition = Definition {
+    keywords: &[
+        "auto", "break", "case", "const", "continue", "default", "do", "else",
+        "enum", "extern", "for", "goto", "if", "inline", "register",
+        "restrict", "return", "sizeof", "static", "struct", "switch",
+        "typedef", "union", "volatile", "while",
+    ],
+    types: &[
+        "char", "double", "float", "int", "long", "short", "signed",
+        "unsigned", "void", "size_t", "bool",
+    ],
+    literals: &["NULL", "true", "false"],
+    ..C_STYLE
+};
+
+const CPP: Definition = Definition {
+    keywords: &[
+        "auto",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "constexpr",
+        "continue",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "explicit",
+        "extern",
+        "for",
+        "friend",
+        "goto",
+        "if",
+        "inline",
+        "namespace",
+        "new",
+        "noexcept",
+        "operator",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "sizeof",
+        "static",
+        "struct",
+        "switch",
+        "template",
+        "this",
+        "throw",
+        "try",
+        "typedef",
+        "typename",
+        "union",
+        "using",
+        "virtual",
+        "volatile",
+        "while",
+    ],
+    types: &[
+        "bool", "char", "double", "float", "int", "long", "short", "signed",
+        "unsigned", "void", "size_t",
+    ],
+    literals: &["nullptr", "NULL", "true", "false"],
+    ..C_STYLE
+};
+
+const GO: Definition = Definition {
+    keywords: &[
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "fallthrough",
+        "for",
+        "func",
+        "go",
+        "goto",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "type",
+        "var",
+    ],
+    types: &[
+        "bool", "byte", "error", "float32", "float64", "int", "int8", "int16",
+        "int32", "int64", "rune", "string", "uint", "uint8", "uint16",
+        "uint32", "uint64", "uintptr",
+    ],
+    literals: &["nil", "true", "false", "iota"],
+    multiline_strings: &["`"],
+    ..C_STYLE
+};
+
+const JAVA: Definition = Definition {
+    keywords: &[
+        "abstract",
+        "assert",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "default",
+        "do",
+        "else",
+        "enum",
+        "extends",
+        "final",
+        "finally",
+        "for",
+        "if",
+        "implements",
+        "import",
+        "instanceof",
+        "interface",
+        "native",
+        "new",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "synchronized",
+        "this",
+        "throw",
+        "throws",
+        "try",
+        "var",
+        "volatile",
+        "while",
+    ],
+    types: &[
+        "boolean", "byte", "char", "double", "float", "int", "long", "short",
+        "void",
+    ],
+    literals: &["null", "true", "false"],
+    capitalized_types: true,
+    ..C_STYLE
+};
+
+const JAVASCRIPT: Definition = Definition {
+    keywords: &[
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "export",
+        "extends",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "let",
+        "new",
+        "of",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    literals: &["null", "undefined", "true", "false", "NaN", "Infinity"],
+    multiline_strings: &["`"],
+    capitalized_types: true,
+    ..C_STYLE
+};
+
+const TYPESCRIPT: Definition = Definition {
+    keywords: &[
+        "abstract",
+        "as",
+        "async",
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "declare",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "finally",
+        "for",
+        "from",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "keyof",
+        "let",
+        "namespace",
+        "new",
+        "of",
+        "private",
+        "protected",
+        "public",
+        "readonly",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "try",
+        "type",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "yield",
+    ],
+    types: &[
+        "any", "boolean", "never", "number", "object", "string", "symbol",
+        "unknown",
+    ],
+    ..JAVASCRIPT
+};
+
+const PYTHON: Definition = Definition {
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue",
+        "def", "del", "elif", "else", "except", "finally", "for", "from",
+        "global", "if", "import", "in", "is", "lambda", "nonlocal", "not",
+        "or", "pass", "raise", "return", "try", "while", "with", "yield",
+    ],
+    types: &[
+        "bool", "bytes", "dict", "float", "int", "list", "set", "str", "tuple",
+    ],
+    literals: &["None", "True", "False"],
+    line_comments: &["#"],
+    block_comment: None,
+    multiline_strings: &["\"\"\"", "'''"],
+    capitalized_types: true,
+    ..C_STYLE
+};
+
+const RUBY: Definition = Definition {
+    keywords: &[
+        "alias", "and", "begin", "break", "case", "class", "def", "defined",
+        "do", "else", "elsif", "end", "ensure", "for", "if", "in", "module",
+        "next", "not", "or", "redo", "rescue", "retry", "return", "self",
+        "super", "then", "undef", "unless", "until", "when", "while", "yield",
+        "require",
+    ],
+    literals: &["nil", "true", "false"],
+    line_comments: &["#"],
+    block_comment: Some(("=begin", "=end")),
+    capitalized_types: true,
+    ..C_STYLE
+};
+
+const LUA: Definition = Definition {
+    keywords: &[
+        "and", "break", "do", "else", "elseif", "end", "for", "function",
+        "goto", "if", "in", "local", "not", "or", "repeat", "return", "then",
+        "until", "while",
+    ],
+    literals: &["nil", "true", "false"],
+    line_comments: &["--"],
+    block_comment: None,
+    ..C_STYLE
+};
+
+const SHELL: Definition = Definition {
+    keywords: &[
+        "case", "do", "done", "elif", "else", "esac", "export", "fi", "for",
+        "function", "if", "in", "local", "return", "select", "then", "until",
+        "while", "echo", "exit", "source",
+    ],
+    literals: &["true", "false"],
+    line_comments: &["#"],
+    block_comment: None,
+    ..C_STYLE
+};
+
+const SQL: Definition = Definition {
+    keywords: &[
+        "add",
+        "alter",
+        "and",
+        "as",
+        "asc",
+        "begin",
+        "between",
+        "by",
+        "case",
+        "commit",
+        "create",
+        "delete",
+        "desc",
+        "distinct",
+        "drop",
+        "else",
+        "end",
+        "exists",
+        "from",
+        "group",
+        "having",
+        "in",
+        "index",
+        "inner",
+        "insert",
+        "into",
+        "is",
+        "join",
+        "key",
+        "left",
+        "like",
+        "limit",
+        "not",
+        "on",
+        "or",
+        "order",
+        "outer",
+        "primary",
+        "references",
+        "right",
+        "rollback",
+        "select",
+        "set",
+        "table",
+        "then",
+        "union",
+        "update",
+        "values",
+        "view",
+        "when",
+        "where",
+    ],
+    types: &[
+        "bigint",
+        "blob",
+        "boolean",
+        "char",
+        "date",
+        "decimal",
+        "float",
+        "int",
+        "integer",
+        "numeric",
+        "real",
+        "text",
+        "timestamp",
+        "varchar",
+    ],
+    literals: &["null", "true", "false"],
+    line_comments: &["--"],
+    strings: &["'", "\""],
+    case_insensitive: true,
+    ..C_STYLE
+};
+
+const TOML: Definition = Definition {
+    literals: &["true", "false"],
+    line_comments: &["#"],
+    block_comment: None,
+    multiline_strings: &["\"\"\"", "'''"],
+    ..C_STYLE
+};
+
+const YAML: Definition = Definition {
+    literals: &["true", "false", "null", "yes", "no", "on", "off"],
+    line_comments: &["#"],
+    block_comment: None,
+    ..C_STYLE
+};
+
+const JSON: Definition = Definition {
+    literals: &["true", "false", "null"],
+    line_comments: &[],
+    block_comment: None,
+    strings: &["\""],
+    ..C_STYLE
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_block_comments_across_lines() {
+        let mut syntax = Syntax::new(Language::Rust);
+
+        assert_eq!(
+            syntax.highlight_line("let x = 42; /* start"),
+            vec![
+                (0..3, Highlight::Keyword),
+                (8..10, Highlight::Number),
+                (12..20, Highlight::Comment),
+            ]
+        );
+
+        assert_eq!(
+            syntax.highlight_line("end */ foo(\"bar\")"),
+            vec![
+                (0..6, Highlight::Comment),
+                (7..10, Highlight::Function),
+                (11..16, Highlight::String),
+            ]
+        );
+
+        syntax.change_line(1);
+
+        assert_eq!(syntax.current_line(), 1);
+        assert_eq!(
+            syntax.highlight_line("end */"),
+            vec![(0..6, Highlight::Comment)]
+        );
+    }
+}
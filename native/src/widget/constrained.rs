@@ -0,0 +1,302 @@
+//! Constrain the size of some content.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+/// An element constraining the size of its content.
+///
+/// It can be used to apply minimum and maximum dimensions to any element,
+/// or to keep it at a specific aspect ratio.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{Length, Text};
+/// # type Constrained<'a> =
+/// #     iced_native::Constrained<'a, (), iced_native::renderer::Null>;
+/// #
+/// // A 16:9 preview that fills the available width
+/// let preview = Constrained::new(Text::new("Preview").width(Length::Fill))
+///     .aspect_ratio(16.0 / 9.0)
+///     .min_width(160);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Constrained<'a, Message, Renderer> {
+    min_width: u32,
+    max_width: u32,
+    min_height: u32,
+    max_height: u32,
+    aspect_ratio: Option<f32>,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Constrained<'a, Message, Renderer> {
+    /// Creates a [`Constrained`] element with the given content and no
+    /// constraints.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Constrained {
+            min_width: 0,
+            max_width: u32::MAX,
+            min_height: 0,
+            max_height: u32::MAX,
+            aspect_ratio: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the minimum width of the [`Constrained`] content.
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the maximum width of the [`Constrained`] content.
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Constrained`] content.
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the maximum height of the [`Constrained`] content.
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Constrained`] content, as the result of
+    /// dividing its width by its height.
+    ///
+    /// The content will take the biggest size with the given aspect ratio
+    /// that fits in the available space.
+    ///
+    /// It will panic if the aspect ratio is not a positive, finite number.
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        assert!(
+            aspect_ratio > 0.0 && aspect_ratio.is_finite(),
+            "Aspect ratio must be positive and finite"
+        );
+
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Constrained<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // The minimum size of the parent is kept, so it wins over the
+        // maximum size of the content like in any other `Limits`
+        let limits = limits
+            .min_width(self.min_width)
+            .max_width(self.max_width)
+            .min_height(self.min_height)
+            .max_height(self.max_height);
+
+        let content = match self.aspect_ratio {
+            Some(aspect_ratio) => {
+                let max = limits.max();
+
+                let available =
+                    if max.width.is_infinite() && max.height.is_infinite() {
+                        self.content.layout(renderer, &limits).size()
+                    } else {
+                        max
+                    };
+
+                let size =
+                    fit(available, limits.min(), limits.max(), aspect_ratio);
+
+                self.content.layout(
+                    renderer,
+                    &layout::Limits::new(size, size)
+                        .with_direction(limits.direction()),
+                )
+            }
+            None => self.content.layout(renderer, &limits),
+        };
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.min_width.hash(state);
+        self.max_width.hash(state);
+        self.min_height.hash(state);
+        self.max_height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.content
+            .caret_bounds(layout.children().next().unwrap(), renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Constrained<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        constrained: Constrained<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(constrained)
+    }
+}
+
+/// Returns the biggest [`Size`] with the given aspect ratio that fits in the
+/// available space, while respecting the minimum size.
+///
+/// The result never exceeds the maximum size, even if the aspect ratio cannot
+/// be kept then.
+fn fit(available: Size, min: Size, max: Size, aspect_ratio: f32) -> Size {
+    let mut size = if available.width.is_infinite() {
+        Size::new(available.height * aspect_ratio, available.height)
+    } else if available.height.is_infinite()
+        || available.width / available.height < aspect_ratio
+    {
+        Size::new(available.width, available.width / aspect_ratio)
+    } else {
+        Size::new(available.height * aspect_ratio, available.height)
+    };
+
+    if size.width < min.width {
+        size = Size::new(min.width, min.width / aspect_ratio);
+    }
+
+    if size.height < min.height {
+        size = Size::new(min.height * aspect_ratio, min.height);
+    }
+
+    Size::new(size.width.min(max.width), size.height.min(max.height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_fills_the_narrowest_side() {
+        assert_eq!(
+            fit(Size::new(200.0, 200.0), Size::ZERO, Size::INFINITY, 2.0),
+            Size::new(200.0, 100.0)
+        );
+        assert_eq!(
+            fit(Size::new(200.0, 50.0), Size::ZERO, Size::INFINITY, 2.0),
+            Size::new(100.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn fit_grows_to_the_minimum() {
+        assert_eq!(
+            fit(
+                Size::new(100.0, 100.0),
+                Size::new(160.0, 0.0),
+                Size::INFINITY,
+                2.0
+            ),
+            Size::new(160.0, 80.0)
+        );
+    }
+
+    #[test]
+    fn fit_never_exceeds_the_maximum() {
+        let max = Size::new(200.0, 60.0);
+
+        assert_eq!(
+            fit(Size::new(200.0, 60.0), Size::new(160.0, 0.0), max, 2.0),
+            Size::new(160.0, 60.0)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn aspect_ratio_must_be_positive() {
+        let _ = Constrained::<(), crate::renderer::Null>::new(
+            crate::Text::new("Preview"),
+        )
+        .aspect_ratio(0.0);
+    }
+}
@@ -5,7 +5,8 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
 use crate::{
-    Align, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+    Align, Clipboard, Direction, Element, Hasher, Layout, Length, Point,
+    Rectangle, Widget,
 };
 
 use std::u32;
@@ -22,6 +23,8 @@ pub struct Container<'a, Message, Renderer: self::Renderer> {
     max_height: u32,
     horizontal_alignment: Align,
     vertical_alignment: Align,
+    direction: Option<Direction>,
+    clip: bool,
     style: Renderer::Style,
     content: Element<'a, Message, Renderer>,
 }
@@ -43,6 +46,8 @@ where
             max_height: u32::MAX,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
+            direction: None,
+            clip: false,
             style: Renderer::Style::default(),
             content: content.into(),
         }
@@ -102,6 +107,23 @@ where
         self
     }
 
+    /// Sets the [`Direction`] of the contents of the [`Container`].
+    ///
+    /// By default, a [`Container`] inherits the [`Direction`] of its parent.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets whether the contents of the [`Container`] should be clipped to
+    /// its bounds.
+    ///
+    /// The clipping region follows the rounded corners of the border, if any.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -128,8 +150,10 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let padding = f32::from(self.padding);
+        let direction = self.direction.unwrap_or_else(|| limits.direction());
 
         let limits = limits
+            .with_direction(direction)
             .loose()
             .max_width(self.max_width)
             .max_height(self.max_height)
@@ -141,7 +165,11 @@ where
         let size = limits.resolve(content.size());
 
         content.move_to(Point::new(padding, padding));
-        content.align(self.horizontal_alignment, self.vertical_alignment, size);
+        content.align(
+            direction.align(self.horizontal_alignment),
+            self.vertical_alignment,
+            size,
+        );
 
         layout::Node::with_children(size.pad(padding), vec![content])
     }
@@ -179,6 +207,7 @@ where
             cursor_position,
             viewport,
             &self.style,
+            self.clip,
             &self.content,
             layout.children().next().unwrap(),
         )
@@ -193,6 +222,7 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.direction.hash(state);
 
         self.content.hash_layout(state);
     }
@@ -203,6 +233,15 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.content
+            .caret_bounds(layout.children().next().unwrap(), renderer)
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -223,6 +262,7 @@ pub trait Renderer: crate::Renderer {
         cursor_position: Point,
         viewport: &Rectangle,
         style: &Self::Style,
+        clip: bool,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output;
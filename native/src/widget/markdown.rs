@@ -0,0 +1,642 @@
+//! Display documents written in Markdown.
+mod block;
+mod parser;
+
+pub use block::{Block, Inline};
+pub use parser::parse;
+
+use crate::column;
+use crate::event::{self, Event};
+use crate::image;
+use crate::layout;
+use crate::rich_text::{self, Span};
+use crate::row;
+use crate::{
+    Background, BorderRadius, Clipboard, Color, Column, Element, Hasher, Image,
+    Layout, Length, Point, Rectangle, RichText, Row, Size, Widget,
+};
+
+use std::cell::{Ref, RefCell};
+use std::hash::Hash;
+
+/// A document written in [CommonMark], displayed as a column of blocks.
+///
+/// Paragraphs and headings are laid out as [`RichText`], code blocks and
+/// block quotes are decorated by the renderer, and images are loaded from
+/// their URL as an [`image::Handle`].
+///
+/// # Example
+///
+/// ```
+/// # type Markdown<'a> =
+/// #     iced_native::Markdown<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let markdown = Markdown::new("# Hello\n\nRead the [book](https://book.iced.rs)!")
+///     .on_link(Message::LinkClicked);
+/// ```
+///
+/// [CommonMark]: https://commonmark.org
+#[allow(missing_debug_implementations)]
+pub struct Markdown<'a, Message, Renderer: self::Renderer> {
+    blocks: Vec<Block>,
+    on_link: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    width: Length,
+    spacing: u16,
+    style: Renderer::Style,
+    content: RefCell<Option<Element<'a, Message, Renderer>>>,
+}
+
+impl<'a, Message, Renderer> Markdown<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`Markdown`] document by parsing the given CommonMark
+    /// source.
+    pub fn new(source: &str) -> Self {
+        Self::with_blocks(parse(source))
+    }
+
+    /// Creates a new [`Markdown`] document with the given [`Block`]s.
+    pub fn with_blocks(blocks: Vec<Block>) -> Self {
+        Markdown {
+            blocks,
+            on_link: None,
+            width: Length::Fill,
+            spacing: 10,
+            style: Renderer::Style::default(),
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Sets the message that should be produced when a link of the
+    /// [`Markdown`] document is clicked.
+    ///
+    /// The function receives the destination of the link.
+    pub fn on_link<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_link = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Markdown`] document.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the blocks of the [`Markdown`]
+    /// document.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the style of the [`Markdown`] document.
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn content(&self) -> Ref<'_, Element<'a, Message, Renderer>> {
+        if self.content.borrow().is_none() {
+            *self.content.borrow_mut() = Some(self.build());
+        }
+
+        Ref::map(self.content.borrow(), |content| {
+            content.as_ref().expect("Build markdown content")
+        })
+    }
+
+    fn content_mut(&mut self) -> &mut Element<'a, Message, Renderer> {
+        if self.content.get_mut().is_none() {
+            *self.content.get_mut() = Some(self.build());
+        }
+
+        self.content
+            .get_mut()
+            .as_mut()
+            .expect("Build markdown content")
+    }
+
+    fn build(&self) -> Element<'a, Message, Renderer> {
+        let builder = Builder {
+            appearance: Renderer::appearance(&self.style),
+            spacing: self.spacing,
+            on_link: self.on_link.as_deref(),
+        };
+
+        builder.blocks(&self.blocks).width(self.width).into()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Markdown<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content_mut().on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content().draw(
+            renderer,
+            defaults,
+            layout,
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content().hash_layout(state);
+    }
+}
+
+struct Builder<'b, Message, Font> {
+    appearance: Appearance<Font>,
+    spacing: u16,
+    on_link: Option<&'b dyn Fn(String) -> Message>,
+}
+
+impl<'b, Message, Font> Builder<'b, Message, Font>
+where
+    Font: Default + Copy,
+{
+    fn blocks<'a, Renderer>(
+        &self,
+        blocks: &[Block],
+    ) -> Column<'a, Message, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a + self::Renderer<Font = Font>,
+    {
+        blocks.iter().fold(
+            Column::new().spacing(self.spacing).width(Length::Fill),
+            |column, block| column.push(self.block(block)),
+        )
+    }
+
+    fn block<'a, Renderer>(
+        &self,
+        block: &Block,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a + self::Renderer<Font = Font>,
+    {
+        let appearance = &self.appearance;
+
+        match block {
+            Block::Heading { level, content } => {
+                let size = appearance.heading_sizes
+                    [usize::from((*level).max(1) - 1).min(5)];
+
+                self.paragraph(content, size, appearance.heading_font)
+                    .into()
+            }
+            Block::Paragraph(content) => self
+                .paragraph(content, appearance.text_size, appearance.font)
+                .into(),
+            Block::CodeBlock { code, .. } => {
+                let mut span = Span::new(code.clone())
+                    .size(appearance.code_size)
+                    .font(appearance.code_font);
+
+                span.color = appearance.code_color.or(appearance.text_color);
+
+                Decorated::new(
+                    Decoration::CodeBlock,
+                    *appearance,
+                    0.0,
+                    f32::from(self.spacing),
+                    RichText::with_spans(vec![span]).width(Length::Fill),
+                )
+                .into()
+            }
+            Block::List { start, items } => items
+                .iter()
+                .zip(0..)
+                .fold(
+                    Column::new().spacing(self.spacing / 2),
+                    |list, (item, i)| {
+                        let marker = match start {
+                            Some(start) => format!("{}.", start + i),
+                            None => String::from("•"),
+                        };
+
+                        let mut marker =
+                            Span::new(marker).size(appearance.text_size);
+
+                        marker.font = appearance.font;
+                        marker.color = appearance.text_color;
+
+                        list.push(
+                            Row::new()
+                                .width(Length::Fill)
+                                .spacing(self.spacing / 2)
+                                .push(RichText::with_spans(vec![marker]))
+                                .push(self.blocks(item)),
+                        )
+                    },
+                )
+                .into(),
+            Block::Quote(blocks) => {
+                let builder = Builder {
+                    appearance: Appearance {
+                        text_color: appearance
+                            .quote_color
+                            .or(appearance.text_color),
+                        ..*appearance
+                    },
+                    spacing: self.spacing,
+                    on_link: self.on_link,
+                };
+
+                Decorated::new(
+                    Decoration::Quote,
+                    *appearance,
+                    appearance.quote_border_width + f32::from(self.spacing),
+                    0.0,
+                    builder.blocks(blocks),
+                )
+                .into()
+            }
+            Block::Image { url, .. } => {
+                Image::new(image::Handle::from_path(url)).into()
+            }
+        }
+    }
+
+    fn paragraph<Renderer>(
+        &self,
+        content: &[Inline],
+        size: u16,
+        font: Font,
+    ) -> RichText<Message, Renderer>
+    where
+        Message: Clone,
+        Renderer: self::Renderer<Font = Font>,
+    {
+        let appearance = &self.appearance;
+
+        let spans = content
+            .iter()
+            .map(|inline| {
+                let mut span = Span::new(inline.content.clone()).size(size);
+
+                span.font = if inline.code {
+                    appearance.code_font
+                } else if inline.strong {
+                    appearance.strong_font
+                } else if inline.emphasis {
+                    appearance.emphasis_font
+                } else {
+                    font
+                };
+
+                span.color = if inline.code {
+                    appearance.code_color.or(appearance.text_color)
+                } else {
+                    appearance.text_color
+                };
+
+                if inline.strikethrough {
+                    span = span.strikethrough();
+                }
+
+                if let Some(destination) = &inline.link {
+                    span = span.color(appearance.link_color).underline();
+
+                    if let Some(on_link) = self.on_link {
+                        span = span.link(on_link(destination.clone()));
+                    }
+                }
+
+                span
+            })
+            .collect();
+
+        RichText::with_spans(spans).width(Length::Fill)
+    }
+}
+
+/// A decoration drawn by the renderer around a [`Block`] of a [`Markdown`]
+/// document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decoration {
+    /// The background of a code block.
+    CodeBlock,
+
+    /// The border on the side of a block quote.
+    Quote,
+}
+
+struct Decorated<'a, Message, Renderer: self::Renderer> {
+    decoration: Decoration,
+    appearance: Appearance<Renderer::Font>,
+    indent: f32,
+    padding: f32,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Decorated<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn new(
+        decoration: Decoration,
+        appearance: Appearance<Renderer::Font>,
+        indent: f32,
+        padding: f32,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Decorated {
+            decoration,
+            appearance,
+            indent,
+            padding,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Decorated<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+
+        let horizontal = self.indent + self.padding * 2.0;
+        let vertical = self.padding * 2.0;
+
+        let mut content = self.content.layout(
+            renderer,
+            &limits.loose().shrink(Size::new(horizontal, vertical)),
+        );
+
+        content.move_to(Point::new(self.indent + self.padding, self.padding));
+
+        let size = limits.resolve(Size::new(
+            content.size().width + horizontal,
+            content.size().height + vertical,
+        ));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.decorate(
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            viewport,
+            self.decoration,
+            &self.appearance,
+            &self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.indent.to_bits().hash(state);
+        self.padding.to_bits().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<Decorated<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        decorated: Decorated<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(decorated)
+    }
+}
+
+/// The appearance of a [`Markdown`] document.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance<Font> {
+    /// The size of the text of paragraphs and lists.
+    pub text_size: u16,
+
+    /// The sizes of the headings, from level 1 to level 6.
+    pub heading_sizes: [u16; 6],
+
+    /// The color of the text, if different from the default one.
+    pub text_color: Option<Color>,
+
+    /// The font of the text.
+    pub font: Font,
+
+    /// The font of the headings.
+    pub heading_font: Font,
+
+    /// The font of strongly emphasized text.
+    pub strong_font: Font,
+
+    /// The font of emphasized text.
+    pub emphasis_font: Font,
+
+    /// The font of code spans and code blocks.
+    pub code_font: Font,
+
+    /// The size of the text of code blocks.
+    pub code_size: u16,
+
+    /// The color of code spans and code blocks, if different from the color
+    /// of the text.
+    pub code_color: Option<Color>,
+
+    /// The [`Background`] of code blocks.
+    pub code_background: Background,
+
+    /// The radius of each corner of code blocks.
+    pub code_border_radius: BorderRadius,
+
+    /// The color of links.
+    pub link_color: Color,
+
+    /// The color of the text of block quotes, if different from the color of
+    /// the text.
+    pub quote_color: Option<Color>,
+
+    /// The color of the border of block quotes.
+    pub quote_border_color: Color,
+
+    /// The width of the border of block quotes.
+    pub quote_border_width: f32,
+}
+
+impl<Font: Default> Default for Appearance<Font> {
+    fn default() -> Self {
+        Appearance {
+            text_size: 20,
+            heading_sizes: [32, 28, 24, 22, 20, 20],
+            text_color: None,
+            font: Font::default(),
+            heading_font: Font::default(),
+            strong_font: Font::default(),
+            emphasis_font: Font::default(),
+            code_font: Font::default(),
+            code_size: 18,
+            code_color: None,
+            code_background: Background::Color(Color::from_rgb(
+                0.95, 0.95, 0.95,
+            )),
+            code_border_radius: 4.0.into(),
+            link_color: Color::from_rgb(0.2, 0.4, 0.8),
+            quote_color: None,
+            quote_border_color: Color::from_rgb(0.8, 0.8, 0.8),
+            quote_border_width: 4.0,
+        }
+    }
+}
+
+/// The renderer of a [`Markdown`] document.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Markdown`] document in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer:
+    rich_text::Renderer + column::Renderer + row::Renderer + image::Renderer
+{
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a [`Markdown`] document with the given
+    /// style.
+    fn appearance(style: &Self::Style) -> Appearance<Self::Font>;
+
+    /// Draws the content of a [`Block`] with the given [`Decoration`].
+    ///
+    /// It receives:
+    ///   * the bounds of the decorated [`Block`]
+    ///   * the cursor position
+    ///   * the [`Decoration`] to draw
+    ///   * the [`Appearance`] of the [`Markdown`] document
+    ///   * the content of the [`Block`] and its [`Layout`]
+    fn decorate<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        decoration: Decoration,
+        appearance: &Appearance<Self::Font>,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Markdown<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        markdown: Markdown<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(markdown)
+    }
+}
@@ -0,0 +1,67 @@
+/// A block of a Markdown document.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum Block {
+    /// A heading.
+    Heading {
+        /// The level of the heading, from 1 to 6.
+        level: u8,
+
+        /// The contents of the heading.
+        content: Vec<Inline>,
+    },
+
+    /// A paragraph of text.
+    Paragraph(Vec<Inline>),
+
+    /// A block of code.
+    CodeBlock {
+        /// The language of the code, if it was specified.
+        language: Option<String>,
+
+        /// The code.
+        code: String,
+    },
+
+    /// A list of items, each one made of blocks.
+    List {
+        /// The number of the first item, if the list is ordered.
+        start: Option<u64>,
+
+        /// The items of the list.
+        items: Vec<Vec<Block>>,
+    },
+
+    /// A block quote.
+    Quote(Vec<Block>),
+
+    /// An image.
+    Image {
+        /// The URL of the image.
+        url: String,
+
+        /// The alternative text of the image.
+        alt: String,
+    },
+}
+
+/// A piece of text inside a [`Block`], with its own style.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Inline {
+    /// The text of the [`Inline`].
+    pub content: String,
+
+    /// Whether the [`Inline`] is strongly emphasized or not.
+    pub strong: bool,
+
+    /// Whether the [`Inline`] is emphasized or not.
+    pub emphasis: bool,
+
+    /// Whether the [`Inline`] is struck through or not.
+    pub strikethrough: bool,
+
+    /// Whether the [`Inline`] is a code span or not.
+    pub code: bool,
+
+    /// The destination of the link of the [`Inline`], if any.
+    pub link: Option<String>,
+}
@@ -0,0 +1,327 @@
+use crate::markdown::{Block, Inline};
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+
+/// Parses a CommonMark document into a list of [`Block`]s.
+///
+/// Strikethrough and task lists are supported as extensions. Raw HTML,
+/// thematic breaks and footnotes are ignored.
+pub fn parse(source: &str) -> Vec<Block> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut state = State::new();
+
+    for event in Parser::new_ext(source, options) {
+        state.process(event);
+    }
+
+    state.finish()
+}
+
+enum Container {
+    Document(Vec<Block>),
+    Quote(Vec<Block>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    Item(Vec<Block>),
+}
+
+struct State {
+    containers: Vec<Container>,
+    inlines: Vec<Inline>,
+    strong: usize,
+    emphasis: usize,
+    strikethrough: usize,
+    link: Option<String>,
+    heading: Option<u8>,
+    code_block: Option<(Option<String>, String)>,
+    image: Option<(String, String)>,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            containers: vec![Container::Document(Vec::new())],
+            inlines: Vec::new(),
+            strong: 0,
+            emphasis: 0,
+            strikethrough: 0,
+            link: None,
+            heading: None,
+            code_block: None,
+            image: None,
+        }
+    }
+
+    fn process(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text, false),
+            Event::Code(code) => self.text(&code, true),
+            Event::SoftBreak => self.text(" ", false),
+            Event::HardBreak => self.text("\n", false),
+            Event::TaskListMarker(is_checked) => {
+                self.text(if is_checked { "[x] " } else { "[ ] " }, false)
+            }
+            Event::Html(_) | Event::FootnoteReference(_) | Event::Rule => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Heading(level) => {
+                self.flush();
+                self.heading = Some(level.min(6) as u8);
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().map(String::from)
+                    }
+                    CodeBlockKind::Indented => None,
+                };
+
+                self.code_block = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.containers.push(Container::List {
+                    start,
+                    items: Vec::new(),
+                });
+            }
+            Tag::Item => {
+                self.containers.push(Container::Item(Vec::new()));
+            }
+            Tag::BlockQuote => {
+                self.flush();
+                self.containers.push(Container::Quote(Vec::new()));
+            }
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link(_, destination, _) => {
+                self.link = Some(destination.to_string());
+            }
+            Tag::Image(_, destination, _) => {
+                self.image = Some((destination.to_string(), String::new()));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph | Tag::Heading(_) => self.flush(),
+            Tag::CodeBlock(_) => {
+                if let Some((language, mut code)) = self.code_block.take() {
+                    if code.ends_with('\n') {
+                        let _ = code.pop();
+                    }
+
+                    self.push(Block::CodeBlock { language, code });
+                }
+            }
+            Tag::List(_) => {
+                if let Some(Container::List { start, items }) =
+                    self.containers.pop()
+                {
+                    self.push(Block::List { start, items });
+                }
+            }
+            Tag::Item => {
+                self.flush();
+
+                if let Some(Container::Item(blocks)) = self.containers.pop() {
+                    if let Some(Container::List { items, .. }) =
+                        self.containers.last_mut()
+                    {
+                        items.push(blocks);
+                    }
+                }
+            }
+            Tag::BlockQuote => {
+                self.flush();
+
+                if let Some(Container::Quote(blocks)) = self.containers.pop() {
+                    self.push(Block::Quote(blocks));
+                }
+            }
+            Tag::Emphasis => self.emphasis -= 1,
+            Tag::Strong => self.strong -= 1,
+            Tag::Strikethrough => self.strikethrough -= 1,
+            Tag::Link(..) => self.link = None,
+            Tag::Image(..) => {
+                if let Some((url, alt)) = self.image.take() {
+                    // Headings cannot contain blocks, so we keep the
+                    // alternative text instead.
+                    if self.heading.is_some() {
+                        self.text(&alt, false);
+                    } else {
+                        self.flush();
+                        self.push(Block::Image { url, alt });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, content: &str, is_code: bool) {
+        if let Some((_, code)) = &mut self.code_block {
+            code.push_str(content);
+            return;
+        }
+
+        if let Some((_, alt)) = &mut self.image {
+            alt.push_str(content);
+            return;
+        }
+
+        let inline = Inline {
+            content: String::from(content),
+            strong: self.strong > 0,
+            emphasis: self.emphasis > 0,
+            strikethrough: self.strikethrough > 0,
+            code: is_code,
+            link: self.link.clone(),
+        };
+
+        match self.inlines.last_mut() {
+            Some(last) if has_same_style(last, &inline) => {
+                last.content.push_str(content);
+            }
+            _ => self.inlines.push(inline),
+        }
+    }
+
+    fn flush(&mut self) {
+        let inlines = std::mem::take(&mut self.inlines);
+
+        match self.heading.take() {
+            Some(level) => self.push(Block::Heading {
+                level,
+                content: inlines,
+            }),
+            None if !inlines.is_empty() => self.push(Block::Paragraph(inlines)),
+            None => {}
+        }
+    }
+
+    fn push(&mut self, block: Block) {
+        match self.containers.last_mut() {
+            Some(Container::Document(blocks))
+            | Some(Container::Quote(blocks))
+            | Some(Container::Item(blocks)) => blocks.push(block),
+            Some(Container::List { .. }) | None => {}
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush();
+
+        match self.containers.into_iter().next() {
+            Some(Container::Document(blocks)) => blocks,
+            _ => Vec::new(),
+        }
+    }
+}
+
+fn has_same_style(a: &Inline, b: &Inline) -> bool {
+    a.strong == b.strong
+        && a.emphasis == b.emphasis
+        && a.strikethrough == b.strikethrough
+        && a.code == b.code
+        && a.link == b.link
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(content: &str) -> Inline {
+        Inline {
+            content: String::from(content),
+            ..Inline::default()
+        }
+    }
+
+    #[test]
+    fn parses_inline_styles() {
+        let blocks = parse(
+            "# Hello\n\nSome *fancy* `code` and [a link](https://iced.rs).",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading {
+                    level: 1,
+                    content: vec![text("Hello")],
+                },
+                Block::Paragraph(vec![
+                    text("Some "),
+                    Inline {
+                        emphasis: true,
+                        ..text("fancy")
+                    },
+                    text(" "),
+                    Inline {
+                        code: true,
+                        ..text("code")
+                    },
+                    text(" and "),
+                    Inline {
+                        link: Some(String::from("https://iced.rs")),
+                        ..text("a link")
+                    },
+                    text("."),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_nested_blocks() {
+        let blocks = parse(
+            "> Quoted\n\n1. One\n   - Nested\n2. ![Ferris](ferris.png)\n\n\
+             ```rust\nfn main() {}\n```\n",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Quote(vec![Block::Paragraph(vec![text("Quoted")])]),
+                Block::List {
+                    start: Some(1),
+                    items: vec![
+                        vec![
+                            Block::Paragraph(vec![text("One")]),
+                            Block::List {
+                                start: None,
+                                items: vec![vec![Block::Paragraph(vec![
+                                    text("Nested")
+                                ])]],
+                            },
+                        ],
+                        vec![Block::Image {
+                            url: String::from("ferris.png"),
+                            alt: String::from("Ferris"),
+                        }],
+                    ],
+                },
+                Block::CodeBlock {
+                    language: Some(String::from("rust")),
+                    code: String::from("fn main() {}"),
+                },
+            ]
+        );
+    }
+}
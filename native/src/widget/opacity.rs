@@ -0,0 +1,169 @@
+//! Composite some content with a given opacity.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+/// An element drawing its content with some opacity.
+///
+/// The content is composited as a whole. Therefore, overlapping children of
+/// the content do not show through each other.
+///
+/// An [`Opacity`] only changes the way its content looks. The content keeps
+/// receiving events, even when it is fully transparent.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::Text;
+/// # type Opacity<'a> =
+/// #     iced_native::Opacity<'a, (), iced_native::renderer::Null>;
+/// #
+/// let faded = Opacity::new(Text::new("Fading out..."), 0.5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Opacity<'a, Message, Renderer> {
+    opacity: f32,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Opacity<'a, Message, Renderer> {
+    /// Creates an [`Opacity`] with the given content and opacity, from 0 to
+    /// 1.
+    pub fn new<T>(content: T, opacity: f32) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Opacity {
+            opacity: opacity.clamp(0.0, 1.0),
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Opacity<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self.content.layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            cursor_position,
+            viewport,
+            self.opacity,
+            &self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.content
+            .caret_bounds(layout.children().next().unwrap(), renderer)
+    }
+}
+
+/// The renderer of an [`Opacity`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use an [`Opacity`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws an [`Opacity`].
+    ///
+    /// It receives:
+    ///   * the cursor position
+    ///   * the visible region of the content
+    ///   * the opacity of the content, from 0 to 1
+    ///   * the content of the [`Opacity`]
+    ///   * the [`Layout`] of the content
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        opacity: f32,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Opacity<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        opacity: Opacity<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(opacity)
+    }
+}
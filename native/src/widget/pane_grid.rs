@@ -344,8 +344,11 @@ where
                 let region = regions.get(pane)?;
                 let size = Size::new(region.width, region.height);
 
-                let mut node =
-                    element.layout(renderer, &layout::Limits::new(size, size));
+                let mut node = element.layout(
+                    renderer,
+                    &layout::Limits::new(size, size)
+                        .with_direction(limits.direction()),
+                );
 
                 node.move_to(Point::new(region.x, region.y));
 
@@ -568,6 +571,17 @@ where
             .filter_map(|((_, pane), layout)| pane.overlay(layout))
             .next()
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.elements
+            .iter()
+            .zip(layout.children())
+            .find_map(|((_, pane), layout)| pane.caret_bounds(layout, renderer))
+    }
 }
 
 /// The renderer of a [`PaneGrid`].
@@ -3,7 +3,7 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
 use crate::pane_grid::{self, TitleBar};
-use crate::{Clipboard, Element, Hasher, Layout, Point, Size};
+use crate::{Clipboard, Element, Hasher, Layout, Point, Rectangle, Size};
 
 /// The content of a [`Pane`].
 ///
@@ -111,8 +111,11 @@ where
         if let Some(title_bar) = &self.title_bar {
             let max_size = limits.max();
 
-            let title_bar_layout = title_bar
-                .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+            let title_bar_layout = title_bar.layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
+            );
 
             let title_bar_size = title_bar_layout.size();
 
@@ -124,7 +127,8 @@ where
                         max_size.width,
                         max_size.height - title_bar_size.height,
                     ),
-                ),
+                )
+                .with_direction(limits.direction()),
             );
 
             body_layout.move_to(Point::new(0.0, title_bar_size.height));
@@ -203,6 +207,20 @@ where
 
         self.body.overlay(body_layout)
     }
+
+    pub(crate) fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        let body_layout = if self.title_bar.is_some() {
+            layout.children().nth(1)?
+        } else {
+            layout
+        };
+
+        self.body.caret_bounds(body_layout, renderer)
+    }
 }
 
 impl<'a, T, Message, Renderer> From<T> for Content<'a, Message, Renderer>
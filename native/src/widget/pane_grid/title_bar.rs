@@ -158,21 +158,33 @@ where
         let limits = limits.pad(padding);
         let max_size = limits.max();
 
-        let title_layout = self
-            .content
-            .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+        let mut title_layout = self.content.layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, max_size)
+                .with_direction(limits.direction()),
+        );
         let title_size = title_layout.size();
 
         let mut node = if let Some(controls) = &self.controls {
-            let mut controls_layout = controls
-                .layout(renderer, &layout::Limits::new(Size::ZERO, max_size));
+            let mut controls_layout = controls.layout(
+                renderer,
+                &layout::Limits::new(Size::ZERO, max_size)
+                    .with_direction(limits.direction()),
+            );
 
             let controls_size = controls_layout.size();
             let space_before_controls = max_size.width - controls_size.width;
 
             let height = title_size.height.max(controls_size.height);
 
-            controls_layout.move_to(Point::new(space_before_controls, 0.0));
+            if limits.direction().is_rtl() {
+                title_layout.move_to(Point::new(
+                    max_size.width - title_size.width,
+                    0.0,
+                ));
+            } else {
+                controls_layout.move_to(Point::new(space_before_controls, 0.0));
+            }
 
             layout::Node::with_children(
                 Size::new(max_size.width, height),
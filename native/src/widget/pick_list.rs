@@ -164,6 +164,9 @@ where
                             text_size,
                             Renderer::Font::default(),
                             Size::new(f32::INFINITY, f32::INFINITY),
+                            text::Overflow::default(),
+                            text::Spacing::default(),
+                            limits.direction(),
                         );
 
                         width.round() as u32
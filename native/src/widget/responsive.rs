@@ -0,0 +1,235 @@
+//! Build content that adapts to the space available.
+use std::cell::RefCell;
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::overlay;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+/// A widget that produces its content from the [`Size`] it is given during
+/// layout.
+///
+/// A [`Responsive`] widget fills all the available space and calls its view
+/// function with the resulting [`Size`]. The produced [`Element`] is cached
+/// until the available space changes.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{responsive, Column, Responsive, Row, Text};
+/// # type Renderer = iced_native::renderer::Null;
+/// #
+/// let mut state = responsive::State::new();
+///
+/// let responsive = Responsive::<(), Renderer>::new(&mut state, |size| {
+///     if size.width < 600.0 {
+///         Column::new().push(Text::new("Narrow")).into()
+///     } else {
+///         Row::new().push(Text::new("Wide")).into()
+///     }
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    state: RefCell<&'a mut State>,
+    view: Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>,
+    content: RefCell<Option<Content<'a, Message, Renderer>>>,
+}
+
+struct Content<'a, Message, Renderer> {
+    size: Size,
+    element: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`Responsive`] widget with the given [`State`] and view
+    /// function.
+    ///
+    /// The view function will be called with the [`Size`] available to the
+    /// [`Responsive`] widget.
+    pub fn new(
+        state: &'a mut State,
+        view: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Responsive {
+            state: RefCell::new(state),
+            view: Box::new(view),
+            content: RefCell::new(None),
+        }
+    }
+
+    fn rebuild_if_needed(&self, size: Size) {
+        let mut content = self.content.borrow_mut();
+
+        let is_outdated = match content.as_ref() {
+            Some(content) => content.size != size,
+            None => true,
+        };
+
+        if is_outdated {
+            *content = Some(Content {
+                size,
+                element: (self.view)(size),
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fill
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .resolve(Size::ZERO);
+
+        self.rebuild_if_needed(size);
+        self.state.borrow_mut().last_size = Some(size);
+
+        let content = self.content.borrow();
+        let node = content.as_ref().unwrap().element.layout(
+            renderer,
+            &layout::Limits::new(Size::ZERO, size)
+                .with_direction(limits.direction()),
+        );
+
+        layout::Node::with_children(size, vec![node])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        self.rebuild_if_needed(layout.bounds().size());
+
+        self.content.get_mut().as_mut().unwrap().element.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.rebuild_if_needed(layout.bounds().size());
+
+        self.content.borrow().as_ref().unwrap().element.draw(
+            renderer,
+            defaults,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        // The content can only be known once a size is available. We reuse
+        // the size of the last layout, which stays valid as long as the rest
+        // of the user interface does not change.
+        let last_size = self.state.borrow().last_size;
+
+        if let Some(size) = last_size {
+            self.rebuild_if_needed(size);
+
+            self.content
+                .borrow()
+                .as_ref()
+                .unwrap()
+                .element
+                .hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.rebuild_if_needed(layout.bounds().size());
+
+        self.content
+            .get_mut()
+            .as_mut()
+            .unwrap()
+            .element
+            .overlay(layout.children().next().unwrap())
+    }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.rebuild_if_needed(layout.bounds().size());
+
+        self.content
+            .borrow()
+            .as_ref()
+            .unwrap()
+            .element
+            .caret_bounds(layout.children().next().unwrap(), renderer)
+    }
+}
+
+/// The local state of a [`Responsive`] widget.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    last_size: Option<Size>,
+}
+
+impl State {
+    /// Creates a new [`State`] for a [`Responsive`] widget.
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
@@ -0,0 +1,418 @@
+//! Write a paragraph of text made of spans with their own style.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::touch;
+use crate::{
+    Clipboard, Color, Direction, Element, Hasher, HorizontalAlignment, Layout,
+    Length, LineHeight, Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of text made of [`Span`]s, each with its own style.
+///
+/// The spans of a [`RichText`] are laid out together, so the paragraph wraps
+/// as a whole.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::rich_text::Span;
+/// # type RichText =
+/// #     iced_native::RichText<Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenLogs,
+/// }
+///
+/// let text = RichText::new()
+///     .push("Build failed with ")
+///     .push(Span::new("3 errors").color([1.0, 0.0, 0.0]).underline())
+///     .push(". ")
+///     .push(Span::new("Open the logs").link(Message::OpenLogs));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<Message, Renderer: text::Renderer> {
+    spans: Vec<Span<Message, Renderer::Font>>,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    spacing: text::Spacing,
+}
+
+impl<Message, Renderer> RichText<Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates an empty [`RichText`].
+    pub fn new() -> Self {
+        Self::with_spans(Vec::new())
+    }
+
+    /// Creates a [`RichText`] with the given [`Span`]s.
+    pub fn with_spans(spans: Vec<Span<Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            spacing: text::Spacing::default(),
+        }
+    }
+
+    /// Adds a [`Span`] to the end of the [`RichText`].
+    pub fn push(
+        mut self,
+        span: impl Into<Span<Message, Renderer::Font>>,
+    ) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`RichText`].
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`RichText`].
+    ///
+    /// A plain number is a factor of the size of the first [`Span`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.spacing.line_height = Some(line_height.into());
+        self
+    }
+
+    /// Sets the extra space between the characters of the [`RichText`], in
+    /// logical pixels.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.spacing.letter_spacing = letter_spacing;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for RichText<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let bounds = limits.max();
+
+        let (width, height) = renderer.measure_spans(
+            &self.spans,
+            self.spacing,
+            limits.direction(),
+            bounds,
+        );
+
+        let baseline = self
+            .spans
+            .first()
+            .map(|span| {
+                renderer.baseline(
+                    span.size.unwrap_or(renderer.default_size()),
+                    span.font,
+                    self.spacing,
+                )
+            })
+            .unwrap_or(0.0);
+
+        let size = limits.resolve(Size::new(width, height));
+
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size)
+            .with_baseline(offset + baseline)
+            .with_direction(limits.direction())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let span = renderer.span_at(
+                    &self.spans,
+                    self.spacing,
+                    layout.direction(),
+                    layout.bounds(),
+                    layout
+                        .direction()
+                        .horizontal_alignment(self.horizontal_alignment),
+                    self.vertical_alignment,
+                    cursor_position,
+                );
+
+                if let Some(link) =
+                    span.and_then(|span| self.spans[span].link.clone())
+                {
+                    messages.push(link);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw_spans(
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            &self.spans,
+            self.spacing,
+            layout.direction(),
+            layout
+                .direction()
+                .horizontal_alignment(self.horizontal_alignment),
+            self.vertical_alignment,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+            span.font.hash(state);
+        }
+
+        self.spacing.hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<Message, Renderer> Default for RichText<Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A piece of text with its own style, part of a [`RichText`].
+#[derive(Debug, Clone)]
+pub struct Span<Message, Font> {
+    /// The content of the [`Span`].
+    pub content: String,
+
+    /// The size of the [`Span`].
+    ///
+    /// If `None`, the default size of the renderer is used.
+    pub size: Option<u16>,
+
+    /// The [`Color`] of the [`Span`].
+    ///
+    /// If `None`, the default text color of the renderer is used.
+    pub color: Option<Color>,
+
+    /// The font of the [`Span`].
+    pub font: Font,
+
+    /// Whether the [`Span`] is underlined or not.
+    pub underline: bool,
+
+    /// Whether the [`Span`] is struck through or not.
+    pub strikethrough: bool,
+
+    /// The message produced when the [`Span`] is clicked, if any.
+    pub link: Option<Message>,
+}
+
+impl<Message, Font: Default> Span<Message, Font> {
+    /// Creates a new [`Span`] with the given content.
+    pub fn new(content: impl Into<String>) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Font::default(),
+            underline: false,
+            strikethrough: false,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Underlines the [`Span`].
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Strikes the [`Span`] through.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Span`] is clicked.
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+impl<Message, Font: Default> From<&str> for Span<Message, Font> {
+    fn from(content: &str) -> Self {
+        Span::new(content)
+    }
+}
+
+impl<Message, Font: Default> From<String> for Span<Message, Font> {
+    fn from(content: String) -> Self {
+        Span::new(content)
+    }
+}
+
+/// The renderer of a [`RichText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RichText`] in your user interface.
+///
+/// [renderer]: crate::Renderer
+pub trait Renderer: text::Renderer {
+    /// Measures the [`Span`]s of a [`RichText`] with the given
+    /// [`text::Spacing`] and paragraph [`Direction`] in the given bounds and
+    /// returns the minimum boundaries that can fit the whole paragraph.
+    fn measure_spans<Message>(
+        &self,
+        spans: &[Span<Message, Self::Font>],
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the index of the [`Span`] of a [`RichText`] under the given
+    /// point, if any.
+    fn span_at<Message>(
+        &self,
+        spans: &[Span<Message, Self::Font>],
+        spacing: text::Spacing,
+        direction: Direction,
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        point: Point,
+    ) -> Option<usize>;
+
+    /// Draws the [`Span`]s of a [`RichText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the cursor position
+    ///   * the [`Span`]s of the [`RichText`]
+    ///   * the [`text::Spacing`] of the [`RichText`]
+    ///   * the base [`Direction`] of the paragraph
+    ///   * the [`HorizontalAlignment`] of the [`RichText`]
+    ///   * the [`VerticalAlignment`] of the [`RichText`]
+    fn draw_spans<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        spans: &[Span<Message, Self::Font>],
+        spacing: text::Spacing,
+        direction: Direction,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        rich_text: RichText<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
@@ -190,7 +190,7 @@ where
         self.padding.hash(state);
 
         for child in &self.children {
-            child.widget.hash_layout(state);
+            child.hash_layout(state);
         }
     }
 
@@ -204,6 +204,17 @@ where
             .filter_map(|(child, layout)| child.widget.overlay(layout))
             .next()
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .find_map(|(child, layout)| child.caret_bounds(layout, renderer))
+    }
 }
 
 /// The renderer of a [`Row`].
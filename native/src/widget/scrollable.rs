@@ -149,7 +149,8 @@ where
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
             Size::new(limits.max().width, f32::INFINITY),
-        );
+        )
+        .with_direction(limits.direction());
 
         let content = self.content.layout(renderer, &child_limits);
         let size = limits.resolve(content.size());
@@ -415,6 +416,20 @@ where
                 overlay.translate(Vector::new(0.0, -(offset as f32)))
             })
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let offset = self.state.offset(bounds, content_layout.bounds());
+
+        self.content
+            .caret_bounds(content_layout, renderer)
+            .map(|caret| caret + Vector::new(0.0, -(offset as f32)))
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -0,0 +1,540 @@
+//! Display read-only text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::text;
+use crate::touch;
+use crate::{
+    Clipboard, Color, Direction, Element, Hasher, HorizontalAlignment, Layout,
+    Length, Point, Rectangle, Size, VerticalAlignment, Widget,
+};
+
+use std::hash::Hash;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A paragraph of read-only text that can be selected and copied.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::selectable_text;
+/// # type SelectableText<'a> =
+/// #     iced_native::SelectableText<'a, iced_native::renderer::Null>;
+/// #
+/// let mut state = selectable_text::State::new();
+///
+/// SelectableText::new(&mut state, "Error 0x80070057: invalid parameter")
+///     .size(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> SelectableText<'a, Renderer> {
+    /// Creates a new [`SelectableText`] with the given [`State`] and contents.
+    pub fn new(state: &'a mut State, content: impl Into<String>) -> Self {
+        SelectableText {
+            state,
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`SelectableText`].
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`SelectableText`].
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the [`SelectableText`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn find_position(
+        &self,
+        renderer: &Renderer,
+        layout: Layout<'_>,
+        point: Point,
+    ) -> usize {
+        let bounds = layout.bounds();
+
+        let characters = renderer.character_bounds(
+            &self.content,
+            self.size.unwrap_or(renderer.default_size()),
+            self.font,
+            layout.direction(),
+            bounds.size(),
+            layout
+                .direction()
+                .horizontal_alignment(self.horizontal_alignment),
+            self.vertical_alignment,
+        );
+
+        find_position(
+            &self.content,
+            &characters,
+            Point::new(point.x - bounds.x, point.y - bounds.y),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SelectableText<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let bounds = limits.max();
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font,
+            bounds,
+            text::Overflow::default(),
+            text::Spacing::default(),
+            limits.direction(),
+        );
+
+        let baseline =
+            renderer.baseline(size, self.font, text::Spacing::default());
+        let size = limits.resolve(Size::new(width, height));
+
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size)
+            .with_baseline(offset + baseline)
+            .with_direction(limits.direction())
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_clicked = layout.bounds().contains(cursor_position);
+
+                self.state.is_focused = is_clicked;
+
+                if !is_clicked {
+                    self.state.selection = None;
+
+                    return event::Status::Ignored;
+                }
+
+                let position =
+                    self.find_position(renderer, layout, cursor_position);
+
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        self.state.selection = Some((position, position));
+                        self.state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        self.state.selection =
+                            Some(word_at(&self.content, position));
+                        self.state.is_dragging = false;
+                    }
+                    click::Kind::Triple => {
+                        self.state.selection = Some((0, self.content.len()));
+                        self.state.is_dragging = false;
+                    }
+                }
+
+                self.state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.state.is_dragging =>
+            {
+                let head = self.find_position(renderer, layout, position);
+
+                if let Some((_, selection_head)) = &mut self.state.selection {
+                    *selection_head = head;
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused && modifiers.is_command_pressed() => {
+                match key_code {
+                    keyboard::KeyCode::A => {
+                        self.state.selection = Some((0, self.content.len()));
+                    }
+                    keyboard::KeyCode::C => {
+                        let selection = self.state.selection(&self.content);
+
+                        if let (Some((start, end)), Some(clipboard)) =
+                            (selection, clipboard)
+                        {
+                            clipboard
+                                .write(self.content[start..end].to_owned());
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            &self.content,
+            self.size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.color,
+            layout.direction(),
+            layout
+                .direction()
+                .horizontal_alignment(self.horizontal_alignment),
+            self.vertical_alignment,
+            self.state.selection(&self.content),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+        self.font.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.vertical_alignment.hash(state);
+    }
+}
+
+/// The state of a [`SelectableText`].
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+    selection: Option<(usize, usize)>,
+}
+
+impl State {
+    /// Creates a new [`State`] with nothing selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the byte range of the selected contents, if any.
+    ///
+    /// The selection is discarded if it does not fit the given contents
+    /// anymore.
+    fn selection(&self, content: &str) -> Option<(usize, usize)> {
+        let (anchor, head) = self.selection?;
+        let (start, end) = (anchor.min(head), anchor.max(head));
+
+        if start == end
+            || !content.is_char_boundary(start)
+            || !content.is_char_boundary(end)
+        {
+            return None;
+        }
+
+        Some((start, end))
+    }
+}
+
+/// The renderer of a [`SelectableText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SelectableText`] in your user interface.
+///
+/// [renderer]: crate::Renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Lays out the contents of a [`SelectableText`] in the given bounds and
+    /// paragraph [`Direction`] and returns the byte offset and the bounds of
+    /// every visible character.
+    ///
+    /// The bounds of a character span the whole height of its line and are
+    /// relative to the top-left corner of the [`SelectableText`].
+    fn character_bounds(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<(usize, Rectangle)>;
+
+    /// Draws a [`SelectableText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SelectableText`]
+    ///   * the cursor position
+    ///   * the contents of the [`SelectableText`]
+    ///   * the size of the [`SelectableText`]
+    ///   * the color of the [`SelectableText`]
+    ///   * the base [`Direction`] of the paragraphs
+    ///   * the [`HorizontalAlignment`] of the [`SelectableText`]
+    ///   * the [`VerticalAlignment`] of the [`SelectableText`]
+    ///   * the byte range of the selected contents, if any
+    ///   * the style of the [`SelectableText`]
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        color: Option<Color>,
+        direction: Direction,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        selection: Option<(usize, usize)>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        selectable_text: SelectableText<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
+
+/// Finds the byte offset closest to the given point, using the bounds of
+/// the laid out characters.
+fn find_position(
+    content: &str,
+    characters: &[(usize, Rectangle)],
+    point: Point,
+) -> usize {
+    let (first, last) = match (characters.first(), characters.last()) {
+        (Some((_, first)), Some((_, last))) => (first, last),
+        _ => return 0,
+    };
+
+    if point.y < first.y {
+        return 0;
+    }
+
+    if point.y > last.y + last.height {
+        return content.len();
+    }
+
+    let line = characters
+        .iter()
+        .map(|(_, bounds)| bounds)
+        .find(|bounds| point.y <= bounds.y + bounds.height)
+        .unwrap_or(last)
+        .y;
+
+    characters
+        .iter()
+        .filter(|(_, bounds)| (bounds.y - line).abs() < 0.5)
+        .flat_map(|(index, bounds)| {
+            let length = content[*index..]
+                .chars()
+                .next()
+                .map(char::len_utf8)
+                .unwrap_or(0);
+
+            vec![
+                (*index, bounds.x),
+                (index + length, bounds.x + bounds.width),
+            ]
+        })
+        .min_by(|(_, a), (_, b)| {
+            (a - point.x)
+                .abs()
+                .partial_cmp(&(b - point.x).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+/// Returns the byte range of the word at the given byte offset.
+fn word_at(content: &str, index: usize) -> (usize, usize) {
+    content
+        .split_word_bound_indices()
+        .find(|(start, word)| index < start + word.len())
+        .or_else(|| content.split_word_bound_indices().next_back())
+        .map(|(start, word)| (start, start + word.len()))
+        .unwrap_or((0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_at_finds_the_surrounding_word() {
+        let content = "Error 0x80070057: invalid";
+
+        assert_eq!(word_at(content, 2), (0, 5));
+        assert_eq!(word_at(content, 8), (6, 16));
+        assert_eq!(word_at(content, content.len()), (18, 25));
+        assert_eq!(word_at("", 0), (0, 0));
+    }
+
+    #[test]
+    fn find_position_snaps_to_the_closest_edge() {
+        let characters: Vec<(usize, Rectangle)> = "ab\ncd"
+            .char_indices()
+            .filter(|(_, c)| *c != '\n')
+            .map(|(index, _)| {
+                let (column, line) = if index < 3 {
+                    (index, 0)
+                } else {
+                    (index - 3, 1)
+                };
+
+                (
+                    index,
+                    Rectangle {
+                        x: column as f32 * 10.0,
+                        y: line as f32 * 20.0,
+                        width: 10.0,
+                        height: 20.0,
+                    },
+                )
+            })
+            .collect();
+
+        let find =
+            |x, y| find_position("ab\ncd", &characters, Point::new(x, y));
+
+        assert_eq!(find(3.0, 5.0), 0);
+        assert_eq!(find(7.0, 5.0), 1);
+        assert_eq!(find(100.0, 5.0), 2);
+        assert_eq!(find(12.0, 25.0), 4);
+        assert_eq!(find(0.0, -10.0), 0);
+        assert_eq!(find(0.0, 100.0), 5);
+    }
+}
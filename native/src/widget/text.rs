@@ -1,7 +1,7 @@
 //! Write some text for your users to read.
 use crate::{
-    layout, Color, Element, Hasher, HorizontalAlignment, Layout, Length, Point,
-    Rectangle, Size, VerticalAlignment, Widget,
+    layout, Color, Direction, Element, Hasher, HorizontalAlignment, Layout,
+    Length, LineHeight, Point, Rectangle, Size, VerticalAlignment, Widget,
 };
 
 use std::hash::Hash;
@@ -29,6 +29,8 @@ pub struct Text<Renderer: self::Renderer> {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    overflow: Overflow,
+    spacing: Spacing,
 }
 
 impl<Renderer: self::Renderer> Text<Renderer> {
@@ -43,6 +45,8 @@ impl<Renderer: self::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            overflow: Overflow::default(),
+            spacing: Spacing::default(),
         }
     }
 
@@ -92,6 +96,42 @@ impl<Renderer: self::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets whether the lines of the [`Text`] wrap when they do not fit its
+    /// width.
+    ///
+    /// By default, lines wrap.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.overflow.wrap = wrap;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    pub fn max_lines(mut self, max_lines: u16) -> Self {
+        self.overflow.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets the [`Truncation`] of the [`Text`] when its contents do not fit.
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.overflow.truncation = truncation;
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// A plain number is a factor of the size of the [`Text`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.spacing.line_height = Some(line_height.into());
+        self
+    }
+
+    /// Sets the extra space between the characters of the [`Text`], in
+    /// logical pixels.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.spacing.letter_spacing = letter_spacing;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -117,12 +157,28 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font,
+            bounds,
+            self.overflow,
+            self.spacing,
+            limits.direction(),
+        );
 
+        let baseline = renderer.baseline(size, self.font, self.spacing);
         let size = limits.resolve(Size::new(width, height));
 
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
         layout::Node::new(size)
+            .with_baseline(offset + baseline)
+            .with_direction(limits.direction())
     }
 
     fn draw(
@@ -140,8 +196,13 @@ where
             self.size.unwrap_or(renderer.default_size()),
             self.font,
             self.color,
-            self.horizontal_alignment,
+            layout
+                .direction()
+                .horizontal_alignment(self.horizontal_alignment),
             self.vertical_alignment,
+            self.overflow,
+            self.spacing,
+            layout.direction(),
         )
     }
 
@@ -151,8 +212,76 @@ where
 
         self.content.hash(state);
         self.size.hash(state);
+        self.font.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.vertical_alignment.hash(state);
+        self.overflow.hash(state);
+        self.spacing.hash(state);
+    }
+}
+
+/// The way a [`Text`] deals with contents that do not fit its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Overflow {
+    /// Whether lines wrap when they do not fit the available width.
+    pub wrap: bool,
+
+    /// The maximum amount of lines, if any.
+    pub max_lines: Option<u16>,
+
+    /// The [`Truncation`] of the contents that do not fit.
+    pub truncation: Truncation,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow {
+            wrap: true,
+            max_lines: None,
+            truncation: Truncation::Clip,
+        }
+    }
+}
+
+/// The way the contents of a [`Text`] are cut when they do not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Truncation {
+    /// The contents are clipped at the bounds of the [`Text`].
+    Clip,
+
+    /// The end of the contents is replaced with an ellipsis.
+    End,
+
+    /// The middle of the contents is replaced with an ellipsis.
+    Middle,
+}
+
+/// The spacing of the lines and characters of a [`Text`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spacing {
+    /// The [`LineHeight`], if different from the one of the font.
+    pub line_height: Option<LineHeight>,
+
+    /// The extra space between characters, in logical pixels.
+    pub letter_spacing: f32,
+}
+
+impl Hash for Spacing {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self.line_height {
+            None => 0.hash(state),
+            Some(LineHeight::Relative(factor)) => {
+                1.hash(state);
+                factor.to_bits().hash(state);
+            }
+            Some(LineHeight::Absolute(height)) => {
+                2.hash(state);
+                height.to_bits().hash(state);
+            }
+        }
+
+        self.letter_spacing.to_bits().hash(state);
     }
 }
 
@@ -164,21 +293,34 @@ where
 /// [renderer]: crate::Renderer
 pub trait Renderer: crate::Renderer {
     /// The font type used for [`Text`].
-    type Font: Default + Copy;
+    type Font: Default + Copy + Hash;
 
     /// Returns the default size of [`Text`].
     fn default_size(&self) -> u16;
 
     /// Measures the [`Text`] in the given bounds and returns the minimum
-    /// boundaries that can fit the contents.
+    /// boundaries that can fit the contents, once the given [`Overflow`] and
+    /// [`Spacing`] are applied and its paragraphs are laid out in the given
+    /// [`Direction`].
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Self::Font,
         bounds: Size,
+        overflow: Overflow,
+        spacing: Spacing,
+        direction: Direction,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of [`Text`] to its
+    /// baseline, given its size, font and [`Spacing`].
+    ///
+    /// By default, it is 80% of the size of the [`Text`].
+    fn baseline(&self, size: u16, _font: Self::Font, _spacing: Spacing) -> f32 {
+        f32::from(size) * 0.8
+    }
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
@@ -188,6 +330,9 @@ pub trait Renderer: crate::Renderer {
     ///   * the color of the [`Text`]
     ///   * the [`HorizontalAlignment`] of the [`Text`]
     ///   * the [`VerticalAlignment`] of the [`Text`]
+    ///   * the [`Overflow`] of the [`Text`]
+    ///   * the [`Spacing`] of the [`Text`]
+    ///   * the base [`Direction`] of the paragraphs of the [`Text`]
     fn draw(
         &mut self,
         defaults: &Self::Defaults,
@@ -198,6 +343,9 @@ pub trait Renderer: crate::Renderer {
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
+        overflow: Overflow,
+        spacing: Spacing,
+        direction: Direction,
     ) -> Self::Output;
 }
 
@@ -222,6 +370,8 @@ impl<Renderer: self::Renderer> Clone for Text<Renderer> {
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            overflow: self.overflow,
+            spacing: self.spacing,
         }
     }
 }
@@ -232,7 +232,12 @@ where
             .max_width(self.max_width)
             .height(Length::Units(text_size));
 
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
+        let mut text = layout::Node::new(limits.resolve(Size::ZERO))
+            .with_baseline(renderer.baseline(
+                text_size,
+                self.font,
+                text::Spacing::default(),
+            ));
         text.move_to(Point::new(padding, padding));
 
         layout::Node::with_children(text.size().pad(padding), vec![text])
@@ -253,6 +258,7 @@ where
                 let is_clicked = layout.bounds().contains(cursor_position);
 
                 self.state.is_focused = is_clicked;
+                self.state.preedit = None;
 
                 if is_clicked {
                     let text_layout = layout.children().next().unwrap();
@@ -356,8 +362,42 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::ImePreedit { text, cursor })
+                if self.state.is_focused =>
+            {
+                // Passwords are never shown while being composed
+                self.state.preedit = if text.is_empty() || self.is_secure {
+                    None
+                } else {
+                    Some(Preedit {
+                        content: text,
+                        cursor,
+                    })
+                };
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ImeCommit(text))
+                if self.state.is_focused =>
+            {
+                self.state.preedit = None;
+
+                let content: String =
+                    text.chars().filter(|c| !c.is_control()).collect();
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                editor.paste(Value::new(&content));
+
+                let message = (self.on_change)(editor.contents());
+                messages.push(message);
+
+                return event::Status::Captured;
+            }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused
+                    && self.state.preedit.is_none()
                     && self.state.is_pasting.is_none()
                     && !self.state.keyboard_modifiers.is_command_pressed()
                     && !c.is_control() =>
@@ -372,6 +412,12 @@ where
 
                 return event::Status::Captured;
             }
+            // Keys are handled by the input method editor while composing
+            Event::Keyboard(keyboard::Event::KeyPressed { .. })
+                if self.state.is_focused && self.state.preedit.is_some() =>
+            {
+                return event::Status::Captured;
+            }
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key_code, ..
             }) if self.state.is_focused => {
@@ -543,6 +589,7 @@ where
                     }
                     keyboard::KeyCode::Escape => {
                         self.state.is_focused = false;
+                        self.state.preedit = None;
                         self.state.is_dragging = false;
                         self.state.is_pasting = None;
 
@@ -598,6 +645,44 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn caret_bounds(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        if !self.state.is_focused {
+            return None;
+        }
+
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let before_caret = match self.state.composition(&value) {
+            Some(composition) => composition.value.until(composition.caret),
+            None => value.until(self.state.cursor.end(&value)),
+        };
+
+        let offset =
+            renderer.offset(text_bounds, self.font, size, &value, &self.state);
+
+        let x =
+            renderer.measure_value(&before_caret.to_string(), size, self.font)
+                - offset;
+
+        Some(Rectangle {
+            x: text_bounds.x + x.max(0.0).min(text_bounds.width),
+            y: text_bounds.y,
+            width: 1.0,
+            height: text_bounds.height,
+        })
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -618,6 +703,8 @@ pub trait Renderer: text::Renderer + Sized {
     ///
     /// This is the amount of horizontal scrolling applied when the [`Value`]
     /// does not fit the [`TextInput`].
+    ///
+    /// The [`Composition`] of the [`State`], if any, is taken into account.
     fn offset(
         &self,
         text_bounds: Rectangle,
@@ -635,7 +722,7 @@ pub trait Renderer: text::Renderer + Sized {
     /// - the cursor position
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
-    /// - the current [`State`]
+    /// - the current [`State`], including its [`Composition`], if any
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -698,6 +785,7 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    preedit: Option<Preedit>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -716,6 +804,7 @@ impl State {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            preedit: None,
         }
     }
 
@@ -729,6 +818,46 @@ impl State {
         self.cursor
     }
 
+    /// Returns the [`Preedit`] of the [`TextInput`], if an input method
+    /// editor is composing some text.
+    pub fn preedit(&self) -> Option<&Preedit> {
+        self.preedit.as_ref()
+    }
+
+    /// Returns the [`Composition`] of the given [`Value`] with the
+    /// [`Preedit`] of the [`TextInput`], if any.
+    ///
+    /// The [`Preedit`] replaces the current selection.
+    pub fn composition(&self, value: &Value) -> Option<Composition> {
+        let preedit = self.preedit.as_ref()?;
+        let content = Value::new(&preedit.content);
+        let length = content.len();
+
+        let mut value = value.clone();
+        let mut cursor = self.cursor;
+
+        Editor::new(&mut value, &mut cursor).paste(content);
+
+        let end = cursor.end(&value);
+        let start = end - length;
+
+        let caret = match preedit.cursor {
+            Some((_, caret)) => preedit
+                .content
+                .get(..caret)
+                .map(|before| start + Value::new(before).len())
+                .unwrap_or(end),
+            None => end,
+        };
+
+        Some(Composition {
+            value,
+            start,
+            end,
+            caret,
+        })
+    }
+
     /// Focuses the [`TextInput`].
     pub fn focus(&mut self) {
         self.is_focused = true;
@@ -737,6 +866,7 @@ impl State {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = false;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -755,6 +885,36 @@ impl State {
     }
 }
 
+/// The text being composed by an input method editor in a [`TextInput`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preedit {
+    /// The text being composed.
+    pub content: String,
+
+    /// The byte range of the cursor or selection inside the content, if it
+    /// is visible.
+    pub cursor: Option<(usize, usize)>,
+}
+
+/// A [`Value`] with the [`Preedit`] of a [`TextInput`] inserted at its
+/// [`Cursor`].
+///
+/// Every index is a grapheme position in the composed [`Value`].
+#[derive(Debug, Clone)]
+pub struct Composition {
+    /// The composed [`Value`].
+    pub value: Value,
+
+    /// The start of the [`Preedit`].
+    pub start: usize,
+
+    /// The end of the [`Preedit`].
+    pub end: usize,
+
+    /// The position of the caret inside the [`Preedit`].
+    pub caret: usize,
+}
+
 // TODO: Reduce allocations
 fn find_cursor_position<Renderer: self::Renderer>(
     renderer: &Renderer,
@@ -822,3 +982,40 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composition_is_inserted_at_the_cursor() {
+        let mut state = State::focused();
+        state.move_cursor_to(2);
+        state.preedit = Some(Preedit {
+            content: String::from("にほん"),
+            cursor: Some((3, 3)),
+        });
+
+        let composition = state.composition(&Value::new("abcd")).unwrap();
+
+        assert_eq!(composition.value.to_string(), "abにほんcd");
+        assert_eq!((composition.start, composition.end), (2, 5));
+        assert_eq!(composition.caret, 3);
+    }
+
+    #[test]
+    fn composition_replaces_the_selection() {
+        let mut state = State::focused();
+        state.cursor.select_range(1, 3);
+        state.preedit = Some(Preedit {
+            content: String::from("한"),
+            cursor: None,
+        });
+
+        let composition = state.composition(&Value::new("abcd")).unwrap();
+
+        assert_eq!(composition.value.to_string(), "a한d");
+        assert_eq!((composition.start, composition.end), (1, 2));
+        assert_eq!(composition.caret, 2);
+    }
+}
@@ -1,4 +1,6 @@
 //! Build window-based GUI applications.
 mod event;
+mod screenshot;
 
 pub use event::Event;
+pub use screenshot::{screenshot, Screenshot};
@@ -0,0 +1,52 @@
+use crate::command::{self, Command};
+use crate::image;
+use crate::Size;
+
+use std::fmt;
+
+/// The contents of a window, captured from one of its frames.
+#[derive(Clone)]
+pub struct Screenshot {
+    /// The pixels of the [`Screenshot`] in RGBA format, row by row from the
+    /// top-left corner.
+    pub bytes: Vec<u8>,
+
+    /// The size of the [`Screenshot`], in physical pixels.
+    pub size: Size<u32>,
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Screenshot({} * {})", self.size.width, self.size.height)
+    }
+}
+
+impl From<Screenshot> for image::Handle {
+    fn from(screenshot: Screenshot) -> Self {
+        let mut pixels = screenshot.bytes;
+
+        // An image handle expects BGRA pixels
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+
+        image::Handle::from_pixels(
+            screenshot.size.width,
+            screenshot.size.height,
+            pixels,
+        )
+    }
+}
+
+/// Creates a [`Command`] that captures the next frame presented in the
+/// window.
+///
+/// The [`Screenshot`] contains any overlay drawn on top of the user interface,
+/// like the menu of a pick list, but not the debug overlay.
+///
+/// If the renderer cannot read the frame back, no message is produced.
+pub fn screenshot() -> Command<Screenshot> {
+    Command::single(command::Action::Screenshot {
+        tagger: Box::new(|screenshot| screenshot),
+    })
+}
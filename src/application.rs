@@ -1,5 +1,7 @@
 use crate::window;
-use crate::{Color, Command, Element, Executor, Settings, Subscription};
+use crate::{
+    Color, Command, Direction, Element, Executor, Settings, Subscription,
+};
 
 /// An interactive cross-platform application.
 ///
@@ -178,6 +180,17 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the [`Direction`] in which the contents of the [`Application`]
+    /// flow.
+    ///
+    /// Every widget inherits this [`Direction`], unless it is overridden by
+    /// one of its ancestors.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -273,6 +286,10 @@ where
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
+
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -305,4 +322,8 @@ where
     fn view(&mut self) -> Element<'_, Self::Message> {
         self.0.view()
     }
+
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
 }
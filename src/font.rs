@@ -0,0 +1,5 @@
+//! Describe and load the fonts of your text.
+pub use crate::runtime::font::{Descriptor, Family, Stretch, Style, Weight};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::font::{load, Error};
@@ -184,6 +184,7 @@ mod result;
 mod sandbox;
 
 pub mod executor;
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
 pub mod settings;
@@ -239,6 +240,7 @@ pub use sandbox::Sandbox;
 pub use settings::Settings;
 
 pub use runtime::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    futures, Align, Background, BorderRadius, Color, Command, Direction, Font,
+    HorizontalAlignment, Length, LineHeight, Point, Rectangle, Shadow, Size,
+    Subscription, Vector, VerticalAlignment,
 };
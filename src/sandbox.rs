@@ -1,5 +1,6 @@
 use crate::{
-    Application, Color, Command, Element, Error, Settings, Subscription,
+    Application, Color, Command, Direction, Element, Error, Settings,
+    Subscription,
 };
 
 /// A sandboxed [`Application`].
@@ -131,6 +132,17 @@ pub trait Sandbox {
         1.0
     }
 
+    /// Returns the [`Direction`] in which the contents of the [`Sandbox`]
+    /// flow.
+    ///
+    /// Every widget inherits this [`Direction`], unless it is overridden by
+    /// one of its ancestors.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
     /// Runs the [`Sandbox`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -182,4 +194,8 @@ where
     fn scale_factor(&self) -> f64 {
         T::scale_factor(self)
     }
+
+    fn direction(&self) -> Direction {
+        T::direction(self)
+    }
 }
@@ -16,8 +16,10 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, Column, Row, Space, Text,
+        blur, button, checkbox, code_view, constrained, container, opacity,
+        pane_grid, pick_list, progress_bar, radio, responsive, rich_text, rule,
+        scrollable, selectable_text, slider, text, text_input, Column,
+        Constrained, Responsive, Row, Space, Text,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -27,6 +29,13 @@ mod platform {
     )]
     pub use crate::renderer::widget::canvas;
 
+    #[cfg(any(feature = "markdown", feature = "glow_markdown"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "markdown", feature = "glow_markdown")))
+    )]
+    pub use crate::renderer::widget::markdown;
+
     #[cfg(any(feature = "qr_code", feature = "glow_qr_code"))]
     #[cfg_attr(
         docsrs,
@@ -34,14 +43,21 @@ mod platform {
     )]
     pub use crate::renderer::widget::qr_code;
 
-    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    #[cfg(not(feature = "glow"))]
+    #[cfg_attr(docsrs, doc(cfg(not(feature = "glow"))))]
+    pub use crate::renderer::widget::shader;
+
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "image", feature = "glow_image")))
+    )]
     pub mod image {
         //! Display images in your user interface.
         pub use crate::runtime::image::viewer;
         pub use crate::runtime::image::{Handle, Image, Viewer};
     }
 
-    #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "svg", feature = "glow_svg"))))]
     pub mod svg {
         //! Display vector graphics in your user interface.
         pub use crate::runtime::svg::{Handle, Svg};
@@ -49,16 +65,26 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
+        blur::Blur, button::Button, checkbox::Checkbox, code_view::CodeView,
+        container::Container, image::Image, opacity::Opacity,
         pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput,
+        radio::Radio, rich_text::RichText, rule::Rule, scrollable::Scrollable,
+        selectable_text::SelectableText, slider::Slider, svg::Svg,
+        text_input::TextInput,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
     #[doc(no_inline)]
     pub use canvas::Canvas;
 
+    #[cfg(any(feature = "markdown", feature = "glow_markdown"))]
+    #[doc(no_inline)]
+    pub use markdown::Markdown;
+
+    #[cfg(not(feature = "glow"))]
+    #[doc(no_inline)]
+    pub use shader::Shader;
+
     #[cfg(any(feature = "qr_code", feature = "glow_qr_code"))]
     #[doc(no_inline)]
     pub use qr_code::QRCode;
@@ -7,3 +7,6 @@ pub mod icon;
 pub use icon::Icon;
 pub use mode::Mode;
 pub use settings::Settings;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::window::{screenshot, Screenshot};
@@ -1,15 +1,16 @@
 //! Allow your users to perform actions by pressing a button.
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, BorderRadius, Color, Shadow, Vector};
 
 /// The appearance of a button.
 #[derive(Debug)]
 pub struct Style {
     pub shadow_offset: Vector,
     pub background: Option<Background>,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
     pub text_color: Color,
+    pub shadow: Option<Shadow>,
 }
 
 impl std::default::Default for Style {
@@ -17,10 +18,11 @@ impl std::default::Default for Style {
         Self {
             shadow_offset: Vector::default(),
             background: None,
-            border_radius: 0.0,
+            border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             text_color: Color::BLACK,
+            shadow: None,
         }
     }
 }
@@ -60,6 +62,13 @@ pub trait StyleSheet {
                 a: active.text_color.a * 0.5,
                 ..active.text_color
             },
+            shadow: active.shadow.map(|shadow| Shadow {
+                color: Color {
+                    a: shadow.color.a * 0.5,
+                    ..shadow.color
+                },
+                ..shadow
+            }),
             ..active
         }
     }
@@ -72,10 +81,11 @@ impl StyleSheet for Default {
         Style {
             shadow_offset: Vector::new(0.0, 0.0),
             background: Some(Background::Color([0.87, 0.87, 0.87].into())),
-            border_radius: 2.0,
+            border_radius: 2.0.into(),
             border_width: 1.0,
             border_color: [0.7, 0.7, 0.7].into(),
             text_color: Color::BLACK,
+            shadow: None,
         }
     }
 }
@@ -1,12 +1,12 @@
 //! Show toggle controls using checkboxes.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a checkbox.
 #[derive(Debug)]
 pub struct Style {
     pub background: Background,
     pub checkmark_color: Color,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
 }
@@ -25,7 +25,7 @@ impl StyleSheet for Default {
         Style {
             background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
             checkmark_color: Color::from_rgb(0.3, 0.3, 0.3),
-            border_radius: 5.0,
+            border_radius: 5.0.into(),
             border_width: 1.0,
             border_color: Color::from_rgb(0.6, 0.6, 0.6),
         }
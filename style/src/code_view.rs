@@ -0,0 +1,93 @@
+//! Display and edit code with line numbers and syntax highlighting.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a code view.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: BorderRadius,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub gutter_background: Option<Background>,
+    pub line_number_color: Color,
+    pub selection_color: Color,
+    pub caret_color: Color,
+    pub comment_color: Color,
+    pub keyword_color: Color,
+    pub literal_color: Color,
+    pub number_color: Color,
+    pub string_color: Color,
+    pub type_color: Color,
+    pub function_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::from_rgb(0.2, 0.2, 0.2),
+            gutter_background: Some(Background::Color(Color::from_rgb(
+                0.96, 0.96, 0.96,
+            ))),
+            line_number_color: Color::from_rgb(0.6, 0.6, 0.6),
+            selection_color: Color::from_rgb(0.8, 0.8, 1.0),
+            caret_color: Color::from_rgb(0.2, 0.2, 0.2),
+            comment_color: Color::from_rgb(0.5, 0.55, 0.5),
+            keyword_color: Color::from_rgb(0.6, 0.15, 0.6),
+            literal_color: Color::from_rgb(0.0, 0.4, 0.6),
+            number_color: Color::from_rgb(0.0, 0.45, 0.35),
+            string_color: Color::from_rgb(0.65, 0.3, 0.1),
+            type_color: Color::from_rgb(0.15, 0.35, 0.65),
+            function_color: Color::from_rgb(0.45, 0.35, 0.05),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a code view.
+pub trait StyleSheet {
+    /// Produces the style of an active code view.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a focused code view.
+    fn focused(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            ..Style::default()
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            ..self.active()
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,14 +1,15 @@
 //! Decorate content and apply alignment.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderRadius, Color, Shadow};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
     pub text_color: Option<Color>,
     pub background: Option<Background>,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
+    pub shadow: Option<Shadow>,
 }
 
 impl std::default::Default for Style {
@@ -16,9 +17,10 @@ impl std::default::Default for Style {
         Self {
             text_color: None,
             background: None,
-            border_radius: 0.0,
+            border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: None,
         }
     }
 }
@@ -36,9 +38,10 @@ impl StyleSheet for Default {
         Style {
             text_color: None,
             background: None,
-            border_radius: 0.0,
+            border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            shadow: None,
         }
     }
 }
@@ -4,11 +4,13 @@
 //! widgets.
 //!
 //! ![The foundations of the Iced ecosystem](https://github.com/hecrj/iced/blob/0525d76ff94e828b7b21634fa94a747022001c83/docs/graphs/foundations.png?raw=true)
-pub use iced_core::{Background, Color};
+pub use iced_core::{Background, BorderRadius, Color, Shadow};
 
 pub mod button;
 pub mod checkbox;
+pub mod code_view;
 pub mod container;
+pub mod markdown;
 pub mod menu;
 pub mod pane_grid;
 pub mod pick_list;
@@ -16,5 +18,6 @@ pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod text_input;
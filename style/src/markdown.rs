@@ -0,0 +1,82 @@
+//! Display documents written in Markdown.
+use iced_core::font::{self, Descriptor, Family, Weight};
+use iced_core::{Background, BorderRadius, Color, Font};
+
+/// The appearance of a Markdown document.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_size: u16,
+    pub heading_sizes: [u16; 6],
+    pub text_color: Option<Color>,
+    pub font: Font,
+    pub heading_font: Font,
+    pub strong_font: Font,
+    pub emphasis_font: Font,
+    pub code_font: Font,
+    pub code_size: u16,
+    pub code_color: Option<Color>,
+    pub code_background: Background,
+    pub code_border_radius: BorderRadius,
+    pub link_color: Color,
+    pub quote_color: Option<Color>,
+    pub quote_border_color: Color,
+    pub quote_border_width: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        let bold = Descriptor::new(Family::SansSerif).weight(Weight::Bold);
+
+        Self {
+            text_size: 20,
+            heading_sizes: [32, 28, 24, 22, 20, 20],
+            text_color: None,
+            font: Font::from(Family::SansSerif),
+            heading_font: Font::from(bold),
+            strong_font: Font::from(bold),
+            emphasis_font: Font::from(
+                Descriptor::new(Family::SansSerif).style(font::Style::Italic),
+            ),
+            code_font: Font::from(Family::Monospace),
+            code_size: 18,
+            code_color: None,
+            code_background: Background::Color(Color::from_rgb(
+                0.95, 0.95, 0.95,
+            )),
+            code_border_radius: 4.0.into(),
+            link_color: Color::from_rgb(0.2, 0.4, 0.8),
+            quote_color: Some(Color::from_rgb(0.4, 0.4, 0.4)),
+            quote_border_color: Color::from_rgb(0.8, 0.8, 0.8),
+            quote_border_width: 4.0,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a Markdown document.
+pub trait StyleSheet {
+    /// Produces the style of a Markdown document.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,4 +1,4 @@
-use iced_core::{Background, Color};
+use iced_core::{Background, Color, Shadow};
 
 /// The appearance of a menu.
 #[derive(Debug, Clone, Copy)]
@@ -9,6 +9,7 @@ pub struct Style {
     pub border_color: Color,
     pub selected_text_color: Color,
     pub selected_background: Background,
+    pub shadow: Option<Shadow>,
 }
 
 impl std::default::Default for Style {
@@ -20,6 +21,7 @@ impl std::default::Default for Style {
             border_color: [0.7, 0.7, 0.7].into(),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+            shadow: None,
         }
     }
 }
@@ -1,12 +1,12 @@
 use crate::menu;
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a pick list.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
     pub text_color: Color,
     pub background: Background,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
     pub icon_size: f32,
@@ -17,7 +17,7 @@ impl std::default::Default for Style {
         Self {
             text_color: Color::BLACK,
             background: Background::Color([0.87, 0.87, 0.87].into()),
-            border_radius: 0.0,
+            border_radius: 0.0.into(),
             border_width: 1.0,
             border_color: [0.7, 0.7, 0.7].into(),
             icon_size: 0.7,
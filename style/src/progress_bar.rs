@@ -1,12 +1,12 @@
 //! Provide progress feedback to your users.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a progress bar.
 #[derive(Debug)]
 pub struct Style {
     pub background: Background,
     pub bar: Background,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
 }
 
 /// A set of rules that dictate the style of a progress bar.
@@ -21,7 +21,7 @@ impl StyleSheet for Default {
         Style {
             background: Background::Color(Color::from_rgb(0.6, 0.6, 0.6)),
             bar: Background::Color(Color::from_rgb(0.3, 0.9, 0.3)),
-            border_radius: 5.0,
+            border_radius: 5.0.into(),
         }
     }
 }
@@ -1,11 +1,11 @@
 //! Navigate an endless amount of content with a scrollbar.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Scrollbar {
     pub background: Option<Background>,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
     pub scroller: Scroller,
@@ -15,7 +15,7 @@ pub struct Scrollbar {
 #[derive(Debug, Clone, Copy)]
 pub struct Scroller {
     pub color: Color,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
 }
@@ -40,12 +40,12 @@ impl StyleSheet for Default {
     fn active(&self) -> Scrollbar {
         Scrollbar {
             background: None,
-            border_radius: 5.0,
+            border_radius: 5.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             scroller: Scroller {
                 color: [0.0, 0.0, 0.0, 0.7].into(),
-                border_radius: 5.0,
+                border_radius: 5.0.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
             },
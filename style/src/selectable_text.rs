@@ -0,0 +1,31 @@
+//! Display read-only text that can be selected and copied.
+use iced_core::Color;
+
+/// A set of rules that dictate the style of a selectable text.
+pub trait StyleSheet {
+    /// Produces the color of the selection highlight.
+    fn selection_color(&self) -> Color;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn selection_color(&self) -> Color {
+        Color::from_rgb(0.8, 0.8, 1.0)
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
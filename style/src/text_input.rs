@@ -1,11 +1,11 @@
 //! Display fields that can be filled with text.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a text input.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
     pub background: Background,
-    pub border_radius: f32,
+    pub border_radius: BorderRadius,
     pub border_width: f32,
     pub border_color: Color,
 }
@@ -14,7 +14,7 @@ impl std::default::Default for Style {
     fn default() -> Self {
         Self {
             background: Background::Color(Color::WHITE),
-            border_radius: 0.0,
+            border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
         }
@@ -47,7 +47,7 @@ impl StyleSheet for Default {
     fn active(&self) -> Style {
         Style {
             background: Background::Color(Color::WHITE),
-            border_radius: 5.0,
+            border_radius: 5.0.into(),
             border_width: 1.0,
             border_color: Color::from_rgb(0.7, 0.7, 0.7),
         }
@@ -1,5 +1,9 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::font::{self, Family, Font};
+use crate::{
+    bumpalo, Align, Background, BorderRadius, Color, Direction, Length,
+    LineHeight, Shadow,
+};
 
 use std::collections::BTreeMap;
 
@@ -150,6 +154,15 @@ pub fn min_length(units: u32) -> String {
     }
 }
 
+/// Returns the style value for the given [`LineHeight`].
+pub fn line_height(line_height: Option<LineHeight>) -> String {
+    match line_height {
+        None => String::from("normal"),
+        Some(LineHeight::Relative(factor)) => format!("{}", factor),
+        Some(LineHeight::Absolute(height)) => format!("{}px", height),
+    }
+}
+
 /// Returns the style value for the given [`Color`].
 pub fn color(Color { r, g, b, a }: Color) -> String {
     format!("rgba({}, {}, {}, {})", 255.0 * r, 255.0 * g, 255.0 * b, a)
@@ -162,11 +175,88 @@ pub fn background(background: Background) -> String {
     }
 }
 
+/// Returns the `border-radius` value for the given [`BorderRadius`].
+pub fn border_radius(border_radius: BorderRadius) -> String {
+    format!(
+        "{}px {}px {}px {}px",
+        border_radius.top_left,
+        border_radius.top_right,
+        border_radius.bottom_right,
+        border_radius.bottom_left
+    )
+}
+
+/// Returns the `box-shadow` value for the given [`Shadow`].
+pub fn shadow(shadow: Shadow) -> String {
+    format!(
+        "{}px {}px {}px {}px {}",
+        shadow.offset.x,
+        shadow.offset.y,
+        shadow.blur_radius,
+        shadow.spread,
+        color(shadow.color)
+    )
+}
+
+/// Returns the `dir` attribute value for the given [`Direction`].
+pub fn direction(direction: Direction) -> &'static str {
+    match direction {
+        Direction::LeftToRight => "ltr",
+        Direction::RightToLeft => "rtl",
+    }
+}
+
+/// Returns the style declarations for the given [`Font`].
+pub fn font(font: Font) -> String {
+    match font {
+        Font::Default => String::from("font-family: inherit"),
+        Font::External { name, .. } => format!("font-family: {}", name),
+        Font::Descriptor(descriptor) => {
+            let family = match descriptor.family {
+                Family::Name(name) => format!("\"{}\"", name),
+                Family::Serif => String::from("serif"),
+                Family::SansSerif => String::from("sans-serif"),
+                Family::Cursive => String::from("cursive"),
+                Family::Fantasy => String::from("fantasy"),
+                Family::Monospace => String::from("monospace"),
+            };
+
+            let style = match descriptor.style {
+                font::Style::Normal => "normal",
+                font::Style::Italic => "italic",
+                font::Style::Oblique => "oblique",
+            };
+
+            format!(
+                "font-family: {}; font-weight: {}; font-style: {}; \
+                font-stretch: {}%",
+                family,
+                descriptor.weight.value(),
+                style,
+                descriptor.stretch.ratio() * 100.0
+            )
+        }
+    }
+}
+
 /// Returns the style value for the given [`Align`].
 pub fn align(align: Align) -> &'static str {
     match align {
         Align::Start => "flex-start",
         Align::Center => "center",
         Align::End => "flex-end",
+        Align::Baseline => "baseline",
+    }
+}
+
+/// Returns the style value for the given [`Align`] along the main axis of a
+/// flex container, like `justify-content`.
+///
+/// Baselines only exist in the cross axis, so [`Align::Baseline`] is aligned
+/// to the start.
+pub fn justify(align: Align) -> &'static str {
+    match align {
+        Align::Baseline => "flex-start",
+        align => self::align(align),
     }
 }
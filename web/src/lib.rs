@@ -72,8 +72,9 @@ pub use dodrio;
 pub use element::Element;
 pub use hasher::Hasher;
 pub use iced_core::{
-    keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Vector, VerticalAlignment,
+    font, keyboard, mouse, Align, Background, BorderRadius, Color, Direction,
+    Font, HorizontalAlignment, Length, LineHeight, Point, Rectangle, Shadow,
+    Size, Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
 pub use subscription::Subscription;
@@ -145,6 +146,14 @@ pub trait Application {
         Subscription::none()
     }
 
+    /// Returns the [`Direction`] in which the contents of the [`Application`]
+    /// flow.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
     /// Runs the [`Application`].
     fn run(flags: Self::Flags)
     where
@@ -224,6 +233,7 @@ where
         use dodrio::builder::*;
 
         let mut ui = self.application.borrow_mut();
+        let direction = ui.direction();
         let element = ui.view();
         let mut css = Css::new();
 
@@ -231,6 +241,7 @@ where
 
         div(context.bump)
             .attr("style", "width: 100%; height: 100%")
+            .attr("dir", css::direction(direction))
             .children(vec![css.node(context.bump), node])
             .finish()
     }
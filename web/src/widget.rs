@@ -17,6 +17,7 @@ use dodrio::bumpalo;
 
 pub mod button;
 pub mod checkbox;
+pub mod constrained;
 pub mod container;
 pub mod image;
 pub mod progress_bar;
@@ -43,6 +44,7 @@ pub use text_input::TextInput;
 
 pub use checkbox::Checkbox;
 pub use column::Column;
+pub use constrained::Constrained;
 pub use container::Container;
 pub use image::Image;
 pub use progress_bar::ProgressBar;
@@ -142,13 +142,17 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "background: {}; border-radius: {}px; width:{}; \
-                    min-width: {}; color: {}",
+                    "background: {}; border-radius: {}; width:{}; \
+                    min-width: {}; color: {}; box-shadow: {}",
                     background,
-                    style.border_radius,
+                    css::border_radius(style.border_radius),
                     css::length(self.width),
                     css::min_length(self.min_width),
-                    css::color(style.text_color)
+                    css::color(style.text_color),
+                    style
+                        .shadow
+                        .map(css::shadow)
+                        .unwrap_or(String::from("none"))
                 )
                 .into_bump_str(),
             )
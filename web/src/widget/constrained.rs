@@ -0,0 +1,108 @@
+//! Constrain the size of some content.
+use crate::{bumpalo, css, Bus, Css, Element, Widget};
+
+/// An element constraining the size of its content.
+///
+/// It can be used to apply minimum and maximum dimensions to any element,
+/// or to keep it at a specific aspect ratio.
+#[allow(missing_debug_implementations)]
+pub struct Constrained<'a, Message> {
+    min_width: u32,
+    max_width: u32,
+    min_height: u32,
+    max_height: u32,
+    aspect_ratio: Option<f32>,
+    content: Element<'a, Message>,
+}
+
+impl<'a, Message> Constrained<'a, Message> {
+    /// Creates a [`Constrained`] element with the given content and no
+    /// constraints.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message>>,
+    {
+        Constrained {
+            min_width: 0,
+            max_width: u32::MAX,
+            min_height: 0,
+            max_height: u32::MAX,
+            aspect_ratio: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the minimum width of the [`Constrained`] content.
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the maximum width of the [`Constrained`] content.
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Constrained`] content.
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Sets the maximum height of the [`Constrained`] content.
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Constrained`] content, as the result of
+    /// dividing its width by its height.
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Constrained<'a, Message> {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let aspect_ratio = self
+            .aspect_ratio
+            .map(|aspect_ratio| aspect_ratio.to_string())
+            .unwrap_or(String::from("auto"));
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: flex; min-width: {}; max-width: {}; \
+                    min-height: {}; max-height: {}; aspect-ratio: {}",
+                    css::min_length(self.min_width),
+                    css::max_length(self.max_width),
+                    css::min_length(self.min_height),
+                    css::max_length(self.max_height),
+                    aspect_ratio
+                )
+                .into_bump_str(),
+            )
+            .children(vec![self.content.node(bump, bus, style_sheet)])
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Constrained<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static,
+{
+    fn from(constrained: Constrained<'a, Message>) -> Element<'a, Message> {
+        Element::new(constrained)
+    }
+}
@@ -1,5 +1,7 @@
 //! Decorate content and apply alignment.
-use crate::{bumpalo, css, Align, Bus, Css, Element, Length, Widget};
+use crate::{
+    bumpalo, css, Align, Bus, Css, Direction, Element, Length, Widget,
+};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -15,6 +17,8 @@ pub struct Container<'a, Message> {
     max_height: u32,
     horizontal_alignment: Align,
     vertical_alignment: Align,
+    direction: Option<Direction>,
+    clip: bool,
     style_sheet: Box<dyn StyleSheet>,
     content: Element<'a, Message>,
 }
@@ -35,6 +39,8 @@ impl<'a, Message> Container<'a, Message> {
             max_height: u32::MAX,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
+            direction: None,
+            clip: false,
             style_sheet: Default::default(),
             content: content.into(),
         }
@@ -84,6 +90,23 @@ impl<'a, Message> Container<'a, Message> {
         self
     }
 
+    /// Sets the [`Direction`] of the contents of the [`Container`].
+    ///
+    /// By default, a [`Container`] inherits the [`Direction`] of its parent.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets whether the contents of the [`Container`] should be clipped to
+    /// its bounds.
+    ///
+    /// The clipping region follows the rounded corners of the border, if any.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
         self.style_sheet = style.into();
@@ -110,7 +133,7 @@ where
 
         let style = self.style_sheet.style();
 
-        let node = div(bump)
+        let mut node = div(bump)
             .attr(
                 "class",
                 bumpalo::format!(in bump, "{} {}", column_class, padding_class).into_bump_str(),
@@ -119,22 +142,28 @@ where
                 "style",
                 bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}px",
+                    "width: {}; height: {}; max-width: {}; align-items: {}; justify-content: {}; background: {}; color: {}; border-width: {}px; border-color: {}; border-radius: {}; box-shadow: {}; overflow: {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
                     css::align(self.horizontal_alignment),
-                    css::align(self.vertical_alignment),
+                    css::justify(self.vertical_alignment),
                     style.background.map(css::background).unwrap_or(String::from("initial")),
                     style.text_color.map(css::color).unwrap_or(String::from("inherit")),
                     style.border_width,
                     css::color(style.border_color),
-                    style.border_radius
+                    css::border_radius(style.border_radius),
+                    style.shadow.map(css::shadow).unwrap_or(String::from("none")),
+                    if self.clip { "hidden" } else { "visible" }
                 )
                 .into_bump_str(),
             )
             .children(vec![self.content.node(bump, bus, style_sheet)]);
 
+        if let Some(direction) = self.direction {
+            node = node.attr("dir", css::direction(direction));
+        }
+
         // TODO: Complete styling
 
         node.finish()
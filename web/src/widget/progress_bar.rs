@@ -93,11 +93,11 @@ impl<Message> Widget<Message> for ProgressBar {
             "style",
             bumpalo::format!(
                 in bump,
-                "width: {}; height: {}; background: {}; border-radius: {}px; overflow: hidden;",
+                "width: {}; height: {}; background: {}; border-radius: {}; overflow: hidden;",
                 css::length(self.width),
                 css::length(self.height.unwrap_or(Length::Units(30))),
                 css::background(style.background),
-                style.border_radius
+                css::border_radius(style.border_radius)
             )
             .into_bump_str(),
         ).children(vec![bar]);
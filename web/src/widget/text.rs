@@ -1,6 +1,6 @@
 use crate::{
     css, Bus, Color, Css, Element, Font, HorizontalAlignment, Length,
-    VerticalAlignment, Widget,
+    LineHeight, VerticalAlignment, Widget,
 };
 use dodrio::bumpalo;
 
@@ -24,6 +24,8 @@ pub struct Text {
     height: Length,
     horizontal_alignment: HorizontalAlignment,
     vertical_alignment: VerticalAlignment,
+    line_height: Option<LineHeight>,
+    letter_spacing: f32,
 }
 
 impl Text {
@@ -38,6 +40,8 @@ impl Text {
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            line_height: None,
+            letter_spacing: 0.0,
         }
     }
 
@@ -54,8 +58,8 @@ impl Text {
     }
 
     /// Sets the [`Font`] of the [`Text`].
-    pub fn font(mut self, font: Font) -> Self {
-        self.font = font;
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
         self
     }
 
@@ -85,6 +89,21 @@ impl Text {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// A plain number is a factor of the size of the [`Text`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+
+    /// Sets the extra space between the characters of the [`Text`], in
+    /// pixels.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
 }
 
 impl<'a, Message> Widget<Message> for Text {
@@ -111,24 +130,23 @@ impl<'a, Message> Widget<Message> for Text {
         let height = css::length(self.height);
 
         let text_align = match self.horizontal_alignment {
-            HorizontalAlignment::Left => "left",
+            HorizontalAlignment::Left => "start",
             HorizontalAlignment::Center => "center",
-            HorizontalAlignment::Right => "right",
+            HorizontalAlignment::Right => "end",
         };
 
         let style = bumpalo::format!(
             in bump,
             "width: {}; height: {}; font-size: {}px; color: {}; \
-            text-align: {}; font-family: {}",
+            text-align: {}; line-height: {}; letter-spacing: {}px; {}",
             width,
             height,
             self.size.unwrap_or(20),
             color,
             text_align,
-            match self.font {
-                Font::Default => "inherit",
-                Font::External { name, .. } => name,
-            }
+            css::line_height(self.line_height),
+            self.letter_spacing,
+            css::font(self.font)
         );
 
         // TODO: Complete styling
@@ -166,14 +166,14 @@ where
                     in bump,
                     "width: {}; max-width: {}; font-size: {}px; \
                     background: {}; border-width: {}px; border-color: {}; \
-                    border-radius: {}px; color: {}",
+                    border-radius: {}; color: {}",
                     css::length(self.width),
                     css::max_length(self.max_width),
                     self.size.unwrap_or(20),
                     css::background(style.background),
                     style.border_width,
                     css::color(style.border_color),
-                    style.border_radius,
+                    css::border_radius(style.border_radius),
                     css::color(self.style_sheet.value_color())
                 )
                 .into_bump_str(),
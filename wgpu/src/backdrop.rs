@@ -0,0 +1,405 @@
+use iced_graphics::layer;
+use iced_native::Rectangle;
+
+use bytemuck::{Pod, Zeroable};
+use std::mem;
+
+/// A pipeline that blurs a region of an offscreen target in place.
+///
+/// It runs a separable Gaussian blur in two passes: a horizontal one into an
+/// intermediate texture and a vertical one back into the target.
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    horizontal: Pass,
+    vertical: Pass,
+    texture_layout: wgpu::BindGroupLayout,
+    intermediate: Option<Intermediate>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Pipeline {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::backdrop uniforms layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler { comparison: false },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::UniformBuffer {
+                            dynamic: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let horizontal = Pass::new(device, &constant_layout, &sampler);
+        let vertical = Pass::new(device, &constant_layout, &sampler);
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::backdrop texture layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::SampledTexture {
+                        dimension: wgpu::TextureViewDimension::D2,
+                        component_type: wgpu::TextureComponentType::Float,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::backdrop pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        let vs_module = device
+            .create_shader_module(wgpu::include_spirv!("shader/blit.vert.spv"));
+
+        let fs_module = device
+            .create_shader_module(wgpu::include_spirv!("shader/blur.frag.spv"));
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::backdrop pipeline"),
+                layout: Some(&layout),
+                vertex_stage: wgpu::ProgrammableStageDescriptor {
+                    module: &vs_module,
+                    entry_point: "main",
+                },
+                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                    module: &fs_module,
+                    entry_point: "main",
+                }),
+                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: wgpu::CullMode::None,
+                    ..Default::default()
+                }),
+                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                color_states: &[wgpu::ColorStateDescriptor {
+                    format,
+                    // The blurred texels replace the original ones
+                    color_blend: wgpu::BlendDescriptor::REPLACE,
+                    alpha_blend: wgpu::BlendDescriptor::REPLACE,
+                    write_mask: wgpu::ColorWrite::ALL,
+                }],
+                depth_stencil_state: None,
+                vertex_state: wgpu::VertexStateDescriptor {
+                    index_format: wgpu::IndexFormat::Uint16,
+                    vertex_buffers: &[],
+                },
+                sample_count: 1,
+                sample_mask: !0,
+                alpha_to_coverage_enabled: false,
+            });
+
+        Pipeline {
+            format,
+            pipeline,
+            horizontal,
+            vertical,
+            texture_layout,
+            intermediate: None,
+        }
+    }
+
+    /// Blurs the region of the given target described by the [`layer::Blur`],
+    /// in physical pixels.
+    ///
+    /// The target must be an offscreen texture that can be sampled.
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        blur: layer::Blur,
+        target: &wgpu::TextureView,
+        target_width: u32,
+        target_height: u32,
+    ) {
+        // Beyond three standard deviations, the weights are negligible
+        let taps = (blur.radius * 3.0).ceil();
+
+        let bounds = match blur.bounds.intersection(&Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: target_width as f32,
+            height: target_height as f32,
+        }) {
+            Some(bounds) if taps >= 1.0 => bounds.snap(),
+            _ => return,
+        };
+
+        // Snapping may round the region past the edges of the target
+        let bounds = Rectangle {
+            width: bounds.width.min(target_width - bounds.x),
+            height: bounds.height.min(target_height - bounds.y),
+            ..bounds
+        };
+
+        if bounds.width == 0 || bounds.height == 0 {
+            return;
+        }
+
+        let intermediate = match self.intermediate.take() {
+            Some(intermediate)
+                if intermediate.width == target_width
+                    && intermediate.height == target_height =>
+            {
+                intermediate
+            }
+            _ => Intermediate::new(
+                device,
+                self.format,
+                &self.texture_layout,
+                target_width,
+                target_height,
+            ),
+        };
+
+        self.horizontal.write(
+            device,
+            staging_belt,
+            encoder,
+            Uniforms {
+                step: [1.0 / target_width as f32, 0.0],
+                sigma: blur.radius,
+                taps,
+            },
+        );
+
+        self.vertical.write(
+            device,
+            staging_belt,
+            encoder,
+            Uniforms {
+                step: [0.0, 1.0 / target_height as f32],
+                sigma: blur.radius,
+                taps,
+            },
+        );
+
+        let target_bind_group =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_wgpu::backdrop target bind group"),
+                layout: &self.texture_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(target),
+                }],
+            });
+
+        // The vertical pass samples texels above and below the region, so
+        // the horizontal one needs to cover them too
+        let taps = taps as u32;
+        let top = bounds.y.saturating_sub(taps);
+        let bottom = (bounds.y + bounds.height + taps).min(target_height);
+
+        {
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    color_attachments: &[
+                        wgpu::RenderPassColorAttachmentDescriptor {
+                            attachment: &intermediate.view,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: true,
+                            },
+                        },
+                    ],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &self.horizontal.constants, &[]);
+            render_pass.set_bind_group(1, &target_bind_group, &[]);
+            render_pass.set_scissor_rect(
+                bounds.x,
+                top,
+                bounds.width,
+                bottom - top,
+            );
+            render_pass.draw(0..6, 0..1);
+        }
+
+        {
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    color_attachments: &[
+                        wgpu::RenderPassColorAttachmentDescriptor {
+                            attachment: target,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: true,
+                            },
+                        },
+                    ],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &self.vertical.constants, &[]);
+            render_pass.set_bind_group(1, &intermediate.bind_group, &[]);
+            render_pass.set_scissor_rect(
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height,
+            );
+            render_pass.draw(0..6, 0..1);
+        }
+
+        self.intermediate = Some(intermediate);
+    }
+}
+
+/// The uniforms of one of the passes of the blur.
+#[derive(Debug)]
+struct Pass {
+    constants: wgpu::BindGroup,
+    constants_buffer: wgpu::Buffer,
+}
+
+impl Pass {
+    fn new(
+        device: &wgpu::Device,
+        constant_layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+    ) -> Pass {
+        let constants_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::backdrop uniforms bind group"),
+            layout: constant_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Buffer(
+                        constants_buffer.slice(..),
+                    ),
+                },
+            ],
+        });
+
+        Pass {
+            constants,
+            constants_buffer,
+        }
+    }
+
+    fn write(
+        &self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        uniforms: Uniforms,
+    ) {
+        let mut constants_buffer = staging_belt.write_buffer(
+            encoder,
+            &self.constants_buffer,
+            0,
+            wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64).unwrap(),
+            device,
+        );
+
+        constants_buffer.copy_from_slice(bytemuck::bytes_of(&uniforms));
+    }
+}
+
+/// The texture holding the result of the horizontal pass.
+#[derive(Debug)]
+struct Intermediate {
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    width: u32,
+    height: u32,
+}
+
+impl Intermediate {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        texture_layout: &wgpu::BindGroupLayout,
+        width: u32,
+        height: u32,
+    ) -> Intermediate {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::backdrop intermediate texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::backdrop intermediate bind group"),
+            layout: texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Intermediate {
+            view,
+            bind_group,
+            width,
+            height,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    step: [f32; 2],
+    sigma: f32,
+    taps: f32,
+}
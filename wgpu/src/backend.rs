@@ -1,13 +1,21 @@
+use crate::backdrop;
+use crate::capture::Capture;
+use crate::compositor;
+use crate::glyph;
 use crate::quad;
-use crate::text;
 use crate::triangle;
+use crate::widget::shader;
 use crate::{Settings, Transformation};
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::layer::Layer;
-use iced_graphics::{Primitive, Viewport};
+use iced_graphics::layer::{Layer, Opacity};
+use iced_graphics::{Color, Primitive, Viewport};
 use iced_native::mouse;
-use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
+use iced_native::{
+    Direction, Font, HorizontalAlignment, Rectangle, Size, VerticalAlignment,
+};
+
+use std::borrow::Cow;
 
 #[cfg(any(feature = "image", feature = "svg"))]
 use crate::image;
@@ -19,21 +27,30 @@ use crate::image;
 #[derive(Debug)]
 pub struct Backend {
     quad_pipeline: quad::Pipeline,
-    text_pipeline: text::Pipeline,
+    compositor: compositor::Pipeline,
+    backdrop: backdrop::Pipeline,
+    text_pipeline: glyph::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    shader_storage: shader::Storage,
 
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
 
+    format: wgpu::TextureFormat,
     default_text_size: u16,
 }
 
 impl Backend {
     /// Creates a new [`Backend`].
     pub fn new(device: &wgpu::Device, settings: Settings) -> Self {
-        let text_pipeline =
-            text::Pipeline::new(device, settings.format, settings.default_font);
+        let text_pipeline = glyph::Pipeline::new(
+            device,
+            settings.format,
+            settings.default_font,
+        );
         let quad_pipeline = quad::Pipeline::new(device, settings.format);
+        let compositor = compositor::Pipeline::new(device, settings.format);
+        let backdrop = backdrop::Pipeline::new(device, settings.format);
         let triangle_pipeline = triangle::Pipeline::new(
             device,
             settings.format,
@@ -45,12 +62,16 @@ impl Backend {
 
         Self {
             quad_pipeline,
+            compositor,
+            backdrop,
             text_pipeline,
             triangle_pipeline,
+            shader_storage: shader::Storage::default(),
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
 
+            format: settings.format,
             default_text_size: settings.default_text_size,
         }
     }
@@ -62,6 +83,7 @@ impl Backend {
     pub fn draw<T: AsRef<str>>(
         &mut self,
         device: &wgpu::Device,
+        queue: &wgpu::Queue,
         staging_belt: &mut wgpu::util::StagingBelt,
         encoder: &mut wgpu::CommandEncoder,
         frame: &wgpu::TextureView,
@@ -71,6 +93,191 @@ impl Backend {
     ) -> mouse::Interaction {
         log::debug!("Drawing");
 
+        self.render(
+            device,
+            queue,
+            staging_belt,
+            encoder,
+            frame,
+            viewport,
+            primitive,
+            overlay_text,
+        );
+
+        *mouse_interaction
+    }
+
+    /// Draws the provided output primitive like [`Backend::draw`], but into
+    /// an offscreen target that is then blitted to the frame.
+    ///
+    /// The frame and the target are cleared, with transparency and the given
+    /// background color respectively. The overlay text is only drawn to the
+    /// frame.
+    ///
+    /// The pixels of the target can be read with [`Capture::read`] once the
+    /// `encoder` has been submitted. Nothing is drawn and `None` is returned if
+    /// the texture format of the [`Backend`] cannot be captured.
+    pub fn capture<T: AsRef<str>>(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Color,
+        (primitive, mouse_interaction): &(Primitive, mouse::Interaction),
+        overlay_text: &[T],
+    ) -> Option<(mouse::Interaction, Capture)> {
+        log::debug!("Capturing frame");
+
+        let swap_red_and_blue = Capture::swaps_red_and_blue(self.format)?;
+        let Size { width, height } = viewport.physical_size();
+
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let target = self.compositor.begin(device, encoder, width, height);
+
+        clear(encoder, frame, Color::TRANSPARENT);
+        clear(encoder, &target.view, background_color);
+
+        self.render(
+            device,
+            queue,
+            staging_belt,
+            encoder,
+            &target.view,
+            viewport,
+            primitive,
+            &[] as &[&str],
+        );
+
+        let capture = Capture::new(
+            device,
+            encoder,
+            &target.texture,
+            width,
+            height,
+            swap_red_and_blue,
+        );
+
+        self.compositor.composite(
+            device,
+            staging_belt,
+            encoder,
+            target,
+            1.0,
+            frame,
+        );
+
+        self.render(
+            device,
+            queue,
+            staging_belt,
+            encoder,
+            frame,
+            viewport,
+            &Primitive::None,
+            overlay_text,
+        );
+
+        Some((*mouse_interaction, capture))
+    }
+
+    /// Renders the provided primitive at the given [`Viewport`] into an
+    /// offscreen texture and reads its pixels back.
+    ///
+    /// The pixels are returned as an RGBA buffer, row by row from the top-left
+    /// corner. Its dimensions are the physical size of the [`Viewport`].
+    ///
+    /// No surface is needed, so the `device` can come from any adapter,
+    /// including software ones. Only the 8-bit RGBA and BGRA texture formats
+    /// can be read back; `None` is returned for any other format of the
+    /// [`Backend`].
+    ///
+    /// This call blocks until the GPU has finished rendering.
+    pub fn render_offscreen(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        viewport: &Viewport,
+        background_color: Color,
+        primitive: &Primitive,
+    ) -> Option<Vec<u8>> {
+        log::debug!("Rendering offscreen");
+
+        let swap_red_and_blue = Capture::swaps_red_and_blue(self.format)?;
+        let Size { width, height } = viewport.physical_size();
+
+        if width == 0 || height == 0 {
+            return Some(Vec::new());
+        }
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu offscreen texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu offscreen encoder"),
+            });
+
+        clear(&mut encoder, &view, background_color);
+
+        let mut staging_belt = wgpu::util::StagingBelt::new(10 * 1024);
+
+        self.render(
+            device,
+            queue,
+            &mut staging_belt,
+            &mut encoder,
+            &view,
+            viewport,
+            primitive,
+            &[] as &[&str],
+        );
+
+        let capture = Capture::new(
+            device,
+            &mut encoder,
+            &texture,
+            width,
+            height,
+            swap_red_and_blue,
+        );
+
+        staging_belt.finish();
+        queue.submit(Some(encoder.finish()));
+
+        Some(capture.read(device))
+    }
+
+    fn render<T: AsRef<str>>(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        viewport: &Viewport,
+        primitive: &Primitive,
+        overlay_text: &[T],
+    ) {
         let target_size = viewport.physical_size();
         let scale_factor = viewport.scale_factor() as f32;
         let transformation = viewport.projection();
@@ -78,29 +285,133 @@ impl Backend {
         let mut layers = Layer::generate(primitive, viewport);
         layers.push(Layer::overlay(overlay_text, viewport));
 
-        for layer in layers {
+        // Blurring samples what has been drawn so far, which is not possible
+        // with the frame. Therefore, frames with some blur are rendered
+        // offscreen first.
+        let scene = if layers.iter().any(|layer| layer.blur.is_some()) {
+            Some(self.compositor.begin(
+                device,
+                encoder,
+                target_size.width,
+                target_size.height,
+            ))
+        } else {
+            None
+        };
+
+        let output = scene.as_ref().map_or(frame, |scene| &scene.view);
+
+        // The layers of an opacity group are rendered offscreen first, so
+        // overlapping primitives do not show through each other. A nested
+        // group is composited into the target of its parent.
+        let mut groups: Vec<(Opacity, compositor::Target)> = Vec::new();
+
+        for layer in layers.iter() {
+            let innermost = match layer.opacity {
+                Some(opacity)
+                    if groups
+                        .iter()
+                        .any(|(group, _)| group.group == opacity.group) =>
+                {
+                    Some(opacity.group)
+                }
+                Some(opacity) => opacity.parent,
+                None => None,
+            };
+
+            // Groups that do not contain the layer have been fully drawn
+            while let Some(group) = groups.last().map(|(group, _)| group.group)
+            {
+                if Some(group) == innermost {
+                    break;
+                }
+
+                let (opacity, target) = groups.pop().unwrap();
+
+                self.compositor.composite(
+                    device,
+                    staging_belt,
+                    encoder,
+                    target,
+                    opacity.alpha,
+                    groups.last().map_or(output, |(_, parent)| &parent.view),
+                );
+            }
+
+            if let Some(opacity) = layer.opacity {
+                if innermost != Some(opacity.group) {
+                    let target = self.compositor.begin(
+                        device,
+                        encoder,
+                        target_size.width,
+                        target_size.height,
+                    );
+
+                    groups.push((opacity, target));
+                }
+            }
+
+            // The backdrop of a blur is the scene beneath its outermost
+            // opacity group, since an intermediate target only contains the
+            // contents of its own group
+            if let Some(blur) = layer.blur {
+                self.backdrop.draw(
+                    device,
+                    staging_belt,
+                    encoder,
+                    blur * scale_factor,
+                    output,
+                    target_size.width,
+                    target_size.height,
+                );
+            }
+
             self.flush(
                 device,
+                queue,
                 scale_factor,
                 transformation,
-                &layer,
+                layer,
                 staging_belt,
                 encoder,
-                &frame,
+                groups.last().map_or(output, |(_, target)| &target.view),
                 target_size.width,
                 target_size.height,
             );
         }
 
+        while let Some((opacity, target)) = groups.pop() {
+            self.compositor.composite(
+                device,
+                staging_belt,
+                encoder,
+                target,
+                opacity.alpha,
+                groups.last().map_or(output, |(_, parent)| &parent.view),
+            );
+        }
+
+        if let Some(scene) = scene {
+            self.compositor.composite(
+                device,
+                staging_belt,
+                encoder,
+                scene,
+                1.0,
+                frame,
+            );
+        }
+
+        self.compositor.trim();
+
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.trim_cache();
-
-        *mouse_interaction
     }
 
     fn flush(
         &mut self,
         device: &wgpu::Device,
+        queue: &wgpu::Queue,
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
@@ -111,6 +422,20 @@ impl Backend {
         target_height: u32,
     ) {
         let bounds = (layer.bounds * scale_factor).snap();
+        let mask = layer.mask.map(|mask| mask * scale_factor);
+
+        // The transformation of the layer is given in logical coordinates,
+        // but quads and text are positioned in physical ones
+        let transformation = if layer.transformation
+            == Transformation::identity()
+        {
+            transformation
+        } else {
+            transformation
+                * Transformation::scale(scale_factor, scale_factor)
+                * layer.transformation
+                * Transformation::scale(1.0 / scale_factor, 1.0 / scale_factor)
+        };
 
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
@@ -121,6 +446,7 @@ impl Backend {
                 transformation,
                 scale_factor,
                 bounds,
+                mask,
                 target,
             );
         }
@@ -138,6 +464,7 @@ impl Backend {
                 target_height,
                 scaled,
                 scale_factor,
+                mask,
                 &layer.meshes,
             );
         }
@@ -155,14 +482,22 @@ impl Backend {
                     &layer.images,
                     scaled,
                     bounds,
+                    mask,
                     target,
                     scale_factor,
                 );
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
+                let line_height = text
+                    .line_height
+                    .map(|height| height.to_absolute(text.size) * scale_factor);
+
+                let letter_spacing = text.letter_spacing * scale_factor;
+                let direction = text.direction;
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
@@ -192,39 +527,78 @@ impl Backend {
                             x: text.size * scale_factor,
                             y: text.size * scale_factor,
                         },
-                        font_id: self.text_pipeline.find_font(text.font),
+                        font_id: self
+                            .text_pipeline
+                            .cache()
+                            .find_font(text.font),
                         extra: wgpu_glyph::Extra {
                             color: text.color,
                             z: 0.0,
                         },
                     }],
                     layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            HorizontalAlignment::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            HorizontalAlignment::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            HorizontalAlignment::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
-                        })
-                        .v_align(match text.vertical_alignment {
-                            VerticalAlignment::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            VerticalAlignment::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            VerticalAlignment::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .h_align(horizontal_align(text.horizontal_alignment))
+                        .v_align(vertical_align(text.vertical_alignment)),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                self.text_pipeline.queue(
+                    text,
+                    line_height,
+                    letter_spacing,
+                    direction,
+                );
+            }
+
+            for text in layer.rich_text.iter() {
+                let line_height = text.line_height.and_then(|height| {
+                    text.spans.first().map(|span| {
+                        height.to_absolute(span.size) * scale_factor
+                    })
+                });
+
+                let letter_spacing = text.letter_spacing * scale_factor;
+                let direction = text.direction;
+
+                let text = wgpu_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .map(|span| wgpu_glyph::Text {
+                            text: span.content,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id: self
+                                .text_pipeline
+                                .cache()
+                                .find_font(span.font),
+                            extra: wgpu_glyph::Extra {
+                                color: span.color,
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
+                    layout: wgpu_glyph::Layout::default()
+                        .h_align(horizontal_align(text.horizontal_alignment))
+                        .v_align(vertical_align(text.vertical_alignment)),
+                };
+
+                self.text_pipeline.queue(
+                    text,
+                    line_height,
+                    letter_spacing,
+                    direction,
+                );
             }
 
             self.text_pipeline.draw_queued(
@@ -241,12 +615,88 @@ impl Backend {
                 },
             );
         }
+
+        if let Some(custom) = layer.custom {
+            if let Some(shader::Content(primitive)) =
+                custom.content.downcast_ref()
+            {
+                let bounds = custom.bounds * scale_factor;
+                let width = bounds.width.round() as u32;
+                let height = bounds.height.round() as u32;
+
+                // Snapping may round the clipping bounds past the edges of
+                // the target
+                let clip_bounds = (custom.clip_bounds * scale_factor).snap();
+                let clip_bounds = Rectangle {
+                    width: clip_bounds
+                        .width
+                        .min(target_width.saturating_sub(clip_bounds.x)),
+                    height: clip_bounds
+                        .height
+                        .min(target_height.saturating_sub(clip_bounds.y)),
+                    ..clip_bounds
+                };
+
+                if width == 0
+                    || height == 0
+                    || clip_bounds.width == 0
+                    || clip_bounds.height == 0
+                {
+                    return;
+                }
+
+                // The primitive is rendered offscreen, so it cannot draw
+                // outside of its bounds
+                let viewport = Viewport::with_physical_size(
+                    Size::new(width, height),
+                    f64::from(scale_factor),
+                );
+
+                let offscreen =
+                    self.compositor.begin(device, encoder, width, height);
+
+                primitive.render(
+                    device,
+                    queue,
+                    encoder,
+                    &mut self.shader_storage,
+                    &shader::Target {
+                        view: &offscreen.view,
+                        format: self.format,
+                        bounds: custom.bounds,
+                        viewport: &viewport,
+                    },
+                );
+
+                self.compositor.composite_at(
+                    device,
+                    staging_belt,
+                    encoder,
+                    offscreen,
+                    Rectangle {
+                        x: bounds.x.round(),
+                        y: bounds.y.round(),
+                        width: width as f32,
+                        height: height as f32,
+                    },
+                    clip_bounds,
+                    target,
+                );
+            }
+        }
     }
 }
 
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
-        self.text_pipeline.trim_measurement_cache()
+        self.text_pipeline.cache_mut().trim_measurement_cache()
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), iced_native::font::Error> {
+        self.text_pipeline.cache_mut().load_font(bytes)
     }
 }
 
@@ -265,8 +715,101 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline
+            .cache()
+            .measure(contents, size, font, bounds, spacing, direction)
+    }
+
+    fn baseline(
+        &self,
+        size: f32,
+        font: Font,
+        line_height: Option<iced_native::LineHeight>,
+    ) -> f32 {
+        self.text_pipeline.cache().baseline(size, font, line_height)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[backend::Span<'_>],
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline
+            .cache()
+            .measure_spans(spans, spacing, direction, bounds)
+    }
+
+    fn span_fragments(
+        &self,
+        spans: &[backend::Span<'_>],
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<backend::Fragment> {
+        self.text_pipeline.cache().span_fragments(
+            spans,
+            spacing,
+            direction,
+            bounds,
+            horizontal_align(horizontal_alignment),
+            vertical_align(vertical_alignment),
+        )
+    }
+
+    fn character_bounds(
+        &self,
+        span: backend::Span<'_>,
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        bounds: Size,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) -> Vec<(usize, iced_native::Rectangle)> {
+        self.text_pipeline.cache().character_bounds(
+            span,
+            spacing,
+            direction,
+            bounds,
+            horizontal_align(horizontal_alignment),
+            vertical_align(vertical_alignment),
+        )
+    }
+
+    fn line_breaks(
+        &self,
+        span: backend::Span<'_>,
+        spacing: iced_native::text::Spacing,
+        direction: Direction,
+        width: f32,
+    ) -> Vec<std::ops::Range<usize>> {
+        self.text_pipeline
+            .cache()
+            .line_breaks(span, spacing, direction, width)
+    }
+}
+
+fn horizontal_align(
+    alignment: HorizontalAlignment,
+) -> wgpu_glyph::HorizontalAlign {
+    match alignment {
+        HorizontalAlignment::Left => wgpu_glyph::HorizontalAlign::Left,
+        HorizontalAlignment::Center => wgpu_glyph::HorizontalAlign::Center,
+        HorizontalAlignment::Right => wgpu_glyph::HorizontalAlign::Right,
+    }
+}
+
+fn vertical_align(alignment: VerticalAlignment) -> wgpu_glyph::VerticalAlign {
+    match alignment {
+        VerticalAlignment::Top => wgpu_glyph::VerticalAlign::Top,
+        VerticalAlignment::Center => wgpu_glyph::VerticalAlign::Center,
+        VerticalAlignment::Bottom => wgpu_glyph::VerticalAlign::Bottom,
     }
 }
 
@@ -286,3 +829,30 @@ impl backend::Svg for Backend {
         self.image_pipeline.viewport_dimensions(handle)
     }
 }
+
+fn clear(
+    encoder: &mut wgpu::CommandEncoder,
+    target: &wgpu::TextureView,
+    color: Color,
+) {
+    let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+            attachment: target,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear({
+                    let [r, g, b, a] = color.into_linear();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                store: true,
+            },
+        }],
+        depth_stencil_attachment: None,
+    });
+}
@@ -0,0 +1,117 @@
+/// The pixels of a rendered texture, copied to a buffer that can be read back
+/// once the commands that render and copy them have been submitted.
+#[derive(Debug)]
+pub struct Capture {
+    buffer: wgpu::Buffer,
+    row_size: usize,
+    padded_row_size: usize,
+    height: u32,
+    swap_red_and_blue: bool,
+}
+
+impl Capture {
+    /// Returns whether the red and blue channels of a texture with the given
+    /// format need to be swapped to obtain RGBA pixels.
+    ///
+    /// Only the 8-bit RGBA and BGRA formats can be captured, `None` is
+    /// returned for any other format.
+    pub(crate) fn swaps_red_and_blue(
+        format: wgpu::TextureFormat,
+    ) -> Option<bool> {
+        match format {
+            wgpu::TextureFormat::Rgba8Unorm
+            | wgpu::TextureFormat::Rgba8UnormSrgb => Some(false),
+            wgpu::TextureFormat::Bgra8Unorm
+            | wgpu::TextureFormat::Bgra8UnormSrgb => Some(true),
+            format => {
+                log::error!("Unsupported capture format: {:?}", format);
+
+                None
+            }
+        }
+    }
+
+    /// Records the copy of the given texture into a new [`Capture`].
+    ///
+    /// The texture must have been created with [`wgpu::TextureUsage::COPY_SRC`].
+    pub(crate) fn new(
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        width: u32,
+        height: u32,
+        swap_red_and_blue: bool,
+    ) -> Capture {
+        // Every row of a texture copy needs to be aligned
+        let row_size = width as usize * 4;
+        let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as usize;
+        let padded_row_size =
+            row_size + (alignment - row_size % alignment) % alignment;
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu capture buffer"),
+            size: (padded_row_size * height as usize) as u64,
+            usage: wgpu::BufferUsage::MAP_READ | wgpu::BufferUsage::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::TextureCopyView {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+            },
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                layout: wgpu::TextureDataLayout {
+                    offset: 0,
+                    bytes_per_row: padded_row_size as u32,
+                    rows_per_image: height,
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth: 1,
+            },
+        );
+
+        Capture {
+            buffer,
+            row_size,
+            padded_row_size,
+            height,
+            swap_red_and_blue,
+        }
+    }
+
+    /// Reads the captured pixels back as an RGBA buffer, row by row from the
+    /// top-left corner.
+    ///
+    /// This call blocks until the GPU has finished the submitted work.
+    pub fn read(self, device: &wgpu::Device) -> Vec<u8> {
+        let slice = self.buffer.slice(..);
+        let mapping = slice.map_async(wgpu::MapMode::Read);
+
+        device.poll(wgpu::Maintain::Wait);
+
+        futures::executor::block_on(mapping).expect("Map capture buffer");
+
+        let mut pixels =
+            Vec::with_capacity(self.row_size * self.height as usize);
+
+        for row in slice.get_mapped_range().chunks(self.padded_row_size) {
+            pixels.extend_from_slice(&row[..self.row_size]);
+        }
+
+        self.buffer.unmap();
+
+        if self.swap_red_and_blue {
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+        }
+
+        pixels
+    }
+}
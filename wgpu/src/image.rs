@@ -1,14 +1,9 @@
-mod atlas;
-
-#[cfg(feature = "image")]
-mod raster;
-
-#[cfg(feature = "svg")]
-mod vector;
+mod texture;
 
 use crate::Transformation;
-use atlas::Atlas;
+use texture::Texture;
 
+use iced_graphics::atlas::{self, Atlas};
 use iced_graphics::layer;
 use iced_native::Rectangle;
 use std::cell::RefCell;
@@ -25,9 +20,9 @@ use iced_native::svg;
 #[derive(Debug)]
 pub struct Pipeline {
     #[cfg(feature = "image")]
-    raster_cache: RefCell<raster::Cache>,
+    raster_cache: RefCell<iced_graphics::raster::Cache>,
     #[cfg(feature = "svg")]
-    vector_cache: RefCell<vector::Cache>,
+    vector_cache: RefCell<iced_graphics::vector::Cache>,
 
     pipeline: wgpu::RenderPipeline,
     uniforms: wgpu::Buffer,
@@ -38,7 +33,8 @@ pub struct Pipeline {
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
-    texture_atlas: Atlas,
+    texture_atlas: Texture,
+    atlas: Atlas,
 }
 
 impl Pipeline {
@@ -61,7 +57,8 @@ impl Pipeline {
                 entries: &[
                     wgpu::BindGroupLayoutEntry {
                         binding: 0,
-                        visibility: wgpu::ShaderStage::VERTEX,
+                        visibility: wgpu::ShaderStage::VERTEX
+                            | wgpu::ShaderStage::FRAGMENT,
                         ty: wgpu::BindingType::UniformBuffer {
                             dynamic: false,
                             min_binding_size: wgpu::BufferSize::new(
@@ -238,7 +235,8 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let texture_atlas = Atlas::new(device);
+        let texture_atlas = Texture::new(device);
+        let atlas = Atlas::new();
 
         let texture = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("iced_wgpu::image texture atlas bind group"),
@@ -253,10 +251,10 @@ impl Pipeline {
 
         Pipeline {
             #[cfg(feature = "image")]
-            raster_cache: RefCell::new(raster::Cache::new()),
+            raster_cache: RefCell::new(iced_graphics::raster::Cache::new()),
 
             #[cfg(feature = "svg")]
-            vector_cache: RefCell::new(vector::Cache::new()),
+            vector_cache: RefCell::new(iced_graphics::vector::Cache::new()),
 
             pipeline,
             uniforms: uniforms_buffer,
@@ -265,9 +263,10 @@ impl Pipeline {
             instances,
             constants: constant_bind_group,
             texture,
-            texture_version: texture_atlas.layer_count(),
+            texture_version: atlas.layer_count(),
             texture_layout,
             texture_atlas,
+            atlas,
         }
     }
 
@@ -295,6 +294,7 @@ impl Pipeline {
         images: &[layer::Image],
         transformation: Transformation,
         bounds: Rectangle<u32>,
+        mask: Option<layer::Mask>,
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
@@ -312,9 +312,8 @@ impl Pipeline {
                 layer::Image::Raster { handle, bounds } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
-                        device,
-                        encoder,
-                        &mut self.texture_atlas,
+                        &mut self.atlas,
+                        &mut self.texture_atlas.storage(device, encoder),
                     ) {
                         add_instances(
                             [bounds.x, bounds.y],
@@ -335,9 +334,8 @@ impl Pipeline {
                         handle,
                         size,
                         _scale,
-                        device,
-                        encoder,
-                        &mut self.texture_atlas,
+                        &mut self.atlas,
+                        &mut self.texture_atlas.storage(device, encoder),
                     ) {
                         add_instances(
                             [bounds.x, bounds.y],
@@ -356,7 +354,7 @@ impl Pipeline {
             return;
         }
 
-        let texture_version = self.texture_atlas.layer_count();
+        let texture_version = self.atlas.layer_count();
 
         if self.texture_version != texture_version {
             log::info!("Atlas has grown. Recreating bind group...");
@@ -386,9 +384,9 @@ impl Pipeline {
                 device,
             );
 
-            uniforms_buffer.copy_from_slice(bytemuck::bytes_of(&Uniforms {
-                transform: transformation.into(),
-            }));
+            uniforms_buffer.copy_from_slice(bytemuck::bytes_of(
+                &Uniforms::new(transformation, mask),
+            ));
         }
 
         let mut i = 0;
@@ -454,10 +452,10 @@ impl Pipeline {
 
     pub fn trim_cache(&mut self) {
         #[cfg(feature = "image")]
-        self.raster_cache.borrow_mut().trim(&mut self.texture_atlas);
+        self.raster_cache.borrow_mut().trim(&mut self.atlas);
 
         #[cfg(feature = "svg")]
-        self.vector_cache.borrow_mut().trim(&mut self.texture_atlas);
+        self.vector_cache.borrow_mut().trim(&mut self.atlas);
     }
 }
 
@@ -502,6 +500,32 @@ impl Instance {
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
 struct Uniforms {
     transform: [f32; 16],
+    mask_bounds: [f32; 4],
+    mask_radius: [f32; 4],
+}
+
+impl Uniforms {
+    fn new(
+        transformation: Transformation,
+        mask: Option<layer::Mask>,
+    ) -> Uniforms {
+        let (mask_bounds, mask_radius) = match mask {
+            Some(layer::Mask {
+                bounds,
+                border_radius,
+            }) => (
+                [bounds.x, bounds.y, bounds.width, bounds.height],
+                border_radius,
+            ),
+            None => ([0.0; 4], [0.0; 4]),
+        };
+
+        Self {
+            transform: transformation.into(),
+            mask_bounds,
+            mask_radius,
+        }
+    }
 }
 
 fn add_instances(
@@ -0,0 +1,216 @@
+use iced_graphics::atlas::{self, Entry, Layer};
+
+/// The texture array holding the layers of an [`Atlas`].
+///
+/// [`Atlas`]: atlas::Atlas
+#[derive(Debug)]
+pub struct Texture {
+    raw: wgpu::Texture,
+    view: wgpu::TextureView,
+    layers: usize,
+}
+
+impl Texture {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let raw = create_texture(device, 1);
+        let view = create_view(&raw);
+
+        Texture {
+            raw,
+            view,
+            layers: 1,
+        }
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    pub fn storage<'a>(
+        &'a mut self,
+        device: &'a wgpu::Device,
+        encoder: &'a mut wgpu::CommandEncoder,
+    ) -> Storage<'a> {
+        Storage {
+            texture: self,
+            device,
+            encoder,
+        }
+    }
+}
+
+/// A [`Texture`] ready to be grown and uploaded to.
+#[derive(Debug)]
+pub struct Storage<'a> {
+    texture: &'a mut Texture,
+    device: &'a wgpu::Device,
+    encoder: &'a mut wgpu::CommandEncoder,
+}
+
+impl Storage<'_> {
+    fn upload_allocation(
+        &mut self,
+        buffer: &wgpu::Buffer,
+        image_width: u32,
+        image_height: u32,
+        padding: u32,
+        offset: usize,
+        allocation: &atlas::Allocation,
+    ) {
+        let (x, y) = allocation.position();
+        let (width, height) = allocation.size();
+        let layer = allocation.layer();
+
+        let extent = wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        };
+
+        self.encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer,
+                layout: wgpu::TextureDataLayout {
+                    offset: offset as u64,
+                    bytes_per_row: 4 * image_width + padding,
+                    rows_per_image: image_height,
+                },
+            },
+            wgpu::TextureCopyView {
+                texture: &self.texture.raw,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x,
+                    y,
+                    z: layer as u32,
+                },
+            },
+            extent,
+        );
+    }
+}
+
+impl atlas::Storage for Storage<'_> {
+    fn grow(&mut self, layers: &[Layer]) {
+        if layers.len() <= self.texture.layers {
+            return;
+        }
+
+        let new_texture = create_texture(self.device, layers.len());
+
+        for (i, layer) in layers.iter().take(self.texture.layers).enumerate() {
+            if layer.is_empty() {
+                continue;
+            }
+
+            self.encoder.copy_texture_to_texture(
+                wgpu::TextureCopyView {
+                    texture: &self.texture.raw,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: 0,
+                        y: 0,
+                        z: i as u32,
+                    },
+                },
+                wgpu::TextureCopyView {
+                    texture: &new_texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: 0,
+                        y: 0,
+                        z: i as u32,
+                    },
+                },
+                wgpu::Extent3d {
+                    width: atlas::SIZE,
+                    height: atlas::SIZE,
+                    depth: 1,
+                },
+            );
+        }
+
+        self.texture.view = create_view(&new_texture);
+        self.texture.raw = new_texture;
+        self.texture.layers = layers.len();
+    }
+
+    fn upload(&mut self, width: u32, height: u32, data: &[u8], entry: &Entry) {
+        use wgpu::util::DeviceExt;
+
+        // It is a webgpu requirement that:
+        //   BufferCopyView.layout.bytes_per_row % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT == 0
+        // So we calculate padded_width by rounding width up to the next
+        // multiple of wgpu::COPY_BYTES_PER_ROW_ALIGNMENT.
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padding = (align - (4 * width) % align) % align;
+        let padded_width = (4 * width + padding) as usize;
+        let padded_data_size = padded_width * height as usize;
+
+        let mut padded_data = vec![0; padded_data_size];
+
+        for row in 0..height as usize {
+            let offset = row * padded_width;
+
+            padded_data[offset..offset + 4 * width as usize].copy_from_slice(
+                &data[row * 4 * width as usize..(row + 1) * 4 * width as usize],
+            )
+        }
+
+        let buffer =
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("iced_wgpu::image staging buffer"),
+                    contents: &padded_data,
+                    usage: wgpu::BufferUsage::COPY_SRC,
+                });
+
+        match entry {
+            Entry::Contiguous(allocation) => {
+                self.upload_allocation(
+                    &buffer, width, height, padding, 0, allocation,
+                );
+            }
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    let (x, y) = fragment.position;
+                    let offset = (y * padded_width as u32 + 4 * x) as usize;
+
+                    self.upload_allocation(
+                        &buffer,
+                        width,
+                        height,
+                        padding,
+                        offset,
+                        &fragment.allocation,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn create_texture(device: &wgpu::Device, layers: usize) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::image texture atlas"),
+        size: wgpu::Extent3d {
+            width: atlas::SIZE,
+            height: atlas::SIZE,
+            depth: layers as u32,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage: wgpu::TextureUsage::COPY_DST
+            | wgpu::TextureUsage::COPY_SRC
+            | wgpu::TextureUsage::SAMPLED,
+    })
+}
+
+fn create_view(texture: &wgpu::Texture) -> wgpu::TextureView {
+    texture.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(wgpu::TextureViewDimension::D2Array),
+        ..Default::default()
+    })
+}
@@ -32,9 +32,12 @@ pub mod triangle;
 pub mod widget;
 pub mod window;
 
+mod backdrop;
 mod backend;
+mod capture;
+mod compositor;
+mod glyph;
 mod quad;
-mod text;
 
 pub use iced_graphics::{
     Antialiasing, Color, Defaults, Error, Primitive, Viewport,
@@ -42,6 +45,7 @@ pub use iced_graphics::{
 pub use wgpu;
 
 pub use backend::Backend;
+pub use capture::Capture;
 pub use settings::Settings;
 
 #[doc(no_inline)]
@@ -23,7 +23,8 @@ impl Pipeline {
                 label: Some("iced_wgpu::quad uniforms layout"),
                 entries: &[wgpu::BindGroupLayoutEntry {
                     binding: 0,
-                    visibility: wgpu::ShaderStage::VERTEX,
+                    visibility: wgpu::ShaderStage::VERTEX
+                        | wgpu::ShaderStage::FRAGMENT,
                     ty: wgpu::BindingType::UniformBuffer {
                         dynamic: false,
                         min_binding_size: wgpu::BufferSize::new(
@@ -136,13 +137,34 @@ impl Pipeline {
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 5,
-                                    format: wgpu::VertexFormat::Float,
+                                    format: wgpu::VertexFormat::Float4,
                                     offset: 4 * (2 + 2 + 4 + 4),
                                 },
                                 wgpu::VertexAttributeDescriptor {
                                     shader_location: 6,
                                     format: wgpu::VertexFormat::Float,
-                                    offset: 4 * (2 + 2 + 4 + 4 + 1),
+                                    offset: 4 * (2 + 2 + 4 + 4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 7,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (2 + 2 + 4 + 4 + 4 + 1),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 8,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * (2 + 2 + 4 + 4 + 4 + 1 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 9,
+                                    format: wgpu::VertexFormat::Float,
+                                    offset: 4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 10,
+                                    format: wgpu::VertexFormat::Float,
+                                    offset: 4
+                                        * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 2 + 1),
                                 },
                             ],
                         },
@@ -193,9 +215,10 @@ impl Pipeline {
         transformation: Transformation,
         scale: f32,
         bounds: Rectangle<u32>,
+        mask: Option<layer::Mask>,
         target: &wgpu::TextureView,
     ) {
-        let uniforms = Uniforms::new(transformation, scale);
+        let uniforms = Uniforms::new(transformation, scale, mask);
 
         {
             let mut constants_buffer = staging_belt.write_buffer(
@@ -300,22 +323,41 @@ const MAX_INSTANCES: usize = 100_000;
 struct Uniforms {
     transform: [f32; 16],
     scale: f32,
+    // The `vec4` members of the uniform block are aligned to 16 bytes
+    _padding: [f32; 3],
+    mask_bounds: [f32; 4],
+    mask_radius: [f32; 4],
 }
 
 impl Uniforms {
-    fn new(transformation: Transformation, scale: f32) -> Uniforms {
+    fn new(
+        transformation: Transformation,
+        scale: f32,
+        mask: Option<layer::Mask>,
+    ) -> Uniforms {
+        let (mask_bounds, mask_radius) = match mask {
+            Some(layer::Mask {
+                bounds,
+                border_radius,
+            }) => (
+                [bounds.x, bounds.y, bounds.width, bounds.height],
+                border_radius,
+            ),
+            None => ([0.0; 4], [0.0; 4]),
+        };
+
         Self {
             transform: *transformation.as_ref(),
             scale,
+            _padding: [0.0; 3],
+            mask_bounds,
+            mask_radius,
         }
     }
 }
 
 impl Default for Uniforms {
     fn default() -> Self {
-        Self {
-            transform: *Transformation::identity().as_ref(),
-            scale: 1.0,
-        }
+        Self::new(Transformation::identity(), 1.0, None)
     }
 }
@@ -0,0 +1,34 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+
+layout(set = 0, binding = 0) uniform sampler u_Sampler;
+layout(set = 0, binding = 1) uniform Globals {
+    vec2 u_Step;
+    float u_Sigma;
+    float u_Taps;
+};
+layout(set = 1, binding = 0) uniform texture2D u_Texture;
+
+layout(location = 0) out vec4 o_Color;
+
+// One pass of a separable Gaussian blur, sampling texels on both sides of the
+// fragment along the direction of the step
+void main() {
+    vec4 color = texture(sampler2D(u_Texture, u_Sampler), v_Uv);
+    float total = 1.0;
+
+    for(int i = 1; i <= int(u_Taps); i++) {
+        float weight = exp(-float(i * i) / (2.0 * u_Sigma * u_Sigma));
+        vec2 offset = u_Step * float(i);
+
+        color += weight * (
+            texture(sampler2D(u_Texture, u_Sampler), v_Uv + offset)
+            + texture(sampler2D(u_Texture, u_Sampler), v_Uv - offset)
+        );
+        total += 2.0 * weight;
+    }
+
+    // The texture contains premultiplied colors, which can be blurred as is
+    o_Color = color / total;
+}
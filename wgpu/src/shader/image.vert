@@ -9,6 +9,8 @@ layout(location = 5) in uint i_Layer;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
+    vec4 u_MaskBounds;
+    vec4 u_MaskRadius;
 };
 
 layout(location = 0) out vec3 o_Uv;
@@ -0,0 +1,16 @@
+#version 450
+
+layout(location = 0) in vec2 v_Uv;
+
+layout(set = 0, binding = 0) uniform sampler u_Sampler;
+layout(set = 0, binding = 1) uniform Globals {
+    float u_Opacity;
+};
+layout(set = 1, binding = 0) uniform texture2D u_Texture;
+
+layout(location = 0) out vec4 o_Color;
+
+void main() {
+    // The texture contains premultiplied colors
+    o_Color = texture(sampler2D(u_Texture, u_Sampler), v_Uv) * u_Opacity;
+}
@@ -4,8 +4,20 @@ layout(location = 0) in vec4 v_Color;
 layout(location = 1) in vec4 v_BorderColor;
 layout(location = 2) in vec2 v_Pos;
 layout(location = 3) in vec2 v_Scale;
-layout(location = 4) in float v_BorderRadius;
+layout(location = 4) in vec4 v_BorderRadius;
 layout(location = 5) in float v_BorderWidth;
+layout(location = 6) in vec2 v_Position;
+layout(location = 7) in vec4 v_ShadowColor;
+layout(location = 8) in vec2 v_ShadowOffset;
+layout(location = 9) in float v_ShadowBlurRadius;
+layout(location = 10) in float v_ShadowSpread;
+
+layout (set = 0, binding = 0) uniform Globals {
+    mat4 u_Transform;
+    float u_Scale;
+    vec4 u_MaskBounds;
+    vec4 u_MaskRadius;
+};
 
 layout(location = 0) out vec4 o_Color;
 
@@ -27,15 +39,81 @@ float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
     return sqrt(distance.x * distance.x + distance.y * distance.y);
 }
 
+// Picks the radius of the corner closest to the fragment, given the radii
+// clockwise from the top-left one
+float select_border_radius(in vec4 radii, in vec2 frag_coord, in vec2 center)
+{
+    float top = frag_coord.x > center.x ? radii.y : radii.x;
+    float bottom = frag_coord.x > center.x ? radii.z : radii.w;
+
+    return frag_coord.y > center.y ? bottom : top;
+}
+
+// The signed distance from a point to a rounded box, given relative to the
+// center of the box
+float rounded_box_sdf(in vec2 to_center, in vec2 half_size, float radius)
+{
+    vec2 q = abs(to_center) - half_size + vec2(radius);
+
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - radius;
+}
+
+// The coverage of a blurred box shadow, approximating the gaussian falloff
+// of a CSS `box-shadow` with a smoothstep across twice the blur radius
+float shadow_alpha(in vec2 frag_coord)
+{
+    vec2 half_size = max(v_Scale / 2.0 + vec2(v_ShadowSpread), vec2(0.0));
+    vec2 center = v_Pos + v_ShadowOffset + v_Scale / 2.0;
+
+    float radius = clamp(
+        select_border_radius(v_BorderRadius, frag_coord, center)
+            + v_ShadowSpread,
+        0.0,
+        min(half_size.x, half_size.y)
+    );
+
+    float d = rounded_box_sdf(frag_coord - center, half_size, radius);
+
+    float blur_radius = max(v_ShadowBlurRadius, 0.5);
+
+    return 1.0 - smoothstep(-blur_radius, blur_radius, d);
+}
+
+// The coverage of the rounded clipping mask of the layer
+float mask_alpha(in vec2 frag_coord)
+{
+    if(u_MaskRadius == vec4(0.0)) {
+        return 1.0;
+    }
+
+    vec2 half_size = u_MaskBounds.zw / 2.0;
+    vec2 center = u_MaskBounds.xy + half_size;
+
+    float radius = min(
+        select_border_radius(u_MaskRadius, frag_coord, center),
+        min(half_size.x, half_size.y)
+    );
+
+    float d = rounded_box_sdf(frag_coord - center, half_size, radius);
+
+    return 1.0 - smoothstep(-0.5, 0.5, d);
+}
+
 void main() {
     vec4 mixed_color;
 
+    float border_radius = select_border_radius(
+        v_BorderRadius,
+        v_Position,
+        v_Pos + v_Scale / 2.0
+    );
+
     // TODO: Remove branching (?)
     if(v_BorderWidth > 0) {
-        float internal_border = max(v_BorderRadius - v_BorderWidth, 0);
+        float internal_border = max(border_radius - v_BorderWidth, 0);
 
         float internal_distance = distance(
-            gl_FragCoord.xy,
+            v_Position,
             v_Pos + vec2(v_BorderWidth),
             v_Scale - vec2(v_BorderWidth * 2.0),
             internal_border
@@ -53,14 +131,36 @@ void main() {
     }
 
     float d = distance(
-        gl_FragCoord.xy,
+        v_Position,
         v_Pos,
         v_Scale,
-        v_BorderRadius
+        border_radius
     );
 
     float radius_alpha =
-        1.0 - smoothstep(max(v_BorderRadius - 0.5, 0), v_BorderRadius + 0.5, d);
+        1.0 - smoothstep(max(border_radius - 0.5, 0), border_radius + 0.5, d);
+
+    vec4 quad_color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+
+    // Like in CSS, the shadow is only visible outside of the quad, so both
+    // never cover the same part of a pixel
+    if(v_ShadowColor.w > 0.0) {
+        float shadow =
+            v_ShadowColor.w * shadow_alpha(v_Position) * (1.0 - radius_alpha);
 
-    o_Color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+        float alpha = quad_color.w + shadow;
+
+        if(alpha > 0.0) {
+            quad_color = vec4(
+                (quad_color.xyz * quad_color.w + v_ShadowColor.xyz * shadow)
+                    / alpha,
+                alpha
+            );
+        }
+    }
+
+    o_Color = vec4(
+        quad_color.xyz,
+        quad_color.w * mask_alpha(gl_FragCoord.xy)
+    );
 }
@@ -7,6 +7,8 @@ layout(location = 0) out vec4 o_Color;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
+    vec4 u_MaskBounds;
+    vec4 u_MaskRadius;
 };
 
 void main() {
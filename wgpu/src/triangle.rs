@@ -85,7 +85,8 @@ impl Pipeline {
                 label: Some("iced_wgpu::triangle uniforms layout"),
                 entries: &[wgpu::BindGroupLayoutEntry {
                     binding: 0,
-                    visibility: wgpu::ShaderStage::VERTEX,
+                    visibility: wgpu::ShaderStage::VERTEX
+                        | wgpu::ShaderStage::FRAGMENT,
                     ty: wgpu::BindingType::UniformBuffer {
                         dynamic: true,
                         min_binding_size: wgpu::BufferSize::new(
@@ -224,6 +225,7 @@ impl Pipeline {
         target_height: u32,
         transformation: Transformation,
         scale_factor: f32,
+        mask: Option<layer::Mask>,
         meshes: &[layer::Mesh<'_>],
     ) {
         // This looks a bit crazy, but we are just counting how many vertices
@@ -272,9 +274,11 @@ impl Pipeline {
 
         // We upload everything upfront
         for mesh in meshes {
-            let transform = (transformation
-                * Transformation::translate(mesh.origin.x, mesh.origin.y))
-            .into();
+            let uniform = Uniforms::new(
+                transformation
+                    * Transformation::translate(mesh.origin.x, mesh.origin.y),
+                mask,
+            );
 
             let vertices = bytemuck::cast_slice(&mesh.buffers.vertices);
             let indices = bytemuck::cast_slice(&mesh.buffers.indices);
@@ -309,7 +313,7 @@ impl Pipeline {
                         index_buffer.copy_from_slice(indices);
                     }
 
-                    uniforms.push(transform);
+                    uniforms.push(uniform);
                     offsets.push((
                         last_vertex as u64,
                         last_index as u64,
@@ -413,28 +417,42 @@ impl Pipeline {
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
 struct Uniforms {
     transform: [f32; 16],
+    mask_bounds: [f32; 4],
+    mask_radius: [f32; 4],
     // We need to align this to 256 bytes to please `wgpu`...
     // TODO: Be smarter and stop wasting memory!
-    _padding_a: [f32; 32],
+    _padding_a: [f32; 24],
     _padding_b: [f32; 16],
 }
 
-impl Default for Uniforms {
-    fn default() -> Self {
+impl Uniforms {
+    fn new(
+        transformation: Transformation,
+        mask: Option<layer::Mask>,
+    ) -> Uniforms {
+        let (mask_bounds, mask_radius) = match mask {
+            Some(layer::Mask {
+                bounds,
+                border_radius,
+            }) => (
+                [bounds.x, bounds.y, bounds.width, bounds.height],
+                border_radius,
+            ),
+            None => ([0.0; 4], [0.0; 4]),
+        };
+
         Self {
-            transform: *Transformation::identity().as_ref(),
-            _padding_a: [0.0; 32],
+            transform: transformation.into(),
+            mask_bounds,
+            mask_radius,
+            _padding_a: [0.0; 24],
             _padding_b: [0.0; 16],
         }
     }
 }
 
-impl From<Transformation> for Uniforms {
-    fn from(transformation: Transformation) -> Uniforms {
-        Self {
-            transform: transformation.into(),
-            _padding_a: [0.0; 32],
-            _padding_b: [0.0; 16],
-        }
+impl Default for Uniforms {
+    fn default() -> Self {
+        Self::new(Transformation::identity(), None)
     }
 }
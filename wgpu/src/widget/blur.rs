@@ -0,0 +1,8 @@
+//! Blur whatever is drawn beneath some content.
+use crate::Renderer;
+
+/// An element blurring whatever is drawn beneath its bounds, before drawing
+/// its content on top.
+///
+/// This is an alias of an `iced_native` blur with a default `Renderer`.
+pub type Blur<'a, Message> = iced_native::Blur<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Display and edit code with line numbers and syntax highlighting.
+//!
+//! A [`CodeView`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::code_view::{
+    highlighter, Highlight, Highlighter, State, Style, StyleSheet,
+};
+
+/// A view of some code, with line numbers and syntax highlighting.
+///
+/// This is an alias of an `iced_native` code view with an `iced_wgpu::Renderer`.
+pub type CodeView<'a, Message> = iced_native::CodeView<'a, Message, Renderer>;
@@ -0,0 +1,10 @@
+//! Display documents written in Markdown.
+use crate::Renderer;
+
+pub use iced_graphics::markdown::{parse, Block, Inline, Style, StyleSheet};
+
+/// A document written in Markdown.
+///
+/// This is an alias of an `iced_native` markdown document with an
+/// `iced_wgpu::Renderer`.
+pub type Markdown<'a, Message> = iced_native::Markdown<'a, Message, Renderer>;
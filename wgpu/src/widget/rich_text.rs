@@ -0,0 +1,9 @@
+//! Write a paragraph of text made of spans with their own style.
+use crate::Renderer;
+
+pub use iced_graphics::rich_text::Span;
+
+/// A paragraph of text made of spans with their own style.
+///
+/// This is an alias of an `iced_native` rich text with an `iced_wgpu::Renderer`.
+pub type RichText<Message> = iced_native::RichText<Message, Renderer>;
@@ -0,0 +1,325 @@
+//! Render your own graphics with [`wgpu`] inside of your user interface.
+//!
+//! A [`Shader`] widget hands its bounds to a [`Program`], which produces a
+//! [`Primitive`] that records its own render passes when the frame is drawn.
+//! It can be used for 3D graphics, custom visualizations, and more!
+//!
+//! # Example
+//! ```
+//! use iced_native::{Color, Length, Point, Rectangle};
+//! use iced_wgpu::shader::{self, wgpu, Program, Shader, Storage, Target};
+//!
+//! #[derive(Debug)]
+//! struct Fill(Color);
+//!
+//! impl shader::Primitive for Fill {
+//!     fn render(
+//!         &self,
+//!         _device: &wgpu::Device,
+//!         _queue: &wgpu::Queue,
+//!         encoder: &mut wgpu::CommandEncoder,
+//!         _storage: &mut Storage,
+//!         target: &Target<'_>,
+//!     ) {
+//!         let [r, g, b, a] = self.0.into_linear();
+//!
+//!         let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+//!             color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+//!                 attachment: target.view,
+//!                 resolve_target: None,
+//!                 ops: wgpu::Operations {
+//!                     load: wgpu::LoadOp::Clear(wgpu::Color {
+//!                         r: f64::from(r),
+//!                         g: f64::from(g),
+//!                         b: f64::from(b),
+//!                         a: f64::from(a),
+//!                     }),
+//!                     store: true,
+//!                 },
+//!             }],
+//!             depth_stencil_attachment: None,
+//!         });
+//!     }
+//! }
+//!
+//! struct Background;
+//!
+//! impl Program<()> for Background {
+//!     type Primitive = Fill;
+//!
+//!     fn draw(&self, _bounds: Rectangle, _cursor_position: Point) -> Fill {
+//!         Fill(Color::from_rgb(0.2, 0.4, 0.8))
+//!     }
+//! }
+//!
+//! let shader = Shader::new(Background).width(Length::Fill);
+//! ```
+//!
+//! [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
+use crate::{Defaults, Renderer};
+use iced_graphics::Viewport;
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+pub use wgpu;
+
+/// A widget that renders a [`Primitive`] of its own.
+///
+/// The contents of the [`Shader`] are clipped to its bounds and stacked
+/// between the widgets drawn before and after it.
+#[derive(Debug)]
+pub struct Shader<Message, P: Program<Message>> {
+    width: Length,
+    height: Length,
+    program: P,
+    phantom: PhantomData<Message>,
+}
+
+impl<Message, P: Program<Message>> Shader<Message, P> {
+    const DEFAULT_SIZE: u16 = 100;
+
+    /// Creates a new [`Shader`] with the given [`Program`].
+    pub fn new(program: P) -> Self {
+        Shader {
+            width: Length::Units(Self::DEFAULT_SIZE),
+            height: Length::Units(Self::DEFAULT_SIZE),
+            program,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the width of the [`Shader`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Shader`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+/// The state and logic of a [`Shader`].
+pub trait Program<Message> {
+    /// The [`Primitive`] produced by the [`Program`].
+    type Primitive: Primitive;
+
+    /// Updates the state of the [`Program`].
+    ///
+    /// When a [`Program`] is used in a [`Shader`], the runtime will call this
+    /// method for each [`Event`].
+    ///
+    /// This method can optionally return a `Message` to notify an application
+    /// of any meaningful interactions.
+    ///
+    /// By default, this method does and returns nothing.
+    fn update(
+        &mut self,
+        _event: Event,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+    ) -> (event::Status, Option<Message>) {
+        (event::Status::Ignored, None)
+    }
+
+    /// Produces the [`Primitive`] to render in the given bounds.
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Self::Primitive;
+
+    /// Returns the current mouse interaction of the [`Program`].
+    ///
+    /// The interaction returned will be in effect even if the cursor position
+    /// is out of bounds of the program's [`Shader`].
+    fn mouse_interaction(
+        &self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+}
+
+/// Some graphics that record their own render passes.
+pub trait Primitive: std::fmt::Debug + Send + Sync + 'static {
+    /// Renders the [`Primitive`] into the given [`Target`].
+    ///
+    /// The commands should be recorded in the `encoder`, which is submitted
+    /// once the whole frame has been recorded. Any write through the `queue`
+    /// takes place before that.
+    ///
+    /// Resources that outlive a frame, like pipelines, can be kept in the
+    /// [`Storage`].
+    fn render(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        storage: &mut Storage,
+        target: &Target<'_>,
+    );
+}
+
+/// The texture a [`Primitive`] is rendered into.
+///
+/// It covers the bounds of the [`Shader`] exactly and is cleared before
+/// rendering. It is composited afterwards, so its colors are expected to be
+/// premultiplied by their alpha.
+#[derive(Debug)]
+pub struct Target<'a> {
+    /// The view of the texture.
+    pub view: &'a wgpu::TextureView,
+
+    /// The format of the texture.
+    pub format: wgpu::TextureFormat,
+
+    /// The bounds of the [`Shader`] in the window, in logical pixels.
+    pub bounds: Rectangle,
+
+    /// The [`Viewport`] of the texture.
+    ///
+    /// Its physical size is the size of the texture.
+    pub viewport: &'a Viewport,
+}
+
+/// A store of resources shared by every [`Primitive`], like pipelines or
+/// buffers.
+///
+/// It keeps a single value per type.
+#[derive(Default)]
+pub struct Storage {
+    values: HashMap<TypeId, Box<dyn Any + Send>>,
+}
+
+impl Storage {
+    /// Returns true if the [`Storage`] contains a value of type `T`.
+    pub fn has<T: 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Stores the given value, replacing any other value of the same type.
+    pub fn store<T: Send + 'static>(&mut self, value: T) {
+        let _ = self.values.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// Returns a reference to the value of type `T`, if any.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// Returns a mutable reference to the value of type `T`, if any.
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut())
+    }
+}
+
+impl std::fmt::Debug for Storage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Storage")
+            .field("values", &self.values.len())
+            .finish()
+    }
+}
+
+/// The content of the custom primitive of a [`Shader`].
+#[derive(Debug)]
+pub(crate) struct Content(pub Box<dyn Primitive>);
+
+impl<Message, P> Widget<Message, Renderer> for Shader<Message, P>
+where
+    P: Program<Message>,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> event::Status {
+        let (event_status, message) =
+            self.program.update(event, layout.bounds(), cursor_position);
+
+        if let Some(message) = message {
+            messages.push(message);
+        }
+
+        event_status
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (crate::Primitive, mouse::Interaction) {
+        let bounds = layout.bounds();
+        let primitive = self.program.draw(bounds, cursor_position);
+
+        (
+            crate::Primitive::Custom {
+                bounds,
+                content: Arc::new(Content(Box::new(primitive))),
+            },
+            self.program.mouse_interaction(bounds, cursor_position),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, P> From<Shader<Message, P>> for Element<'a, Message, Renderer>
+where
+    Message: 'static,
+    P: Program<Message> + 'a,
+{
+    fn from(shader: Shader<Message, P>) -> Element<'a, Message, Renderer> {
+        Element::new(shader)
+    }
+}
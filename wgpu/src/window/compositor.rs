@@ -1,4 +1,4 @@
-use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+use crate::{Backend, Color, Error, Primitive, Renderer, Settings, Viewport};
 
 use futures::task::SpawnExt;
 use iced_native::{futures, mouse};
@@ -152,6 +152,7 @@ impl iced_graphics::window::Compositor for Compositor {
 
         let mouse_interaction = renderer.backend_mut().draw(
             &mut self.device,
+            &self.queue,
             &mut self.staging_belt,
             &mut encoder,
             &frame.output.view,
@@ -174,4 +175,50 @@ impl iced_graphics::window::Compositor for Compositor {
 
         mouse_interaction
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        swap_chain: &mut Self::SwapChain,
+        viewport: &Viewport,
+        background_color: Color,
+        output: &(Primitive, mouse::Interaction),
+        overlay: &[T],
+    ) -> Option<(Vec<u8>, mouse::Interaction)> {
+        let frame = swap_chain.get_current_frame().expect("Next frame");
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu encoder"),
+            },
+        );
+
+        let (mouse_interaction, capture) = renderer.backend_mut().capture(
+            &self.device,
+            &self.queue,
+            &mut self.staging_belt,
+            &mut encoder,
+            &frame.output.view,
+            viewport,
+            background_color,
+            output,
+            overlay,
+        )?;
+
+        // Submit work
+        self.staging_belt.finish();
+        self.queue.submit(Some(encoder.finish()));
+
+        let pixels = capture.read(&self.device);
+
+        // Recall staging buffers
+        self.local_pool
+            .spawner()
+            .spawn(self.staging_belt.recall())
+            .expect("Recall staging belt");
+
+        self.local_pool.run_until_stalled();
+
+        Some((pixels, mouse_interaction))
+    }
 }
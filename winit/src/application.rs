@@ -4,16 +4,18 @@ mod state;
 pub use state::State;
 
 use crate::conversion;
+use crate::ime;
 use crate::mouse;
 use crate::{
-    Clipboard, Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime,
-    Settings, Size, Subscription,
+    Clipboard, Color, Command, Debug, Direction, Error, Executor, Mode, Proxy,
+    Runtime, Settings, Size, Subscription,
 };
 
 use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::window;
 use iced_native::program::Program;
+use iced_native::window::Screenshot;
 use iced_native::{Cache, UserInterface};
 
 use std::mem::ManuallyDrop;
@@ -91,6 +93,17 @@ pub trait Application: Program {
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    /// Returns the [`Direction`] in which the contents of the [`Application`]
+    /// flow.
+    ///
+    /// Every widget inherits this [`Direction`], unless it is overridden by
+    /// one of its ancestors.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -111,7 +124,7 @@ where
     let mut debug = Debug::new();
     debug.startup_started();
 
-    let (compositor, renderer) = C::new(compositor_settings)?;
+    let (compositor, mut renderer) = C::new(compositor_settings)?;
 
     let event_loop = EventLoop::with_user_event();
 
@@ -130,7 +143,14 @@ where
 
     let subscription = application.subscription();
 
-    runtime.spawn(init_command);
+    let mut screenshots = Vec::new();
+
+    let _ = run_command(
+        init_command,
+        &mut renderer,
+        &mut runtime,
+        &mut screenshots,
+    );
     runtime.track(subscription);
 
     let window = settings
@@ -153,6 +173,7 @@ where
         runtime,
         debug,
         receiver,
+        screenshots,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -185,6 +206,7 @@ async fn run_instance<A, E, C>(
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut screenshots: Vec<Box<dyn Fn(Screenshot) -> A::Message + Send>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -219,7 +241,9 @@ async fn run_instance<A, E, C>(
     let mut primitive =
         user_interface.draw(&mut renderer, state.cursor_position());
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut caret_bounds = None;
 
+    let mut ime = ime::Ime::new();
     let mut events = Vec::new();
     let mut messages = Vec::new();
 
@@ -228,6 +252,8 @@ async fn run_instance<A, E, C>(
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                ime.flush(&mut events);
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -249,15 +275,18 @@ async fn run_instance<A, E, C>(
                 }
 
                 if !messages.is_empty() {
-                    let cache =
+                    let mut cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
-                    update(
+                    update_with(
                         &mut application,
+                        &mut cache,
+                        &mut renderer,
                         &mut runtime,
                         &mut debug,
                         &mut messages,
+                        &mut screenshots,
                     );
 
                     // Update window
@@ -277,6 +306,19 @@ async fn run_instance<A, E, C>(
                     user_interface.draw(&mut renderer, state.cursor_position());
                 debug.draw_finished();
 
+                let new_caret_bounds = user_interface.caret_bounds(&renderer);
+
+                if new_caret_bounds != caret_bounds {
+                    if let Some(bounds) = new_caret_bounds {
+                        window.set_ime_position(conversion::ime_position(
+                            bounds,
+                            state.scale_factor(),
+                        ));
+                    }
+
+                    caret_bounds = new_caret_bounds;
+                }
+
                 window.request_redraw();
             }
             event::Event::UserEvent(message) => {
@@ -311,14 +353,51 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                let new_mouse_interaction = compositor.draw(
-                    &mut renderer,
-                    &mut swap_chain,
-                    state.viewport(),
-                    state.background_color(),
-                    &primitive,
-                    &debug.overlay(),
-                );
+                let screenshot = if screenshots.is_empty() {
+                    None
+                } else {
+                    compositor.screenshot(
+                        &mut renderer,
+                        &mut swap_chain,
+                        state.viewport(),
+                        state.background_color(),
+                        &primitive,
+                        &debug.overlay(),
+                    )
+                };
+
+                let new_mouse_interaction = match screenshot {
+                    Some((bytes, mouse_interaction)) => {
+                        let screenshot = Screenshot {
+                            bytes,
+                            size: state.physical_size(),
+                        };
+
+                        deliver_screenshots(
+                            screenshot,
+                            &mut screenshots,
+                            &mut runtime,
+                        );
+
+                        mouse_interaction
+                    }
+                    None => {
+                        if !screenshots.is_empty() {
+                            log::error!("The frame could not be captured");
+
+                            screenshots.clear();
+                        }
+
+                        compositor.draw(
+                            &mut renderer,
+                            &mut swap_chain,
+                            state.viewport(),
+                            state.background_color(),
+                            &primitive,
+                            &debug.overlay(),
+                        )
+                    }
+                };
 
                 debug.render_finished();
 
@@ -343,13 +422,12 @@ async fn run_instance<A, E, C>(
 
                 state.update(&window, &window_event, &mut debug);
 
-                if let Some(event) = conversion::window_event(
+                ime.window_event(
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
-                ) {
-                    events.push(event);
-                }
+                    &mut events,
+                );
             }
             _ => {}
         }
@@ -392,8 +470,10 @@ pub fn build_user_interface<'a, A: Application>(
     size: Size,
     debug: &mut Debug,
 ) -> UserInterface<'a, A::Message, A::Renderer> {
+    let direction = application.direction();
+
     debug.view_started();
-    let view = application.view();
+    let view = application.view().direction(direction);
     debug.view_finished();
 
     debug.layout_started();
@@ -405,11 +485,81 @@ pub fn build_user_interface<'a, A: Application>(
 
 /// Updates an [`Application`] by feeding it the provided messages, spawning any
 /// resulting [`Command`], and tracking its [`Subscription`].
+///
+/// The actions of a [`Command`] need the renderer and the window, which are
+/// not available here. Therefore, fonts fail to load with
+/// [`font::Error::Unsupported`] and screenshots are never taken. Use
+/// [`update_with`] to perform them.
+///
+/// [`font::Error::Unsupported`]: iced_native::font::Error::Unsupported
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
+) {
+    use iced_native::command::Action;
+    use iced_native::font;
+
+    update_each(application, runtime, debug, messages, |command, runtime| {
+        let (futures, actions) = command.into_parts();
+
+        runtime.spawn(futures);
+
+        for action in actions {
+            match action {
+                Action::LoadFont { tagger, .. } => {
+                    runtime.spawn(iced_futures::Command::from(
+                        futures::future::ready(tagger(Err(
+                            font::Error::Unsupported,
+                        ))),
+                    ));
+                }
+                Action::Screenshot { .. } => {
+                    log::warn!("Screenshots are not supported");
+                }
+            }
+        }
+    });
+}
+
+/// Updates an [`Application`] like [`update`], but performing the actions of
+/// any resulting [`Command`] with the given [`Renderer`].
+///
+/// The layout in the [`Cache`] is discarded if any [`Command`] loads a font.
+/// Any requested screenshot is queued in `screenshots`.
+///
+/// [`Renderer`]: iced_native::Renderer
+pub fn update_with<A: Application, E: Executor>(
+    application: &mut A,
+    cache: &mut Cache,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    screenshots: &mut Vec<Box<dyn Fn(Screenshot) -> A::Message + Send>>,
+) {
+    update_each(application, runtime, debug, messages, |command, runtime| {
+        if run_command(command, renderer, runtime, screenshots) {
+            // Layouts are cached by the hash of their widgets, which only
+            // describes the family of a font and not the font it resolves to.
+            // A new font can change the measurements of any text without
+            // changing any hash, and any node may contain text. Therefore,
+            // the whole cache is stale.
+            *cache = Cache::default();
+        }
+    });
+}
+
+fn update_each<A: Application, E: Executor>(
+    application: &mut A,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    mut run: impl FnMut(
+        Command<A::Message>,
+        &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    ),
 ) {
     for message in messages.drain(..) {
         debug.log_message(&message);
@@ -418,9 +568,73 @@ pub fn update<A: Application, E: Executor>(
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        runtime.spawn(command);
+        run(command, runtime);
     }
 
     let subscription = application.subscription();
     runtime.track(subscription);
 }
+
+/// Runs a [`Command`] by spawning its futures in the [`Runtime`] and
+/// performing its actions with the given [`Renderer`].
+///
+/// Screenshots need a frame to be presented first. Therefore, they are queued
+/// in `screenshots` and should be taken after drawing the next frame.
+///
+/// Returns true if a font was loaded. Any layout computed before is stale in
+/// that case.
+///
+/// [`Renderer`]: iced_native::Renderer
+pub fn run_command<Message, E>(
+    command: Command<Message>,
+    renderer: &mut impl iced_native::Renderer,
+    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+    screenshots: &mut Vec<Box<dyn Fn(Screenshot) -> Message + Send>>,
+) -> bool
+where
+    Message: 'static + Send,
+    E: Executor,
+{
+    use iced_native::command::Action;
+
+    let (futures, actions) = command.into_parts();
+    let mut loaded_font = false;
+
+    runtime.spawn(futures);
+
+    for action in actions {
+        match action {
+            Action::LoadFont { bytes, tagger } => {
+                let result = renderer.load_font(bytes);
+
+                loaded_font = loaded_font || result.is_ok();
+
+                runtime.spawn(iced_futures::Command::from(
+                    futures::future::ready(tagger(result)),
+                ));
+            }
+            Action::Screenshot { tagger } => {
+                screenshots.push(tagger);
+            }
+        }
+    }
+
+    loaded_font
+}
+
+/// Spawns the messages produced by the queued taggers of some screenshots
+/// with the given [`Screenshot`], emptying the queue.
+pub fn deliver_screenshots<Message, E>(
+    screenshot: Screenshot,
+    screenshots: &mut Vec<Box<dyn Fn(Screenshot) -> Message + Send>>,
+    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+) where
+    Message: 'static + Send,
+    E: Executor,
+{
+    for tagger in screenshots.drain(..) {
+        runtime.spawn(iced_futures::Command::from(futures::future::ready(
+            tagger(screenshot.clone()),
+        )));
+    }
+}
@@ -6,7 +6,7 @@ use crate::keyboard;
 use crate::mouse;
 use crate::touch;
 use crate::window;
-use crate::{Event, Mode, Point};
+use crate::{Event, Mode, Point, Rectangle};
 
 /// Converts a winit window event into an iced event.
 pub fn window_event(
@@ -76,6 +76,8 @@ pub fn window_event(
                 }))
             }
         },
+        // The text committed by input method editors is grouped by
+        // `ime::Ime`, since `winit` reports it character by character
         WindowEvent::ReceivedCharacter(c) if !is_private_use_character(*c) => {
             Some(Event::Keyboard(keyboard::Event::CharacterReceived(*c)))
         }
@@ -205,6 +207,19 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts the logical bounds of a text caret to the physical position of
+/// the candidate window of an input method editor, right below the caret.
+pub fn ime_position(
+    caret_bounds: Rectangle,
+    scale_factor: f64,
+) -> winit::dpi::PhysicalPosition<f64> {
+    winit::dpi::LogicalPosition::new(
+        f64::from(caret_bounds.x),
+        f64::from(caret_bounds.y + caret_bounds.height),
+    )
+    .to_physical(scale_factor)
+}
+
 /// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -0,0 +1,83 @@
+//! Receive the text committed by input method editors.
+use crate::conversion;
+use crate::keyboard;
+use crate::Event;
+
+/// Converts winit window events into iced events, grouping the text committed
+/// by an input method editor (IME) into a single
+/// [`keyboard::Event::ImeCommit`].
+///
+/// `winit` does not report the composition state of input method editors.
+/// However, on X11 and Wayland, a finished composition is received as some
+/// characters that no key press precedes. These characters are committed
+/// together, instead of being received one by one.
+///
+/// The text being composed is still drawn by the input method itself, so no
+/// [`keyboard::Event::ImePreedit`] is produced.
+#[derive(Debug, Default)]
+pub struct Ime {
+    is_key_pressed: bool,
+    commit: String,
+}
+
+impl Ime {
+    /// Creates a new [`Ime`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts a winit window event like [`conversion::window_event`],
+    /// pushing the resulting iced events to the given list.
+    ///
+    /// The text committed by an input method is only pushed once another
+    /// event is received or the [`Ime`] is flushed.
+    pub fn window_event(
+        &mut self,
+        event: &winit::event::WindowEvent<'_>,
+        scale_factor: f64,
+        modifiers: winit::event::ModifiersState,
+        events: &mut Vec<Event>,
+    ) {
+        use winit::event::{ElementState, KeyboardInput, WindowEvent};
+
+        match event {
+            WindowEvent::ReceivedCharacter(c)
+                if !self.is_key_pressed && !c.is_control() =>
+            {
+                if !conversion::is_private_use_character(*c) {
+                    self.commit.push(*c);
+                }
+
+                return;
+            }
+            WindowEvent::ReceivedCharacter(_) => {}
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.is_key_pressed = true;
+            }
+            _ => {
+                self.is_key_pressed = false;
+            }
+        }
+
+        self.flush(events);
+
+        events.extend(conversion::window_event(event, scale_factor, modifiers));
+    }
+
+    /// Pushes the text committed by an input method since the last event, if
+    /// any, to the given list.
+    pub fn flush(&mut self, events: &mut Vec<Event>) {
+        if !self.commit.is_empty() {
+            let text = std::mem::take(&mut self.commit);
+
+            events.push(Event::Keyboard(keyboard::Event::ImeCommit(text)));
+        }
+    }
+}
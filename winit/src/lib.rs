@@ -26,6 +26,7 @@ pub use winit;
 
 pub mod application;
 pub mod conversion;
+pub mod ime;
 pub mod settings;
 
 mod clipboard;